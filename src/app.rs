@@ -1,20 +1,50 @@
-use std::sync::Arc;
-use tokio::spawn;
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::{collections::HashMap, future::Future, path::Path, sync::Arc, time::Duration};
+use tokio::{
+    spawn,
+    sync::Semaphore,
+    task::{JoinHandle, JoinSet},
+    time::sleep,
+};
 
 use crate::{
+    bookmark::{Bookmark, Bookmarks},
     client::Client,
     config::Config,
+    disk_usage::UsageNode,
+    duplicate::find_duplicates,
     error::{AppError, Result},
     event::{
-        AppEventType, CompleteDownloadObjectResult, CompleteInitializeResult,
-        CompleteLoadObjectResult, CompleteLoadObjectsResult, CompletePreviewObjectResult, Sender,
+        AppEventType, CompleteBulkObjectsResult, CompleteCalculatePrefixSizeResult,
+        CompleteDiskUsageDeleteResult, CompleteDownloadObjectResult,
+        CompleteDuplicatesDeleteResult, CompleteEditObjectResult, CompleteInitializeResult,
+        CompleteLoadBucketRegionsResult, CompleteLoadBucketStatsResult,
+        CompleteLoadContentTypesResult, CompleteLoadFinderItemsResult, CompleteLoadObjectResult,
+        CompleteLoadObjectsResult, CompleteMoveToResult, CompleteOpenDiskUsageResult,
+        CompleteOpenDuplicatesResult, CompleteOpenObjectResult, CompletePreviewFollowObjectResult,
+        CompletePreviewObjectResult, CompleteTransferResult, CompleteUploadEditedObjectResult,
+        Sender,
+    },
+    external::{EditingObject, OpeningObject, TempObjectFile},
+    file::{copy_to_clipboard, join_key_path, read_local_dir, save_binary, save_error_log},
+    history::{History, VisitedLocation},
+    if_match, key_code, key_code_char,
+    object::{
+        AppObjects, BucketItem, FileDetail, LocationType, ObjectItem, ObjectKey, ObjectLocation,
+        PrefixSize, RawObject,
     },
-    file::{copy_to_clipboard, save_binary, save_error_log},
-    if_match,
-    object::{AppObjects, BucketItem, FileDetail, ObjectItem, ObjectKey, RawObject},
     pages::page::{Page, PageStack},
+    sort::{ObjectListSort, SortSettings},
+    stats::{BucketStatsBuilder, BucketStatsCache},
+    transfer::{self, list_targets, PaneLocation, TransferMode, TransferRequest},
 };
 
+const PREVIEW_FOLLOW_INTERVAL: Duration = Duration::from_secs(3);
+const BUCKET_REGION_CONCURRENCY: usize = 8;
+const CONTENT_TYPE_CONCURRENCY: usize = 8;
+const PREFIX_SEARCH_MAX_KEYS: usize = 10_000;
+
 #[derive(Debug)]
 pub enum Notification {
     None,
@@ -52,27 +82,68 @@ impl AppViewState {
 #[derive(Debug)]
 pub struct App {
     pub app_view_state: AppViewState,
+    // the page stack of the current tab
     pub page_stack: PageStack,
+    // the slot of the current tab holds a placeholder
+    tabs: Vec<PageStack>,
+    current_tab: usize,
+    pending_tab_key: Option<KeyEvent>,
+    // the unfocused pane in the dual-pane mode
+    other_pane: Option<PageStack>,
+    other_pane_left: bool,
+    pending_transfer: Option<TransferRequest>,
     app_objects: AppObjects,
     client: Option<Arc<Client>>,
     config: Config,
+    opened_files: Vec<TempObjectFile>,
+    finder_id: usize,
+    finder_task: Option<JoinHandle<()>>,
+    initial_location: Option<ObjectLocation>,
+    bookmarks: Bookmarks,
+    history: History,
+    bucket_stats: BucketStatsCache,
+    sort_settings: SortSettings,
     tx: Sender,
 }
 
 impl App {
     pub fn new(config: Config, tx: Sender, width: usize, height: usize) -> App {
+        let bookmarks = load_bookmarks(&config, &tx);
+        let history = load_history(&config, &tx);
+        let bucket_stats = load_bucket_stats(&config, &tx);
+        let sort_settings = load_sort_settings(&config, &tx);
         App {
             app_view_state: AppViewState::new(width, height),
             app_objects: AppObjects::default(),
             page_stack: PageStack::new(tx.clone()),
+            tabs: vec![PageStack::new(tx.clone())],
+            current_tab: 0,
+            pending_tab_key: None,
+            other_pane: None,
+            other_pane_left: false,
+            pending_transfer: None,
             client: None,
             config,
+            opened_files: Vec::new(),
+            finder_id: 0,
+            finder_task: None,
+            initial_location: None,
+            bookmarks,
+            history,
+            bucket_stats,
+            sort_settings,
             tx,
         }
     }
 
-    pub fn initialize(&mut self, client: Client, bucket: Option<String>) {
+    pub fn initialize(
+        &mut self,
+        client: Client,
+        bucket: Option<String>,
+        location: Option<ObjectLocation>,
+    ) {
         self.client = Some(Arc::new(client));
+        self.initial_location = location;
 
         let (client, tx) = self.unwrap_client_tx();
         spawn(async move {
@@ -99,7 +170,11 @@ impl App {
             }
         }
 
-        if self.bucket_items().len() == 1 {
+        let initial_location = self.initial_location.take();
+        if let (Some(location), false) = (initial_location, self.bucket_items().is_empty()) {
+            // s3 uri is specified, open the location directly.
+            self.move_to(location);
+        } else if self.bucket_items().len() == 1 {
             // bucket name is specified, or if there is only one bucket, open it.
             // since continues to load object, is_loading is not reset.
             self.bucket_list_move_down();
@@ -108,6 +183,46 @@ impl App {
         }
     }
 
+    pub fn load_bucket_regions(&self, names: Vec<String>) {
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            // the region of each bucket needs a request, so they are loaded concurrently
+            let semaphore = Arc::new(Semaphore::new(BUCKET_REGION_CONCURRENCY));
+            let mut set = JoinSet::new();
+            for name in names {
+                let client = client.clone();
+                let semaphore = semaphore.clone();
+                set.spawn(async move {
+                    let _permit = semaphore.acquire().await;
+                    let region = client.load_bucket_region(&name).await;
+                    (name, region)
+                });
+            }
+            let mut regions = HashMap::new();
+            while let Some(joined) = set.join_next().await {
+                // the buckets which cannot be accessed are shown without the region
+                if let Ok((name, Ok(region))) = joined {
+                    regions.insert(name, region);
+                }
+            }
+            let result = CompleteLoadBucketRegionsResult::new(regions);
+            tx.send(AppEventType::CompleteLoadBucketRegions(result));
+        });
+    }
+
+    pub fn complete_load_bucket_regions(&mut self, result: CompleteLoadBucketRegionsResult) {
+        let CompleteLoadBucketRegionsResult { regions } = result;
+        self.app_objects.set_bucket_regions(&regions);
+
+        for page_stack in self.page_stacks_mut() {
+            for page in page_stack.iter_mut() {
+                if let Page::BucketList(page) = page {
+                    page.set_regions(&regions);
+                }
+            }
+        }
+    }
+
     pub fn resize(&mut self, width: usize, height: usize) {
         self.app_view_state.reset_size(width, height);
     }
@@ -152,105 +267,1560 @@ impl App {
         }
     }
 
-    fn bucket_items(&self) -> Vec<BucketItem> {
-        self.app_objects.get_bucket_items()
-    }
+    // the key of the current object list page itself, not of its selected item
+    fn current_list_object_key(&self) -> ObjectKey {
+        list_object_key(&self.page_stack)
+    }
+
+    fn bucket_items(&self) -> Vec<BucketItem> {
+        self.app_objects.get_bucket_items()
+    }
+
+    fn current_object_items(&self) -> Option<Vec<ObjectItem>> {
+        self.app_objects
+            .get_object_items(&self.current_object_key())
+    }
+
+    pub fn open_bucket_stats(&mut self) {
+        let bucket_name = self.current_bucket();
+        if let Some(stats) = self.bucket_stats.get(&bucket_name) {
+            // calculated before, possibly in the previous sessions
+            let page = Page::of_bucket_stats(stats.clone(), self.tx.clone());
+            self.page_stack.push(page);
+            return;
+        }
+        self.load_bucket_stats(bucket_name);
+    }
+
+    pub fn load_bucket_stats(&mut self, bucket_name: String) {
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let mut builder = BucketStatsBuilder::new(bucket_name.clone(), Local::now());
+            let result = client
+                .load_object_summaries_with(&bucket_name, "", |objs| {
+                    for obj in &objs {
+                        builder.add(obj);
+                    }
+                    let msg = format!("Scanned {} objects", builder.count());
+                    tx.send(AppEventType::NotifyInfo(msg));
+                })
+                .await;
+            let result = CompleteLoadBucketStatsResult::new(result.map(|_| builder.build()));
+            tx.send(AppEventType::CompleteLoadBucketStats(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_load_bucket_stats(&mut self, result: Result<CompleteLoadBucketStatsResult>) {
+        self.app_view_state.is_loading = false;
+        match result {
+            Ok(CompleteLoadBucketStatsResult { stats }) => {
+                if let Err(e) = self.bucket_stats.set(stats.clone()) {
+                    self.tx.send(AppEventType::NotifyError(e));
+                }
+                match self.page_stack.current_page_mut() {
+                    Page::BucketStats(page) if page.bucket_name() == stats.bucket_name => {
+                        page.set_stats(stats);
+                    }
+                    _ => {
+                        let page = Page::of_bucket_stats(stats, self.tx.clone());
+                        self.page_stack.push(page);
+                    }
+                }
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn bucket_list_move_down(&mut self) {
+        if let Some(current_object_items) = self.current_object_items() {
+            // object list has been already loaded
+            let key = self.current_object_key();
+            let object_list_page = self.new_object_list_page(&key, current_object_items);
+            self.page_stack.push(object_list_page);
+        } else {
+            self.tx.send(AppEventType::LoadObjects);
+            self.app_view_state.is_loading = true;
+        }
+    }
+
+    pub fn object_list_move_down(&mut self) {
+        let object_page = self.page_stack.current_page().as_object_list();
+        let selected = object_page.current_selected_item().to_owned();
+
+        match selected {
+            ObjectItem::File { name, .. } => {
+                let current_object_key = &self.current_object_key_with_name(name.to_string());
+                let detail = self.app_objects.get_object_detail(current_object_key);
+                let versions = self.app_objects.get_object_versions(current_object_key);
+
+                if let (Some(detail), Some(versions)) = (detail, versions) {
+                    // object has been already loaded
+                    let object_detail_page = Page::of_object_detail(
+                        detail.clone(),
+                        versions.clone(),
+                        object_page.object_list(),
+                        object_page.list_state(),
+                        self.tx.clone(),
+                    );
+                    self.page_stack.push(object_detail_page);
+                } else {
+                    self.tx.send(AppEventType::LoadObject);
+                    self.app_view_state.is_loading = true;
+                }
+            }
+            ObjectItem::Dir { .. } => {
+                if let Some(current_object_items) = self.current_object_items() {
+                    // object list has been already loaded
+                    let key = self.current_object_key();
+                    let object_list_page = self.new_object_list_page(&key, current_object_items);
+                    self.page_stack.push(object_list_page);
+                } else {
+                    self.tx.send(AppEventType::LoadObjects);
+                    self.app_view_state.is_loading = true;
+                }
+            }
+        }
+    }
+
+    // the page shows the cached sizes of the prefixes and content types of the objects
+    fn new_object_list_page(&self, key: &ObjectKey, items: Vec<ObjectItem>) -> Page {
+        let mut page = Page::of_object_list(items, self.tx.clone());
+        let prefix_sizes = self.app_objects.get_prefix_sizes(key);
+        page.as_mut_object_list().set_prefix_sizes(prefix_sizes);
+        let content_types = self.app_objects.get_content_types(key);
+        page.as_mut_object_list().set_content_types(content_types);
+        page.as_mut_object_list()
+            .set_columns(self.config.list.columns());
+        if let Some(sort) = self.sort_settings.get(&key.bucket_name) {
+            page.as_mut_object_list().set_sort(sort);
+        }
+        page
+    }
+
+    pub fn object_list_save_sort(&mut self, sort: ObjectListSort) {
+        let bucket_name = self.current_list_object_key().bucket_name;
+        if let Err(e) = self.sort_settings.set(bucket_name, sort) {
+            self.tx.send(AppEventType::NotifyError(e));
+        }
+    }
+
+    pub fn object_list_move_up(&mut self) {
+        if self.page_stack.len() == 2 /* bucket list and object list */ && self.bucket_items().len() == 1
+        {
+            return;
+        }
+        self.page_stack.pop();
+    }
+
+    pub fn back_to_bucket_list(&mut self) {
+        if self.bucket_items().len() == 1 {
+            return;
+        }
+        self.page_stack.clear();
+    }
+
+    pub fn load_objects(&self) {
+        let bucket = self.current_bucket();
+        let prefix = self.current_object_prefix();
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let items = client.load_objects(&bucket, &prefix).await;
+            let result = CompleteLoadObjectsResult::new(items);
+            tx.send(AppEventType::CompleteLoadObjects(result));
+        });
+    }
+
+    pub fn complete_load_objects(&mut self, result: Result<CompleteLoadObjectsResult>) {
+        match result {
+            Ok(CompleteLoadObjectsResult { items }) => {
+                let key = self.current_object_key();
+                self.app_objects
+                    .set_object_items(key.clone(), items.clone());
+
+                let object_list_page = self.new_object_list_page(&key, items);
+                self.page_stack.push(object_list_page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn load_objects_recursive(&mut self) {
+        let key = self.current_list_object_key();
+        if let Some(items) = self.app_objects.get_recursive_object_items(&key) {
+            // recursive object list has been already loaded
+            let object_page = self.page_stack.current_page_mut().as_mut_object_list();
+            object_page.set_recursive_items(items);
+            return;
+        }
+
+        let prefix = key.prefix();
+        let bucket = key.bucket_name;
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let items = client.load_objects_recursive(&bucket, &prefix).await;
+            let result = CompleteLoadObjectsResult::new(items);
+            tx.send(AppEventType::CompleteLoadObjectsRecursive(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_load_objects_recursive(&mut self, result: Result<CompleteLoadObjectsResult>) {
+        match result {
+            Ok(CompleteLoadObjectsResult { items }) => {
+                let key = self.current_list_object_key();
+                self.app_objects
+                    .set_recursive_object_items(key, items.clone());
+
+                let object_page = self.page_stack.current_page_mut().as_mut_object_list();
+                object_page.set_recursive_items(items);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn load_finder_items(&mut self) {
+        self.abort_finder_task();
+        self.finder_id += 1;
+
+        let key = self.current_list_object_key();
+        if let Some(items) = self.app_objects.get_recursive_object_items(&key) {
+            // recursive object list has been already loaded
+            let object_page = self.page_stack.current_page_mut().as_mut_object_list();
+            object_page.add_finder_items(items, true, false);
+            return;
+        }
+
+        let finder_id = self.finder_id;
+        let prefix = key.prefix();
+        let bucket = key.bucket_name;
+        let (client, tx) = self.unwrap_client_tx();
+        let task = spawn(async move {
+            let result = client
+                .load_objects_recursive_with(&bucket, &prefix, |items| {
+                    let result =
+                        CompleteLoadFinderItemsResult::new(Ok(items), finder_id, false, false);
+                    tx.send(AppEventType::CompleteLoadFinderItems(result));
+                })
+                .await;
+            let result =
+                CompleteLoadFinderItemsResult::new(result.map(|_| vec![]), finder_id, true, false);
+            tx.send(AppEventType::CompleteLoadFinderItems(result));
+        });
+        self.finder_task = Some(task);
+    }
+
+    pub fn search_finder_items(&mut self, query: String) {
+        // the listing for the previous query is no longer needed
+        self.abort_finder_task();
+        self.finder_id += 1;
+        if query.is_empty() {
+            return;
+        }
+
+        let finder_id = self.finder_id;
+        let key = self.current_list_object_key();
+        let (client, tx) = self.unwrap_client_tx();
+        let task = spawn(async move {
+            let result = client
+                .search_objects_with(
+                    &key.bucket_name,
+                    &key.prefix(),
+                    &query,
+                    PREFIX_SEARCH_MAX_KEYS,
+                    |items| {
+                        let result =
+                            CompleteLoadFinderItemsResult::new(Ok(items), finder_id, false, false);
+                        tx.send(AppEventType::CompleteLoadFinderItems(result));
+                    },
+                )
+                .await;
+            let result = match result {
+                Ok(truncated) => {
+                    CompleteLoadFinderItemsResult::new(Ok(vec![]), finder_id, true, truncated)
+                }
+                Err(e) => Err(e),
+            };
+            tx.send(AppEventType::CompleteLoadFinderItems(result));
+        });
+        self.finder_task = Some(task);
+    }
+
+    pub fn complete_load_finder_items(&mut self, result: Result<CompleteLoadFinderItemsResult>) {
+        let current_page = self.page_stack.current_page_mut();
+        let page = if_match! { current_page: Page::ObjectList(p) => p };
+        match result {
+            Ok(CompleteLoadFinderItemsResult {
+                items,
+                finder_id,
+                done,
+                truncated,
+            }) => {
+                if finder_id != self.finder_id {
+                    // the result of the finder that has been already closed
+                    return;
+                }
+                if let Some(page) = page {
+                    page.add_finder_items(items, done, truncated);
+                }
+                if done {
+                    self.finder_task = None;
+                }
+            }
+            Err(e) => {
+                if let Some(page) = page {
+                    page.add_finder_items(vec![], true, false);
+                }
+                self.finder_task = None;
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    fn abort_finder_task(&mut self) {
+        if let Some(task) = self.finder_task.take() {
+            task.abort();
+        }
+    }
+
+    pub fn object_list_move_to_object(&mut self, key: String) {
+        let mut object_key = self.current_list_object_key();
+        object_key
+            .object_path
+            .extend(key.split('/').map(String::from));
+        let location = ObjectLocation {
+            key: object_key,
+            location_type: LocationType::Object,
+        };
+        self.move_to(location);
+    }
+
+    pub fn move_to_input(&mut self, input: String) {
+        match ObjectLocation::parse(&input) {
+            Some(location) => self.move_to(location),
+            None => {
+                let msg = format!("Invalid location: {}", input);
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+        }
+    }
+
+    // open the object list (or the object detail) of the location,
+    // loading the missing lists on the way
+    fn move_to(&mut self, location: ObjectLocation) {
+        self.abort_finder_task();
+
+        let key = &location.key;
+        if !self
+            .bucket_items()
+            .iter()
+            .any(|item| item.name == key.bucket_name)
+        {
+            let msg = format!("Bucket not found: {}", key.bucket_name);
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+
+        let list_count = match location.location_type {
+            LocationType::Object => key.object_path.len(),
+            // an unknown location may be a prefix, so its list is also loaded
+            LocationType::Prefix | LocationType::Unknown => key.object_path.len() + 1,
+        };
+        let load_keys: Vec<ObjectKey> = (0..list_count)
+            .map(|i| ObjectKey {
+                bucket_name: key.bucket_name.clone(),
+                object_path: key.object_path[..i].to_vec(),
+            })
+            .filter(|k| self.app_objects.get_object_items(k).is_none())
+            .collect();
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let mut loaded = Vec::new();
+            let mut result = Ok(());
+            for load_key in load_keys {
+                let prefix = load_key.prefix();
+                match client.load_objects(&load_key.bucket_name, &prefix).await {
+                    Ok(items) => loaded.push((load_key, items)),
+                    Err(e) => {
+                        result = Err(e);
+                        break;
+                    }
+                }
+            }
+            let result = CompleteMoveToResult::new(location, result.map(|_| loaded));
+            tx.send(AppEventType::CompleteMoveTo(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_move_to(&mut self, result: Result<CompleteMoveToResult>) {
+        self.app_view_state.is_loading = false;
+        match result {
+            Ok(CompleteMoveToResult { location, loaded }) => {
+                let key = &location.key;
+                for (load_key, items) in loaded {
+                    self.app_objects.set_object_items(load_key, items);
+                }
+
+                let location_type = match location.location_type {
+                    LocationType::Unknown => self.resolve_location_type(key),
+                    location_type => location_type,
+                };
+                if location_type == LocationType::Object {
+                    // the list loaded in case of a prefix is empty
+                    self.app_objects.remove_object_items(key);
+                }
+
+                if let Err(e) = self.rebuild_page_stack(key, location_type) {
+                    self.tx.send(AppEventType::NotifyError(e));
+                    return;
+                }
+                if location_type == LocationType::Object {
+                    self.object_list_move_down();
+                }
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    fn resolve_location_type(&self, key: &ObjectKey) -> LocationType {
+        let (name, parent_path) = match key.object_path.split_last() {
+            Some(split) => split,
+            None => return LocationType::Prefix,
+        };
+        let parent_key = ObjectKey {
+            bucket_name: key.bucket_name.clone(),
+            object_path: parent_path.to_vec(),
+        };
+        let items = self
+            .app_objects
+            .get_object_items(&parent_key)
+            .unwrap_or_default();
+        let is_file = items
+            .iter()
+            .any(|item| matches!(item, ObjectItem::File { name: n, .. } if n == name));
+        // if neither is found, it is reported as not found prefix
+        if is_file {
+            LocationType::Object
+        } else {
+            LocationType::Prefix
+        }
+    }
+
+    fn rebuild_page_stack(&mut self, key: &ObjectKey, location_type: LocationType) -> Result<()> {
+        let not_found = || {
+            let path = key.object_path.join("/");
+            AppError::msg(format!("Not found: s3://{}/{}", key.bucket_name, path))
+        };
+
+        let is_file = location_type == LocationType::Object;
+        let dir_count = key.object_path.len() - usize::from(is_file);
+        let mut pages = Vec::new();
+        for i in 0..=dir_count {
+            let list_key = ObjectKey {
+                bucket_name: key.bucket_name.clone(),
+                object_path: key.object_path[..i].to_vec(),
+            };
+            let items = self
+                .app_objects
+                .get_object_items(&list_key)
+                .ok_or_else(not_found)?;
+            let mut page = self.new_object_list_page(&list_key, items);
+            let object_page = page.as_mut_object_list();
+            let found = if i < dir_count {
+                object_page.select_dir(&key.object_path[i])
+            } else if is_file {
+                object_page.select_file(&key.object_path[i])
+            } else {
+                true
+            };
+            if !found {
+                return Err(not_found());
+            }
+            pages.push(page);
+        }
+
+        self.page_stack.clear();
+        let bucket_page = self.page_stack.head_mut().as_mut_bucket_list();
+        if !bucket_page.select_item(&key.bucket_name) {
+            return Err(not_found());
+        }
+        for page in pages {
+            self.page_stack.push(page);
+        }
+        Ok(())
+    }
+
+    // returns the keys to be passed to the current page
+    pub fn handle_tab_key(&mut self, key: KeyEvent) -> Vec<KeyEvent> {
+        if !self.page_stack.current_page().accepts_global_keys() {
+            return vec![key];
+        }
+        if let Some(pending_key) = self.pending_tab_key.take() {
+            match key {
+                key_code_char!('t') => self.select_next_tab(),
+                key_code_char!('T') => self.select_prev_tab(),
+                _ => return vec![pending_key, key],
+            }
+            return Vec::new();
+        }
+        let has_tabs = self.tabs.len() > 1;
+        match key {
+            key_code_char!('t', Ctrl) => self.open_tab(),
+            key_code_char!('w', Ctrl) if has_tabs => self.close_tab(),
+            // `g` alone is used to go to the top, so it waits for the next key
+            key_code_char!('g') if has_tabs => self.pending_tab_key = Some(key),
+            KeyEvent {
+                code: KeyCode::Char(c @ '1'..='9'),
+                ..
+            } if has_tabs => {
+                let index = c as usize - '1' as usize;
+                self.select_tab(index);
+            }
+            _ => return vec![key],
+        }
+        Vec::new()
+    }
+
+    pub fn tab_stacks(&self) -> Vec<&PageStack> {
+        self.tabs
+            .iter()
+            .enumerate()
+            .map(|(i, stack)| {
+                if i == self.current_tab {
+                    &self.page_stack
+                } else {
+                    stack
+                }
+            })
+            .collect()
+    }
+
+    pub fn current_tab(&self) -> usize {
+        self.current_tab
+    }
+
+    // the new tab starts at the location of the current tab
+    fn open_tab(&mut self) {
+        let location = self.current_location();
+        let page_stack = self.bucket_list_page_stack();
+
+        self.abort_finder_task();
+        let index = self.current_tab + 1;
+        self.tabs.insert(index, PageStack::new(self.tx.clone()));
+        self.tabs[self.current_tab] = std::mem::replace(&mut self.page_stack, page_stack);
+        self.current_tab = index;
+
+        if let Some(location) = location {
+            self.move_to(location.location());
+        }
+    }
+
+    fn close_tab(&mut self) {
+        self.abort_finder_task();
+        self.tabs.remove(self.current_tab);
+        let index = self.current_tab.min(self.tabs.len() - 1);
+        let placeholder = PageStack::new(self.tx.clone());
+        self.page_stack = std::mem::replace(&mut self.tabs[index], placeholder);
+        self.current_tab = index;
+    }
+
+    fn select_tab(&mut self, index: usize) {
+        if index == self.current_tab || index >= self.tabs.len() {
+            return;
+        }
+        self.abort_finder_task();
+        let placeholder = PageStack::new(self.tx.clone());
+        let page_stack = std::mem::replace(&mut self.tabs[index], placeholder);
+        self.tabs[self.current_tab] = std::mem::replace(&mut self.page_stack, page_stack);
+        self.current_tab = index;
+    }
+
+    fn select_next_tab(&mut self) {
+        self.select_tab((self.current_tab + 1) % self.tabs.len());
+    }
+
+    fn select_prev_tab(&mut self) {
+        self.select_tab((self.current_tab + self.tabs.len() - 1) % self.tabs.len());
+    }
+
+    // the stacks of the current tab, the other pane and the other tabs
+    fn page_stacks(&self) -> impl Iterator<Item = &PageStack> {
+        std::iter::once(&self.page_stack)
+            .chain(self.other_pane.iter())
+            .chain(self.tabs.iter())
+    }
+
+    fn page_stacks_mut(&mut self) -> impl Iterator<Item = &mut PageStack> {
+        std::iter::once(&mut self.page_stack)
+            .chain(self.other_pane.iter_mut())
+            .chain(self.tabs.iter_mut())
+    }
+
+    fn bucket_list_page_stack(&self) -> PageStack {
+        let mut page_stack = PageStack::new(self.tx.clone());
+        page_stack.pop(); // remove initializing page
+        page_stack.push(Page::of_bucket_list(self.bucket_items(), self.tx.clone()));
+        page_stack
+    }
+
+    // returns true if the key is consumed
+    pub fn handle_pane_key(&mut self, key: KeyEvent) -> bool {
+        if let Some(request) = self.pending_transfer.take() {
+            // any key other than `y` cancels
+            if matches!(key, key_code_char!('y')) {
+                self.transfer(request);
+            }
+            return true;
+        }
+        if !self.page_stack.current_page().accepts_global_keys() {
+            return false;
+        }
+        let is_dual = self.other_pane.is_some();
+        match key {
+            key_code_char!('d', Ctrl) => self.toggle_dual_pane(),
+            key_code_char!('l', Ctrl) => self.toggle_local_pane(),
+            key_code!(KeyCode::Tab) if is_dual => self.switch_pane(),
+            key_code!(KeyCode::F(5)) if is_dual => self.prepare_transfer(TransferMode::Copy),
+            key_code!(KeyCode::F(6)) if is_dual => self.prepare_transfer(TransferMode::Move),
+            key_code!(KeyCode::F(7)) if is_dual => self.prepare_transfer(TransferMode::Sync),
+            _ => return false,
+        }
+        true
+    }
+
+    // returns the left and right panes in the dual-pane mode
+    pub fn panes_mut(&mut self) -> Option<(&mut PageStack, &mut PageStack)> {
+        let other_pane = self.other_pane.as_mut()?;
+        if self.other_pane_left {
+            Some((other_pane, &mut self.page_stack))
+        } else {
+            Some((&mut self.page_stack, other_pane))
+        }
+    }
+
+    pub fn is_left_pane_focused(&self) -> bool {
+        !self.other_pane_left
+    }
+
+    pub fn pending_transfer_message(&self) -> Option<String> {
+        self.pending_transfer
+            .as_ref()
+            .map(TransferRequest::confirm_message)
+    }
+
+    // the new pane starts at the location of the current pane, and gets the focus
+    fn toggle_dual_pane(&mut self) {
+        if self.other_pane.take().is_some() {
+            return;
+        }
+        let location = self.current_location();
+        let page_stack = self.bucket_list_page_stack();
+
+        self.abort_finder_task();
+        self.other_pane = Some(std::mem::replace(&mut self.page_stack, page_stack));
+        self.other_pane_left = true;
+
+        if let Some(location) = location {
+            self.move_to(location.location());
+        }
+    }
+
+    // switch the current pane between the local directory and the bucket list
+    fn toggle_local_pane(&mut self) {
+        let page_stack = if matches!(self.page_stack.current_page(), Page::LocalList(_)) {
+            self.bucket_list_page_stack()
+        } else {
+            let result = std::env::current_dir()
+                .map_err(|e| AppError::new("Failed to get current directory", e))
+                .and_then(|path| read_local_dir(&path).map(|items| (path, items)));
+            let (path, items) = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    self.tx.send(AppEventType::NotifyError(e));
+                    return;
+                }
+            };
+            let mut page_stack = PageStack::new(self.tx.clone());
+            page_stack.pop(); // remove initializing page
+            page_stack.push(Page::of_local_list(path, items, self.tx.clone()));
+            page_stack
+        };
+        self.abort_finder_task();
+        self.page_stack = page_stack;
+    }
+
+    fn switch_pane(&mut self) {
+        self.abort_finder_task();
+        if let Some(other_pane) = self.other_pane.as_mut() {
+            std::mem::swap(&mut self.page_stack, other_pane);
+            self.other_pane_left = !self.other_pane_left;
+        }
+    }
+
+    // the transfer is executed after the confirmation
+    fn prepare_transfer(&mut self, mode: TransferMode) {
+        let Some(other_pane) = &self.other_pane else {
+            return;
+        };
+        let (Some(src), Some(dest)) = (pane_location(&self.page_stack), pane_location(other_pane))
+        else {
+            let msg = "Both panes must show an object list or a local directory".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        };
+        if src.is_same(&dest) {
+            let msg = "Source and destination are the same".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let items = match self.page_stack.current_page() {
+            Page::ObjectList(page) if page.non_empty() => page.target_items(),
+            Page::LocalList(page) => page.target_items(),
+            _ => Vec::new(),
+        };
+        if items.is_empty() {
+            let msg = "No items to transfer".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        if let Some(name) = transfer::dest_inside_items(&src, &items, &dest) {
+            let msg = format!("Cannot transfer {}/ into itself", name);
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        self.pending_transfer = Some(TransferRequest {
+            mode,
+            src,
+            items,
+            dest,
+        });
+    }
+
+    fn transfer(&mut self, request: TransferRequest) {
+        let TransferRequest {
+            mode,
+            src,
+            items,
+            dest,
+        } = request;
+        if let Page::ObjectList(page) = self.page_stack.current_page_mut() {
+            page.clear_marks();
+        }
+
+        // the lists changed by the transfer are reloaded after it
+        let mut reload_keys = Vec::new();
+        if let PaneLocation::S3(key) = &dest {
+            reload_keys.push(key.clone());
+        }
+        if let (TransferMode::Move, PaneLocation::S3(key)) = (mode, &src) {
+            reload_keys.push(key.clone());
+        }
+        for key in &reload_keys {
+            self.app_objects.remove_objects_under(key);
+        }
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let msg = transfer::transfer(&client, mode, &src, items, &dest, |msg| {
+                tx.send(AppEventType::NotifyInfo(msg))
+            })
+            .await;
+            let mut loaded = Vec::new();
+            if msg.is_ok() {
+                for key in reload_keys {
+                    // if it fails, the list is loaded again when it is opened next time
+                    if let Ok(items) = client.load_objects(&key.bucket_name, &key.prefix()).await {
+                        loaded.push((key, items));
+                    }
+                }
+            }
+            let result = CompleteTransferResult::new(msg, loaded);
+            tx.send(AppEventType::CompleteTransfer(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_transfer(&mut self, result: Result<CompleteTransferResult>) {
+        self.app_view_state.is_loading = false;
+        match result {
+            Ok(CompleteTransferResult { msg, loaded }) => {
+                for (key, items) in loaded {
+                    self.app_objects
+                        .set_object_items(key.clone(), items.clone());
+                    for page_stack in self.page_stacks_mut() {
+                        if pane_location(page_stack) == Some(PaneLocation::S3(key.clone())) {
+                            let page = page_stack.current_page_mut().as_mut_object_list();
+                            page.set_items(items.clone());
+                        }
+                    }
+                }
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+
+        for page_stack in self.page_stacks_mut() {
+            if let Page::LocalList(page) = page_stack.current_page_mut() {
+                page.reload();
+            }
+        }
+    }
+
+    pub fn add_bookmark(&mut self) {
+        let bookmark = match self.page_stack.current_page() {
+            Page::ObjectList(_) => Bookmark::new(self.current_list_object_key(), false),
+            Page::ObjectDetail(page) => {
+                let key = ObjectKey {
+                    bucket_name: self.current_bucket(),
+                    object_path: page
+                        .file_detail()
+                        .key
+                        .split('/')
+                        .map(String::from)
+                        .collect(),
+                };
+                Bookmark::new(key, true)
+            }
+            _ => return,
+        };
+        let uri = bookmark.uri();
+        match self.bookmarks.add(bookmark) {
+            Ok(true) => {
+                let msg = format!("Bookmarked {}", uri);
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Ok(false) => {
+                let msg = format!("{} is already bookmarked", uri);
+                self.tx.send(AppEventType::NotifyInfo(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn open_bookmarks(&mut self) {
+        let bookmarks = self.bookmarks.items().to_vec();
+        let bookmarks_page = Page::of_bookmarks(bookmarks, self.tx.clone());
+        self.page_stack.push(bookmarks_page);
+    }
+
+    pub fn bookmarks_move_to(&mut self, location: ObjectLocation) {
+        self.move_to(location);
+    }
+
+    pub fn rename_bookmark(&mut self, index: usize, label: Option<String>) {
+        let result = self.bookmarks.rename(index, label);
+        self.complete_update_bookmarks(result);
+    }
+
+    pub fn delete_bookmark(&mut self, index: usize) {
+        let result = self.bookmarks.delete(index);
+        self.complete_update_bookmarks(result);
+    }
+
+    fn complete_update_bookmarks(&mut self, result: Result<()>) {
+        if let Err(e) = result {
+            self.tx.send(AppEventType::NotifyError(e));
+        }
+        let bookmarks = self.bookmarks.items().to_vec();
+        if let Page::Bookmarks(page) = self.page_stack.current_page_mut() {
+            page.set_bookmarks(bookmarks);
+        }
+    }
+
+    // called before every render, so any navigation is recorded
+    pub fn update_history(&mut self) {
+        if self.app_view_state.is_loading {
+            return;
+        }
+        if let Some(location) = self.current_location() {
+            if let Err(e) = self.history.visit(location) {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    fn current_location(&self) -> Option<VisitedLocation> {
+        let (index, page) = self
+            .page_stack
+            .iter()
+            .enumerate()
+            .rfind(|(_, page)| matches!(page, Page::ObjectList(_) | Page::ObjectDetail(_)))?;
+        let bucket_name = self.current_bucket();
+        match page {
+            Page::ObjectList(_) => {
+                let object_path = self
+                    .page_stack
+                    .iter()
+                    .take(index)
+                    .filter_map(|page| if_match! { page: Page::ObjectList(p) => p })
+                    .map(|page| page.current_selected_item())
+                    .filter_map(
+                        |item| if_match! { item: ObjectItem::Dir { name, .. } => name.to_string() },
+                    )
+                    .collect();
+                let key = ObjectKey {
+                    bucket_name,
+                    object_path,
+                };
+                Some(VisitedLocation::new(key, false))
+            }
+            Page::ObjectDetail(page) => {
+                let object_path = page
+                    .file_detail()
+                    .key
+                    .split('/')
+                    .map(String::from)
+                    .collect();
+                let key = ObjectKey {
+                    bucket_name,
+                    object_path,
+                };
+                Some(VisitedLocation::new(key, true))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn history_back(&mut self) {
+        match self.history.back() {
+            Some(location) => {
+                let location = location.location();
+                self.move_to(location);
+            }
+            None => {
+                let msg = "No previous location".to_string();
+                self.tx.send(AppEventType::NotifyInfo(msg));
+            }
+        }
+    }
+
+    pub fn history_forward(&mut self) {
+        match self.history.forward() {
+            Some(location) => {
+                let location = location.location();
+                self.move_to(location);
+            }
+            None => {
+                let msg = "No next location".to_string();
+                self.tx.send(AppEventType::NotifyInfo(msg));
+            }
+        }
+    }
+
+    pub fn open_history(&mut self) {
+        let locations = self.history.recent().to_vec();
+        let history_page = Page::of_history(locations, self.tx.clone());
+        self.page_stack.push(history_page);
+    }
+
+    pub fn history_move_to(&mut self, location: ObjectLocation) {
+        self.move_to(location);
+    }
+
+    pub fn object_list_download_objects(&mut self, items: Vec<ObjectItem>) {
+        let list_key = self.current_list_object_key();
+        let config = self.config.clone();
+        self.spawn_bulk_objects(move |client, tx| async move {
+            let bucket = &list_key.bucket_name;
+            let prefix = list_key.prefix();
+            let src = PaneLocation::S3(list_key.clone());
+            let targets = list_targets(&client, &src, items).await?;
+            let total = targets.len();
+            let download_dir = Path::new(&config.download_dir);
+            // the keys which would be saved outside of the download directory are skipped
+            let mut skipped = Vec::new();
+            for (i, (name, size_byte)) in targets.into_iter().enumerate() {
+                let msg = format!("Downloading {}/{} objects", i + 1, total);
+                tx.send(AppEventType::NotifyInfo(msg));
+
+                let Ok(path) = join_key_path(download_dir, &name) else {
+                    skipped.push(name);
+                    continue;
+                };
+                let key = format!("{}{}", prefix, name);
+                let obj = client
+                    .download_object(bucket, &key, None, size_byte, |_| {})
+                    .await?;
+                save_binary(&path.to_string_lossy(), &obj.bytes)?;
+            }
+            if !skipped.is_empty() {
+                let msg = format!(
+                    "Downloaded {} of {} objects to {}, skipped the objects with unsafe keys: {}",
+                    total - skipped.len(),
+                    total,
+                    config.download_dir,
+                    skipped.join(", ")
+                );
+                return Err(AppError::msg(msg));
+            }
+            Ok(format!(
+                "Downloaded {} objects to {}",
+                total, config.download_dir
+            ))
+        });
+    }
+
+    pub fn object_list_delete_objects(&mut self, items: Vec<ObjectItem>) {
+        let list_key = self.current_list_object_key();
+        self.app_objects.remove_objects_under(&list_key);
+        self.spawn_bulk_objects(move |client, tx| async move {
+            let src = PaneLocation::S3(list_key);
+            let targets = list_targets(&client, &src, items.clone()).await?;
+            let total = targets.len();
+            let msg = format!("Deleting {} objects", total);
+            tx.send(AppEventType::NotifyInfo(msg));
+
+            transfer::delete_items(&client, &src, &items, &targets).await?;
+            Ok(format!("Deleted {} objects", total))
+        });
+    }
+
+    pub fn object_list_copy_objects(&mut self, items: Vec<ObjectItem>, input: String) {
+        let Some(dest) = ObjectLocation::parse(&input) else {
+            let msg = format!("Invalid location: {}", input);
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        };
+        let list_key = self.current_list_object_key();
+        // the destination is always treated as a prefix
+        let dest_key = dest.key;
+        self.app_objects.remove_objects_under(&dest_key);
+        self.spawn_bulk_objects(move |client, tx| async move {
+            let src = PaneLocation::S3(list_key);
+            let dest = PaneLocation::S3(dest_key);
+            transfer::transfer(&client, TransferMode::Copy, &src, items, &dest, |msg| {
+                tx.send(AppEventType::NotifyInfo(msg))
+            })
+            .await
+        });
+    }
+
+    pub fn object_list_calculate_prefix_sizes(&mut self, names: Vec<String>) {
+        let list_key = self.current_list_object_key();
+        let mut keys = Vec::new();
+        for name in names {
+            let mut key = list_key.clone();
+            key.object_path.push(name);
+            if let Some(items) = self.app_objects.get_recursive_object_items(&key) {
+                // recursive object list has been already loaded
+                let size = PrefixSize::of_items(&items);
+                self.complete_calculate_prefix_size(CompleteCalculatePrefixSizeResult::new(
+                    key,
+                    Ok(size),
+                ));
+            } else {
+                keys.push(key);
+            }
+        }
+        if keys.is_empty() {
+            return;
+        }
+
+        // calculated in the background without blocking the key inputs
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            for key in keys {
+                let mut size = PrefixSize::default();
+                let result = client
+                    .load_objects_recursive_with(&key.bucket_name, &key.prefix(), |items| {
+                        size += PrefixSize::of_items(&items);
+                    })
+                    .await;
+                let failed = result.is_err();
+                let result = CompleteCalculatePrefixSizeResult::new(key, result.map(|_| size));
+                tx.send(AppEventType::CompleteCalculatePrefixSize(result));
+                if failed {
+                    break;
+                }
+            }
+        });
+    }
+
+    pub fn complete_calculate_prefix_size(
+        &mut self,
+        result: Result<CompleteCalculatePrefixSizeResult>,
+    ) {
+        match result {
+            Ok(CompleteCalculatePrefixSizeResult { key, size }) => {
+                self.app_objects.set_prefix_size(key.clone(), size);
+
+                let Some((name, parent_path)) = key.object_path.split_last() else {
+                    return;
+                };
+                let parent_key = ObjectKey {
+                    bucket_name: key.bucket_name.clone(),
+                    object_path: parent_path.to_vec(),
+                };
+                // the size is shown in every list of the parent, including the other tabs
+                for page_stack in self.page_stacks_mut() {
+                    let indices: Vec<usize> = object_list_keys(page_stack)
+                        .into_iter()
+                        .filter(|(_, k)| *k == parent_key)
+                        .map(|(i, _)| i)
+                        .collect();
+                    for (i, page) in page_stack.iter_mut().enumerate() {
+                        if indices.contains(&i) {
+                            page.as_mut_object_list()
+                                .set_prefix_size(name.clone(), size);
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                for page_stack in self.page_stacks_mut() {
+                    for page in page_stack.iter_mut() {
+                        if let Page::ObjectList(page) = page {
+                            page.cancel_prefix_size_calculations();
+                        }
+                    }
+                }
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn object_list_load_content_types(&mut self) {
+        // the lists in the other panes are also rendered
+        let mut requests = Vec::new();
+        for page_stack in self.page_stacks_mut() {
+            let list_keys: HashMap<usize, ObjectKey> =
+                object_list_keys(page_stack).into_iter().collect();
+            for (i, page) in page_stack.iter_mut().enumerate() {
+                if let Some(list_key) = list_keys.get(&i) {
+                    let names = page.as_mut_object_list().take_content_type_requests();
+                    if !names.is_empty() {
+                        requests.push((list_key.clone(), names));
+                    }
+                }
+            }
+        }
+
+        for (list_key, names) in requests {
+            // the content types of the objects whose details have been loaded are cached
+            let cached = self.app_objects.get_content_types(&list_key);
+            let (cached_names, names): (Vec<String>, Vec<String>) = names
+                .into_iter()
+                .partition(|name| cached.contains_key(name));
+            if !cached_names.is_empty() {
+                let content_types = cached_names
+                    .into_iter()
+                    .map(|name| {
+                        let content_type = cached.get(&name).cloned();
+                        (name, content_type)
+                    })
+                    .collect();
+                let result = CompleteLoadContentTypesResult::new(list_key.clone(), content_types);
+                self.complete_load_content_types(result);
+            }
+            if names.is_empty() {
+                continue;
+            }
+
+            let (client, tx) = self.unwrap_client_tx();
+            spawn(async move {
+                // the content type of each object needs a request, so they are loaded concurrently
+                let semaphore = Arc::new(Semaphore::new(CONTENT_TYPE_CONCURRENCY));
+                let mut set = JoinSet::new();
+                for name in names {
+                    let client = client.clone();
+                    let semaphore = semaphore.clone();
+                    let bucket = list_key.bucket_name.clone();
+                    let key = format!("{}{}", list_key.prefix(), name);
+                    set.spawn(async move {
+                        let _permit = semaphore.acquire().await;
+                        let content_type = client.load_object_content_type(&bucket, &key).await;
+                        (name, content_type)
+                    });
+                }
+                let mut content_types = HashMap::new();
+                let mut error = None;
+                while let Some(joined) = set.join_next().await {
+                    let Ok((name, content_type)) = joined else {
+                        continue;
+                    };
+                    match content_type {
+                        Ok(content_type) => {
+                            content_types.insert(name, Some(content_type));
+                        }
+                        Err(e) => {
+                            content_types.insert(name, None);
+                            error.get_or_insert(e);
+                        }
+                    }
+                }
+                let result = CompleteLoadContentTypesResult::new(list_key, content_types);
+                tx.send(AppEventType::CompleteLoadContentTypes(result));
+                // notified only once even if all the requests failed
+                if let Some(e) = error {
+                    tx.send(AppEventType::NotifyError(e));
+                }
+            });
+        }
+    }
+
+    pub fn complete_load_content_types(&mut self, result: CompleteLoadContentTypesResult) {
+        let CompleteLoadContentTypesResult { key, content_types } = result;
+        for (name, content_type) in &content_types {
+            if let Some(content_type) = content_type {
+                let mut object_key = key.clone();
+                object_key.object_path.push(name.clone());
+                self.app_objects
+                    .set_content_type(object_key, content_type.clone());
+            }
+        }
+
+        // the content types are shown in every list of the prefix, including the other tabs
+        for page_stack in self.page_stacks_mut() {
+            let indices: Vec<usize> = object_list_keys(page_stack)
+                .into_iter()
+                .filter(|(_, k)| *k == key)
+                .map(|(i, _)| i)
+                .collect();
+            for (i, page) in page_stack.iter_mut().enumerate() {
+                if !indices.contains(&i) {
+                    continue;
+                }
+                let page = page.as_mut_object_list();
+                for (name, content_type) in &content_types {
+                    // the failed ones are shown empty instead of being loaded repeatedly
+                    let content_type = content_type.clone().unwrap_or_default();
+                    page.set_content_type(name.clone(), content_type);
+                }
+            }
+        }
+    }
+
+    pub fn open_disk_usage(&mut self) {
+        let key = match self.page_stack.current_page() {
+            Page::BucketList(_) => self.current_object_key(),
+            Page::ObjectList(_) => self.current_list_object_key(),
+            _ => return,
+        };
+        if let Some(items) = self.app_objects.get_recursive_object_items(&key) {
+            // recursive object list has been already loaded
+            let result = CompleteOpenDiskUsageResult::new(key, Ok(items));
+            self.complete_open_disk_usage(result);
+            return;
+        }
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let mut items = Vec::new();
+            let result = client
+                .load_objects_recursive_with(&key.bucket_name, &key.prefix(), |loaded| {
+                    items.extend(loaded);
+                    let msg = format!("Scanned {} objects", items.len());
+                    tx.send(AppEventType::NotifyInfo(msg));
+                })
+                .await;
+            let result = CompleteOpenDiskUsageResult::new(key, result.map(|_| items));
+            tx.send(AppEventType::CompleteOpenDiskUsage(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_open_disk_usage(&mut self, result: Result<CompleteOpenDiskUsageResult>) {
+        self.app_view_state.is_loading = false;
+        match result {
+            Ok(CompleteOpenDiskUsageResult { key, items }) => {
+                let root = UsageNode::build(&items);
+                self.app_objects
+                    .set_recursive_object_items(key.clone(), items);
+
+                let page = Page::of_disk_usage(root, key.clone(), self.tx.clone());
+                if let Page::DiskUsage(page) = &page {
+                    self.set_prefix_sizes_under(&key, page.prefix_sizes());
+                }
+                self.page_stack.push(page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    // `key` is the prefix containing `item`
+    pub fn disk_usage_delete_item(&mut self, key: ObjectKey, item: ObjectItem) {
+        let mut item_key = key.clone();
+        item_key.object_path.push(item.name().to_string());
+        let is_dir = matches!(item, ObjectItem::Dir { .. });
+        self.app_objects.remove_objects_under(&key);
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let src = PaneLocation::S3(key.clone());
+            let items = vec![item];
+            let msg = async {
+                let targets = list_targets(&client, &src, items.clone()).await?;
+                let total = targets.len();
+                let msg = format!("Deleting {} objects", total);
+                tx.send(AppEventType::NotifyInfo(msg));
+
+                transfer::delete_items(&client, &src, &items, &targets).await?;
+                Ok(format!("Deleted {} objects", total))
+            }
+            .await;
+            let mut loaded = Vec::new();
+            if msg.is_ok() {
+                // if it fails, the list is loaded again when it is opened next time
+                if let Ok(items) = client.load_objects(&key.bucket_name, &key.prefix()).await {
+                    loaded.push((key, items));
+                }
+            }
+            let result = CompleteDiskUsageDeleteResult::new(msg, item_key, is_dir, loaded);
+            tx.send(AppEventType::CompleteDiskUsageDelete(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_disk_usage_delete(&mut self, result: Result<CompleteDiskUsageDeleteResult>) {
+        self.app_view_state.is_loading = false;
+        match result {
+            Ok(CompleteDiskUsageDeleteResult {
+                msg,
+                key,
+                is_dir,
+                loaded,
+            }) => {
+                for (list_key, items) in loaded {
+                    self.app_objects
+                        .set_object_items(list_key.clone(), items.clone());
+                    for page_stack in self.page_stacks_mut() {
+                        let indices: Vec<usize> = object_list_keys(page_stack)
+                            .into_iter()
+                            .filter(|(_, k)| *k == list_key)
+                            .map(|(i, _)| i)
+                            .collect();
+                        for (i, page) in page_stack.iter_mut().enumerate() {
+                            if indices.contains(&i) {
+                                page.as_mut_object_list().set_items(items.clone());
+                            }
+                        }
+                    }
+                }
+
+                if let Page::DiskUsage(page) = self.page_stack.current_page_mut() {
+                    page.remove_item(&key, is_dir);
+                    let root_key = page.key().clone();
+                    let sizes = page.prefix_sizes();
+                    self.set_prefix_sizes_under(&root_key, sizes);
+                }
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn open_duplicates(&mut self) {
+        let key = self.current_list_object_key();
 
-    fn current_object_items(&self) -> Option<Vec<ObjectItem>> {
-        self.app_objects
-            .get_object_items(&self.current_object_key())
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let mut objects = Vec::new();
+            let result = client
+                .load_object_summaries_with(&key.bucket_name, &key.prefix(), |loaded| {
+                    objects.extend(loaded);
+                    let msg = format!("Scanned {} objects", objects.len());
+                    tx.send(AppEventType::NotifyInfo(msg));
+                })
+                .await;
+            let groups = result.map(|_| find_duplicates(objects));
+            let result = CompleteOpenDuplicatesResult::new(key, groups);
+            tx.send(AppEventType::CompleteOpenDuplicates(result));
+        });
+        self.app_view_state.is_loading = true;
     }
 
-    pub fn bucket_list_move_down(&mut self) {
-        if let Some(current_object_items) = self.current_object_items() {
-            // object list has been already loaded
-            let object_list_page = Page::of_object_list(current_object_items, self.tx.clone());
-            self.page_stack.push(object_list_page);
-        } else {
-            self.tx.send(AppEventType::LoadObjects);
-            self.app_view_state.is_loading = true;
+    pub fn complete_open_duplicates(&mut self, result: Result<CompleteOpenDuplicatesResult>) {
+        self.app_view_state.is_loading = false;
+        match result {
+            Ok(CompleteOpenDuplicatesResult { groups, .. }) if groups.is_empty() => {
+                let msg = "No duplicate objects found".to_string();
+                self.tx.send(AppEventType::NotifyInfo(msg));
+            }
+            Ok(CompleteOpenDuplicatesResult { key, groups }) => {
+                let page = Page::of_duplicates(key, groups, self.tx.clone());
+                self.page_stack.push(page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
         }
     }
 
-    pub fn object_list_move_down(&mut self) {
-        let object_page = self.page_stack.current_page().as_object_list();
-        let selected = object_page.current_selected_item().to_owned();
-
-        match selected {
-            ObjectItem::File { name, .. } => {
-                let current_object_key = &self.current_object_key_with_name(name.to_string());
-                let detail = self.app_objects.get_object_detail(current_object_key);
-                let versions = self.app_objects.get_object_versions(current_object_key);
+    pub fn duplicates_delete_objects(&mut self, bucket_name: String, keys: Vec<String>) {
+        let Page::Duplicates(page) = self.page_stack.current_page() else {
+            return;
+        };
+        let key = page.key().clone();
+        self.app_objects.remove_objects_under(&key);
+
+        // the open lists under the scanned prefix are loaded again
+        let mut list_keys: Vec<ObjectKey> = self
+            .page_stacks_mut()
+            .flat_map(|page_stack| object_list_keys(page_stack))
+            .map(|(_, list_key)| list_key)
+            .filter(|list_key| list_key.starts_with(&key))
+            .collect();
+        list_keys.dedup();
 
-                if let (Some(detail), Some(versions)) = (detail, versions) {
-                    // object has been already loaded
-                    let object_detail_page = Page::of_object_detail(
-                        detail.clone(),
-                        versions.clone(),
-                        object_page.object_list(),
-                        object_page.list_state(),
-                        self.tx.clone(),
-                    );
-                    self.page_stack.push(object_detail_page);
-                } else {
-                    self.tx.send(AppEventType::LoadObject);
-                    self.app_view_state.is_loading = true;
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let msg = format!("Deleting {} objects", keys.len());
+            tx.send(AppEventType::NotifyInfo(msg));
+            let msg = client
+                .delete_objects(&bucket_name, &keys)
+                .await
+                .map(|_| format!("Deleted {} objects", keys.len()));
+            let mut loaded = Vec::new();
+            if msg.is_ok() {
+                for list_key in list_keys {
+                    // if it fails, the list is loaded again when it is opened next time
+                    let result = client
+                        .load_objects(&list_key.bucket_name, &list_key.prefix())
+                        .await;
+                    if let Ok(items) = result {
+                        loaded.push((list_key, items));
+                    }
                 }
             }
-            ObjectItem::Dir { .. } => {
-                if let Some(current_object_items) = self.current_object_items() {
-                    // object list has been already loaded
-                    let object_list_page =
-                        Page::of_object_list(current_object_items, self.tx.clone());
-                    self.page_stack.push(object_list_page);
-                } else {
-                    self.tx.send(AppEventType::LoadObjects);
-                    self.app_view_state.is_loading = true;
+            let result = CompleteDuplicatesDeleteResult::new(msg, keys, loaded);
+            tx.send(AppEventType::CompleteDuplicatesDelete(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_duplicates_delete(&mut self, result: Result<CompleteDuplicatesDeleteResult>) {
+        self.app_view_state.is_loading = false;
+        match result {
+            Ok(CompleteDuplicatesDeleteResult { msg, keys, loaded }) => {
+                for (list_key, items) in loaded {
+                    self.app_objects
+                        .set_object_items(list_key.clone(), items.clone());
+                    for page_stack in self.page_stacks_mut() {
+                        let indices: Vec<usize> = object_list_keys(page_stack)
+                            .into_iter()
+                            .filter(|(_, k)| *k == list_key)
+                            .map(|(i, _)| i)
+                            .collect();
+                        for (i, page) in page_stack.iter_mut().enumerate() {
+                            if indices.contains(&i) {
+                                page.as_mut_object_list().set_items(items.clone());
+                            }
+                        }
+                    }
+                }
+
+                if let Page::Duplicates(page) = self.page_stack.current_page_mut() {
+                    page.remove_objects(&keys);
                 }
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
             }
         }
     }
 
-    pub fn object_list_move_up(&mut self) {
-        if self.page_stack.len() == 2 /* bucket list and object list */ && self.bucket_items().len() == 1
-        {
-            return;
+    // caches the sizes calculated under `key` and shows them in the lists related to it
+    fn set_prefix_sizes_under(&mut self, key: &ObjectKey, sizes: Vec<(ObjectKey, PrefixSize)>) {
+        for (prefix_key, size) in sizes {
+            self.app_objects.set_prefix_size(prefix_key, size);
+        }
+        let page_stacks = std::iter::once(&mut self.page_stack)
+            .chain(self.other_pane.iter_mut())
+            .chain(self.tabs.iter_mut());
+        for page_stack in page_stacks {
+            let list_keys: HashMap<usize, ObjectKey> = object_list_keys(page_stack)
+                .into_iter()
+                .filter(|(_, list_key)| list_key.starts_with(key) || key.starts_with(list_key))
+                .collect();
+            for (i, page) in page_stack.iter_mut().enumerate() {
+                if let Some(list_key) = list_keys.get(&i) {
+                    let prefix_sizes = self.app_objects.get_prefix_sizes(list_key);
+                    page.as_mut_object_list().set_prefix_sizes(prefix_sizes);
+                }
+            }
         }
-        self.page_stack.pop();
     }
 
-    pub fn back_to_bucket_list(&mut self) {
-        if self.bucket_items().len() == 1 {
-            return;
-        }
-        self.page_stack.clear();
+    pub fn object_list_change_storage_class(
+        &mut self,
+        items: Vec<ObjectItem>,
+        storage_class: String,
+    ) {
+        let list_key = self.current_list_object_key();
+        self.app_objects.remove_objects_under(&list_key);
+        self.spawn_bulk_objects(move |client, tx| async move {
+            let bucket = &list_key.bucket_name;
+            let prefix = list_key.prefix();
+            let src = PaneLocation::S3(list_key.clone());
+            let targets = list_targets(&client, &src, items).await?;
+            let total = targets.len();
+            for (i, (name, size_byte)) in targets.into_iter().enumerate() {
+                let msg = format!("Changing storage class {}/{} objects", i + 1, total);
+                tx.send(AppEventType::NotifyInfo(msg));
+
+                let key = format!("{}{}", prefix, name);
+                client
+                    .copy_object(bucket, &key, bucket, &key, size_byte, Some(&storage_class))
+                    .await?;
+            }
+            Ok(format!(
+                "Changed storage class of {} objects to {}",
+                total, storage_class
+            ))
+        });
     }
 
-    pub fn load_objects(&self) {
-        let bucket = self.current_bucket();
-        let prefix = self.current_object_prefix();
+    fn spawn_bulk_objects<F, Fut>(&mut self, f: F)
+    where
+        F: FnOnce(Arc<Client>, Sender) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<String>> + Send,
+    {
         let (client, tx) = self.unwrap_client_tx();
         spawn(async move {
-            let items = client.load_objects(&bucket, &prefix).await;
-            let result = CompleteLoadObjectsResult::new(items);
-            tx.send(AppEventType::CompleteLoadObjects(result));
+            let msg = f(client, tx.clone()).await;
+            let result = CompleteBulkObjectsResult::new(msg);
+            tx.send(AppEventType::CompleteBulkObjects(result));
         });
+        self.app_view_state.is_loading = true;
     }
 
-    pub fn complete_load_objects(&mut self, result: Result<CompleteLoadObjectsResult>) {
+    pub fn complete_bulk_objects(&mut self, result: Result<CompleteBulkObjectsResult>) {
+        self.app_view_state.is_loading = false;
         match result {
-            Ok(CompleteLoadObjectsResult { items }) => {
-                self.app_objects
-                    .set_object_items(self.current_object_key().to_owned(), items.clone());
-
-                let object_list_page = Page::of_object_list(items, self.tx.clone());
-                self.page_stack.push(object_list_page);
+            Ok(CompleteBulkObjectsResult { msg }) => {
+                self.tx.send(AppEventType::NotifySuccess(msg));
             }
             Err(e) => {
                 self.tx.send(AppEventType::NotifyError(e));
             }
         }
-        self.app_view_state.is_loading = false;
+
+        // reload the current list if the operation has dropped it from the cache
+        let list_key = self.current_list_object_key();
+        if self.app_objects.get_object_items(&list_key).is_none() {
+            self.move_to(ObjectLocation::new(list_key, false));
+        }
     }
 
     pub fn load_object(&self) {
@@ -315,9 +1885,16 @@ impl App {
                 return;
             }
             Page::BucketList(page) => page.helps(),
+            Page::BucketStats(page) => page.helps(),
             Page::ObjectList(page) => page.helps(),
             Page::ObjectDetail(page) => page.helps(),
             Page::ObjectPreview(page) => page.helps(),
+            Page::ObjectDiff(page) => page.helps(),
+            Page::Bookmarks(page) => page.helps(),
+            Page::History(page) => page.helps(),
+            Page::LocalList(page) => page.helps(),
+            Page::DiskUsage(page) => page.helps(),
+            Page::Duplicates(page) => page.helps(),
         };
         let help_page = Page::of_help(helps, self.tx.clone());
         self.page_stack.push(help_page);
@@ -442,6 +2019,262 @@ impl App {
         self.app_view_state.is_loading = false;
     }
 
+    pub fn preview_follow_object(
+        &self,
+        file_detail: FileDetail,
+        follow_id: usize,
+        offset: usize,
+        e_tag: Option<String>,
+    ) {
+        // the request may be sent from the page in the other tab or pane
+        let Some(page_stack) = self
+            .page_stacks()
+            .find(|page_stack| following_preview_index(page_stack, follow_id).is_some())
+        else {
+            return;
+        };
+        let bucket_page = page_stack.head().as_bucket_list();
+        let bucket = bucket_page.current_selected_item().name.clone();
+        let key = file_detail.key;
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            sleep(PREVIEW_FOLLOW_INTERVAL).await;
+
+            // only the bytes after `offset` are loaded while the object is the same as the first read
+            let appended = match &e_tag {
+                Some(e_tag) => {
+                    client
+                        .download_object_from_if_match(&bucket, &key, offset, e_tag)
+                        .await
+                }
+                None => Ok(None),
+            };
+            let result = match appended {
+                Ok(Some(obj)) => Ok((obj, e_tag, false)),
+                // the object has been rewritten (or not read yet), so load it from the beginning
+                Ok(None) => client
+                    .download_object_with_e_tag(&bucket, &key, None, offset, |_| {})
+                    .await
+                    .map(|(obj, e_tag)| (obj, e_tag, true)),
+                Err(e) => Err(e),
+            };
+            let result = CompletePreviewFollowObjectResult::new(result);
+            tx.send(AppEventType::CompletePreviewFollowObject(follow_id, result));
+        });
+    }
+
+    pub fn complete_preview_follow_object(
+        &mut self,
+        follow_id: usize,
+        result: Result<CompletePreviewFollowObjectResult>,
+    ) {
+        // the result is delivered only to the page which requested it, wherever it is,
+        // e.g. under the help page or in the other tab
+        let page = self.page_stacks_mut().find_map(|page_stack| {
+            let i = following_preview_index(page_stack, follow_id)?;
+            page_stack.iter_mut().nth(i)
+        });
+        let Some(Page::ObjectPreview(page)) = page else {
+            return;
+        };
+        match result {
+            Ok(CompletePreviewFollowObjectResult { obj, e_tag, reset }) => {
+                page.follow_object(obj, e_tag, follow_id, reset);
+            }
+            Err(e) => {
+                page.stop_follow();
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn edit_object(&mut self, file_detail: FileDetail) {
+        let bucket = self.current_bucket();
+        let key = file_detail.key.clone();
+        let size_byte = file_detail.size_byte;
+
+        let (client, tx) = self.unwrap_client_tx();
+        let loading = self.handle_loading_size(size_byte, tx.clone());
+        spawn(async move {
+            // the ETag of the downloaded content (not the cached detail) is used on upload
+            let obj = client
+                .download_object_with_e_tag(&bucket, &key, None, size_byte, loading)
+                .await;
+            let result = CompleteEditObjectResult::new(obj, file_detail);
+            tx.send(AppEventType::CompleteEditObject(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_edit_object(&mut self, result: Result<CompleteEditObjectResult>) {
+        let result = match result {
+            Ok(CompleteEditObjectResult {
+                obj,
+                e_tag,
+                file_detail,
+            }) => EditingObject::new(file_detail, e_tag, obj),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(editing) => {
+                // the editor is run in the main loop because it needs the terminal
+                self.tx.send(AppEventType::OpenEditor(Box::new(editing)));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn complete_open_editor(&mut self, editing: EditingObject, result: Result<()>) {
+        match result.and_then(|_| editing.read_edited()) {
+            Ok(bytes) if bytes == editing.original.bytes => {
+                let msg = format!("No changes were made to {}", editing.file_detail.name);
+                self.tx.send(AppEventType::NotifyInfo(msg));
+            }
+            Ok(bytes) => {
+                let EditingObject {
+                    file_detail,
+                    e_tag,
+                    original,
+                    ..
+                } = editing;
+                let edited = RawObject { bytes };
+                let object_diff_page =
+                    Page::of_object_diff(file_detail, e_tag, original, edited, self.tx.clone());
+                self.page_stack.push(object_diff_page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn upload_edited_object(&mut self, file_detail: FileDetail, e_tag: String, obj: RawObject) {
+        let bucket = self.current_bucket();
+        let map_key = self.current_object_key_with_name(file_detail.name.clone());
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let FileDetail {
+                name,
+                key,
+                content_type,
+                ..
+            } = file_detail;
+            let result = client
+                .upload_object_if_match(&bucket, &key, &content_type, &e_tag, &obj)
+                .await;
+            let result = match result {
+                Ok(_) => {
+                    let size_byte = obj.bytes.len();
+                    let detail = client
+                        .load_object_detail(&bucket, &key, &name, size_byte)
+                        .await;
+                    let versions = client.load_object_versions(&bucket, &key).await;
+                    CompleteUploadEditedObjectResult::new(detail, versions, map_key, obj)
+                }
+                Err(e) => Err(e),
+            };
+            tx.send(AppEventType::CompleteUploadEditedObject(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_upload_edited_object(
+        &mut self,
+        result: Result<CompleteUploadEditedObjectResult>,
+    ) {
+        match result {
+            Ok(CompleteUploadEditedObjectResult {
+                detail,
+                versions,
+                map_key,
+                obj,
+            }) => {
+                self.page_stack.pop(); // remove diff page
+
+                for page in self.page_stack.iter_mut() {
+                    match page {
+                        Page::ObjectDetail(page) => {
+                            page.update_object(*detail.clone(), versions.clone());
+                        }
+                        Page::ObjectPreview(page) => {
+                            page.update_object(*detail.clone(), obj.clone());
+                        }
+                        _ => {}
+                    }
+                }
+
+                let msg = format!("Uploaded {} successfully", detail.name);
+                self.app_objects
+                    .set_object_details(map_key, *detail, versions);
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn open_object(&mut self, file_detail: FileDetail, version_id: Option<String>) {
+        let command = self
+            .config
+            .open
+            .command_for(&file_detail.name, &file_detail.content_type);
+        let Some(command) = command.map(String::from) else {
+            let msg = format!("No command is configured to open {}", file_detail.name);
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        };
+
+        let object_name = file_detail.name.clone();
+        let size_byte = file_detail.size_byte;
+
+        self.download_object_and(&object_name, size_byte, None, version_id, |tx, obj, _| {
+            let result = CompleteOpenObjectResult::new(obj, file_detail, command);
+            tx.send(AppEventType::CompleteOpenObject(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_open_object(&mut self, result: Result<CompleteOpenObjectResult>) {
+        let result = match result {
+            Ok(CompleteOpenObjectResult {
+                obj,
+                file_detail,
+                command,
+            }) => OpeningObject::new(&file_detail, &obj, command),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(opening) => {
+                // the command is run in the main loop because it needs the terminal
+                self.tx
+                    .send(AppEventType::OpenWithCommand(Box::new(opening)));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn complete_open_with_command(&mut self, opening: OpeningObject, result: Result<()>) {
+        // commands like `xdg-open` may read the file after they exit,
+        // so the file is kept until the app exits, otherwise it is removed now
+        if opening.is_detached() {
+            self.opened_files.push(opening.file);
+        }
+
+        if let Err(e) = result {
+            self.tx.send(AppEventType::NotifyError(e));
+        }
+    }
+
     fn download_object_and<F>(
         &self,
         object_name: &str,
@@ -598,3 +2431,93 @@ impl App {
         (self.client.as_ref().unwrap().clone(), self.tx.clone())
     }
 }
+
+// the key of the object list page on the top of the stack itself
+fn list_object_key(page_stack: &PageStack) -> ObjectKey {
+    list_object_key_at(page_stack, page_stack.len() - 1)
+}
+
+// the key of the object list page at `index` of the stack
+fn list_object_key_at(page_stack: &PageStack, index: usize) -> ObjectKey {
+    let bucket_page = page_stack.head().as_bucket_list();
+    let object_path = page_stack
+        .iter()
+        .take(index)
+        .filter_map(|page| if_match! { page: Page::ObjectList(p) => p })
+        .map(|page| page.current_selected_item())
+        .filter_map(|item| if_match! { item: ObjectItem::Dir { name, .. } => name.to_string() })
+        .collect();
+    ObjectKey {
+        bucket_name: bucket_page.current_selected_item().name.clone(),
+        object_path,
+    }
+}
+
+// the indices and the keys of all object list pages in the stack
+fn object_list_keys(page_stack: &PageStack) -> Vec<(usize, ObjectKey)> {
+    page_stack
+        .iter()
+        .enumerate()
+        .filter(|(_, page)| matches!(page, Page::ObjectList(_)))
+        .map(|(i, _)| (i, list_object_key_at(page_stack, i)))
+        .collect()
+}
+
+// the index of the preview page which is following with `follow_id` in the stack
+fn following_preview_index(page_stack: &PageStack, follow_id: usize) -> Option<usize> {
+    page_stack
+        .iter()
+        .position(|page| matches!(page, Page::ObjectPreview(p) if p.is_following(follow_id)))
+}
+
+fn pane_location(page_stack: &PageStack) -> Option<PaneLocation> {
+    match page_stack.current_page() {
+        Page::ObjectList(_) => Some(PaneLocation::S3(list_object_key(page_stack))),
+        Page::LocalList(page) => Some(PaneLocation::Local(page.path().to_path_buf())),
+        _ => None,
+    }
+}
+
+fn load_bookmarks(config: &Config, tx: &Sender) -> Bookmarks {
+    let result = config
+        .bookmarks_path()
+        .map_err(|e| AppError::msg(e.to_string()))
+        .and_then(|path| Bookmarks::load(&path));
+    result.unwrap_or_else(|e| {
+        tx.send(AppEventType::NotifyError(e));
+        Bookmarks::default()
+    })
+}
+
+fn load_bucket_stats(config: &Config, tx: &Sender) -> BucketStatsCache {
+    let result = config
+        .stats_path()
+        .map_err(|e| AppError::msg(e.to_string()))
+        .and_then(|path| BucketStatsCache::load(&path));
+    result.unwrap_or_else(|e| {
+        tx.send(AppEventType::NotifyError(e));
+        BucketStatsCache::default()
+    })
+}
+
+fn load_sort_settings(config: &Config, tx: &Sender) -> SortSettings {
+    let result = config
+        .sort_path()
+        .map_err(|e| AppError::msg(e.to_string()))
+        .and_then(|path| SortSettings::load(&path));
+    result.unwrap_or_else(|e| {
+        tx.send(AppEventType::NotifyError(e));
+        SortSettings::default()
+    })
+}
+
+fn load_history(config: &Config, tx: &Sender) -> History {
+    let result = config
+        .history_path()
+        .map_err(|e| AppError::msg(e.to_string()))
+        .and_then(|path| History::load(&path));
+    result.unwrap_or_else(|e| {
+        tx.send(AppEventType::NotifyError(e));
+        History::default()
+    })
+}
@@ -0,0 +1,157 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{AppError, Result},
+    file::save_binary,
+    object::{ObjectKey, ObjectLocation},
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub key: ObjectKey,
+    #[serde(default)]
+    pub is_object: bool,
+}
+
+impl Bookmark {
+    pub fn new(key: ObjectKey, is_object: bool) -> Bookmark {
+        Bookmark {
+            label: None,
+            key,
+            is_object,
+        }
+    }
+
+    pub fn uri(&self) -> String {
+        self.key.uri(self.is_object)
+    }
+
+    pub fn location(&self) -> ObjectLocation {
+        ObjectLocation::new(self.key.clone(), self.is_object)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Bookmarks {
+    items: Vec<Bookmark>,
+    // None if the file could not be loaded, to avoid overwriting it
+    path: Option<String>,
+}
+
+impl Bookmarks {
+    pub fn load(path: &str) -> Result<Bookmarks> {
+        let items = if Path::new(path).exists() {
+            let bytes =
+                std::fs::read(path).map_err(|e| AppError::new("Failed to read bookmarks", e))?;
+            serde_json::from_slice(&bytes)
+                .map_err(|e| AppError::new("Failed to parse bookmarks", e))?
+        } else {
+            Vec::new()
+        };
+        Ok(Bookmarks {
+            items,
+            path: Some(path.to_string()),
+        })
+    }
+
+    pub fn items(&self) -> &[Bookmark] {
+        &self.items
+    }
+
+    pub fn add(&mut self, bookmark: Bookmark) -> Result<bool> {
+        let exists = self
+            .items
+            .iter()
+            .any(|b| b.key == bookmark.key && b.is_object == bookmark.is_object);
+        if exists {
+            return Ok(false);
+        }
+        self.items.push(bookmark);
+        self.save()?;
+        Ok(true)
+    }
+
+    pub fn rename(&mut self, index: usize, label: Option<String>) -> Result<()> {
+        if let Some(bookmark) = self.items.get_mut(index) {
+            bookmark.label = label;
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    pub fn delete(&mut self, index: usize) -> Result<()> {
+        if index < self.items.len() {
+            self.items.remove(index);
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = self
+            .path
+            .as_ref()
+            .ok_or_else(|| AppError::msg("Failed to save bookmarks: the file was not loaded"))?;
+        let bytes = serde_json::to_vec_pretty(&self.items)
+            .map_err(|e| AppError::new("Failed to serialize bookmarks", e))?;
+        save_binary(path, &bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(bucket: &str, path: &[&str]) -> ObjectKey {
+        ObjectKey {
+            bucket_name: bucket.to_string(),
+            object_path: path.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_bookmark_uri() {
+        let bookmark = Bookmark::new(key("bucket-1", &[]), false);
+        assert_eq!(bookmark.uri(), "s3://bucket-1/");
+
+        let bookmark = Bookmark::new(key("bucket-1", &["dir1", "dir2"]), false);
+        assert_eq!(bookmark.uri(), "s3://bucket-1/dir1/dir2/");
+
+        let bookmark = Bookmark::new(key("bucket-1", &["dir1", "file1.txt"]), true);
+        assert_eq!(bookmark.uri(), "s3://bucket-1/dir1/file1.txt");
+    }
+
+    #[test]
+    fn test_save_and_load_bookmarks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bookmarks.json");
+        let path = path.to_string_lossy();
+
+        let mut bookmarks = Bookmarks::load(&path).unwrap();
+        assert!(bookmarks.items().is_empty());
+
+        let bookmark = Bookmark::new(key("bucket-1", &["dir1"]), false);
+        assert!(bookmarks.add(bookmark.clone()).unwrap());
+        assert!(!bookmarks.add(bookmark).unwrap());
+        let bookmark = Bookmark::new(key("bucket-2", &["file1.txt"]), true);
+        assert!(bookmarks.add(bookmark).unwrap());
+        bookmarks.rename(1, Some("file".to_string())).unwrap();
+        bookmarks.delete(0).unwrap();
+
+        let bookmarks = Bookmarks::load(&path).unwrap();
+        let mut expected = Bookmark::new(key("bucket-2", &["file1.txt"]), true);
+        expected.label = Some("file".to_string());
+        assert_eq!(bookmarks.items(), [expected]);
+    }
+
+    #[test]
+    fn test_save_bookmarks_without_path() {
+        let mut bookmarks = Bookmarks::default();
+        let bookmark = Bookmark::new(key("bucket-1", &[]), false);
+        assert!(bookmarks.add(bookmark).is_err());
+    }
+}
@@ -1,20 +1,48 @@
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug, sync::Mutex};
 
 use aws_config::{meta::region::RegionProviderChain, BehaviorVersion};
-use aws_sdk_s3::{config::Region, operation::list_objects_v2::ListObjectsV2Output};
+use aws_sdk_s3::{
+    config::Region,
+    error::{ProvideErrorMetadata, SdkError},
+    operation::{
+        get_bucket_location::GetBucketLocationError, list_objects_v2::ListObjectsV2Output,
+    },
+    primitives::ByteStream,
+    types::{
+        CompletedMultipartUpload, CompletedPart, Delete, Object, ObjectIdentifier, StorageClass,
+    },
+};
 use chrono::TimeZone;
 
 use crate::{
     error::{AppError, Result},
-    object::{BucketItem, FileDetail, FileVersion, ObjectItem, RawObject},
+    object::{BucketItem, FileDetail, FileVersion, ObjectItem, ObjectSummary, RawObject},
 };
 
 const DELIMITER: &str = "/";
 const DEFAULT_REGION: &str = "ap-northeast-1";
+const FORBIDDEN: u16 = 403;
+const PRECONDITION_FAILED: u16 = 412;
+const RANGE_NOT_SATISFIABLE: u16 = 416;
+const NOT_IMPLEMENTED: u16 = 501;
+const DELETE_OBJECTS_MAX_KEYS: usize = 1000;
+// the objects larger than this cannot be copied by a single CopyObject request
+const COPY_OBJECT_MAX_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+const COPY_PART_MIN_SIZE: u64 = 512 * 1024 * 1024;
+const MULTIPART_MAX_PARTS: u64 = 10_000;
+// the listing omits the storage class of the standard objects on some services
+const DEFAULT_STORAGE_CLASS: &str = "STANDARD";
 
 pub struct Client {
+    // the client of the configured region, used for the requests which are not bound to a bucket
     pub client: aws_sdk_s3::Client,
     region: String,
+    // false if the endpoint url is specified, the region is not detected for the other services
+    detect_region: bool,
+    // by bucket name
+    bucket_regions: Mutex<HashMap<String, String>>,
+    // by region, except the configured region
+    region_clients: Mutex<HashMap<String, aws_sdk_s3::Client>>,
 }
 
 impl Debug for Client {
@@ -52,7 +80,73 @@ impl Client {
         let client = aws_sdk_s3::Client::from_conf(config);
         let region = sdk_config.region().unwrap().to_string();
 
-        Client { client, region }
+        Client {
+            client,
+            region,
+            detect_region: endpoint_url.is_none(),
+            bucket_regions: Mutex::new(HashMap::new()),
+            region_clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // the region of the bucket is detected on the first access, and the client for it is cached
+    async fn bucket_client(&self, bucket: &str) -> aws_sdk_s3::Client {
+        let region = self.bucket_region(bucket).await;
+        self.region_client(&region)
+    }
+
+    async fn bucket_region(&self, bucket: &str) -> String {
+        if !self.detect_region {
+            return self.region.clone();
+        }
+        if let Some(region) = self.bucket_regions.lock().unwrap().get(bucket) {
+            return region.clone();
+        }
+        match self.request_bucket_region(bucket).await {
+            Ok(region) => region,
+            Err(e) => {
+                // GetBucketLocation may not be allowed, fall back to the configured region,
+                // which is kept only in that case so that the other errors are retried next time
+                let region = self.region.clone();
+                if is_region_unavailable(&e) {
+                    self.bucket_regions
+                        .lock()
+                        .unwrap()
+                        .insert(bucket.to_string(), region.clone());
+                }
+                region
+            }
+        }
+    }
+
+    fn region_client(&self, region: &str) -> aws_sdk_s3::Client {
+        if region == self.region {
+            return self.client.clone();
+        }
+        self.region_clients
+            .lock()
+            .unwrap()
+            .entry(region.to_string())
+            .or_insert_with(|| {
+                let config = self
+                    .client
+                    .config()
+                    .to_builder()
+                    .region(Region::new(region.to_string()))
+                    .build();
+                aws_sdk_s3::Client::from_conf(config)
+            })
+            .clone()
+    }
+
+    // used where the region cannot be detected, such as the management console links
+    fn cached_bucket_region(&self, bucket: &str) -> String {
+        self.bucket_regions
+            .lock()
+            .unwrap()
+            .get(bucket)
+            .cloned()
+            .unwrap_or_else(|| self.region.clone())
     }
 
     pub async fn load_all_buckets(&self) -> Result<Vec<BucketItem>> {
@@ -64,7 +158,12 @@ impl Client {
             .iter()
             .map(|bucket| {
                 let name = bucket.name().unwrap().to_string();
-                BucketItem { name }
+                let creation_date = bucket.creation_date().map(convert_datetime);
+                BucketItem {
+                    name,
+                    creation_date,
+                    region: None,
+                }
             })
             .collect();
 
@@ -76,24 +175,57 @@ impl Client {
     }
 
     pub async fn load_bucket(&self, name: &str) -> Result<BucketItem> {
-        let result = self.client.head_bucket().bucket(name).send().await;
+        let client = self.bucket_client(name).await;
+        let result = client.head_bucket().bucket(name).send().await;
         // Check only existence and accessibility
         result.map_err(|e| AppError::new(format!("Failed to load bucket '{}'", name), e))?;
 
         let bucket = BucketItem {
             name: name.to_string(),
+            creation_date: None,
+            region: None,
         };
         Ok(bucket)
     }
 
+    pub async fn load_bucket_region(&self, name: &str) -> Result<String> {
+        self.request_bucket_region(name).await.map_err(|e| {
+            AppError::new(format!("Failed to load the region of bucket '{}'", name), e)
+        })
+    }
+
+    async fn request_bucket_region(
+        &self,
+        name: &str,
+    ) -> std::result::Result<String, SdkError<GetBucketLocationError>> {
+        let output = self
+            .client
+            .get_bucket_location()
+            .bucket(name)
+            .send()
+            .await?;
+
+        // the location constraint is empty for us-east-1, and `EU` is the legacy name of eu-west-1
+        let region = match output.location_constraint().map(|c| c.as_str()) {
+            None | Some("") => "us-east-1",
+            Some("EU") => "eu-west-1",
+            Some(region) => region,
+        };
+        self.bucket_regions
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), region.to_string());
+        Ok(region.to_string())
+    }
+
     pub async fn load_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<ObjectItem>> {
+        let client = self.bucket_client(bucket).await;
         let mut dirs_vec: Vec<Vec<ObjectItem>> = Vec::new();
         let mut files_vec: Vec<Vec<ObjectItem>> = Vec::new();
 
         let mut token: Option<String> = None;
         loop {
-            let result = self
-                .client
+            let result = client
                 .list_objects_v2()
                 .bucket(bucket)
                 .prefix(prefix)
@@ -120,6 +252,107 @@ impl Client {
         Ok(di.chain(fi).collect())
     }
 
+    // lists the objects with the attributes which are not kept in `ObjectItem`,
+    // `f` is called for each page of the listing
+    pub async fn load_object_summaries_with<F>(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        mut f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Vec<ObjectSummary>),
+    {
+        let client = self.bucket_client(bucket).await;
+        let mut token: Option<String> = None;
+        loop {
+            let result = client
+                .list_objects_v2()
+                .bucket(bucket)
+                .prefix(prefix)
+                .set_continuation_token(token)
+                .send()
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to load objects", e))?;
+
+            f(objects_output_to_summaries(&output));
+
+            token = output.next_continuation_token().map(String::from);
+            if token.is_none() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn load_objects_recursive(
+        &self,
+        bucket: &str,
+        prefix: &str,
+    ) -> Result<Vec<ObjectItem>> {
+        let mut files_vec: Vec<Vec<ObjectItem>> = Vec::new();
+        self.load_objects_recursive_with(bucket, prefix, |files| files_vec.push(files))
+            .await?;
+        Ok(files_vec.into_iter().flatten().collect())
+    }
+
+    // `f` is called for each page of the listing
+    pub async fn load_objects_recursive_with<F>(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Vec<ObjectItem>),
+    {
+        self.search_objects_with(bucket, prefix, "", usize::MAX, f)
+            .await
+            .map(|_| ())
+    }
+
+    // lists the objects whose keys start with `prefix` + `query`, named relative to `prefix`
+    // returns true if the listing was stopped at `max_keys`
+    pub async fn search_objects_with<F>(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        query: &str,
+        max_keys: usize,
+        mut f: F,
+    ) -> Result<bool>
+    where
+        F: FnMut(Vec<ObjectItem>),
+    {
+        let client = self.bucket_client(bucket).await;
+        let search_prefix = format!("{}{}", prefix, query);
+        let mut count = 0;
+        let mut token: Option<String> = None;
+        loop {
+            let result = client
+                .list_objects_v2()
+                .bucket(bucket)
+                .prefix(&search_prefix)
+                .max_keys((max_keys - count).min(1000) as i32)
+                .set_continuation_token(token)
+                .send()
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to load objects", e))?;
+
+            let files = objects_output_to_relative_files(&output, prefix);
+            count += files.len();
+            f(files);
+
+            token = output.next_continuation_token().map(String::from);
+            if token.is_none() {
+                return Ok(false);
+            }
+            if count >= max_keys {
+                return Ok(true);
+            }
+        }
+    }
+
     pub async fn load_object_detail(
         &self,
         bucket: &str,
@@ -127,13 +360,8 @@ impl Client {
         name: &str,
         size_byte: usize,
     ) -> Result<FileDetail> {
-        let result = self
-            .client
-            .head_object()
-            .bucket(bucket)
-            .key(key)
-            .send()
-            .await;
+        let client = self.bucket_client(bucket).await;
+        let result = client.head_object().bucket(bucket).key(key).send().await;
         let output = result.map_err(|e| AppError::new("Failed to load object detail", e))?;
 
         let name = name.to_owned();
@@ -162,6 +390,13 @@ impl Client {
         })
     }
 
+    pub async fn load_object_content_type(&self, bucket: &str, key: &str) -> Result<String> {
+        let client = self.bucket_client(bucket).await;
+        let result = client.head_object().bucket(bucket).key(key).send().await;
+        let output = result.map_err(|e| AppError::new("Failed to load content type", e))?;
+        Ok(output.content_type().unwrap_or_default().to_string())
+    }
+
     fn build_s3_uri(&self, bucket: &str, key: &str) -> String {
         format!("s3://{}/{}", bucket, key)
     }
@@ -173,13 +408,15 @@ impl Client {
     fn build_object_url(&self, bucket: &str, key: &str) -> String {
         format!(
             "https://{}.s3.{}.amazonaws.com/{}",
-            bucket, self.region, key
+            bucket,
+            self.cached_bucket_region(bucket),
+            key
         )
     }
 
     pub async fn load_object_versions(&self, bucket: &str, key: &str) -> Result<Vec<FileVersion>> {
-        let result = self
-            .client
+        let client = self.bucket_client(bucket).await;
+        let result = client
             .list_object_versions()
             .bucket(bucket)
             .prefix(key)
@@ -217,13 +454,32 @@ impl Client {
     where
         F: Fn(usize),
     {
-        let mut request = self.client.get_object().bucket(bucket).key(key);
+        self.download_object_with_e_tag(bucket, key, version_id, size_byte, f)
+            .await
+            .map(|(obj, _)| obj)
+    }
+
+    // also returns the ETag (with quotes) of the downloaded content
+    pub async fn download_object_with_e_tag<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        size_byte: usize,
+        f: F,
+    ) -> Result<(RawObject, Option<String>)>
+    where
+        F: Fn(usize),
+    {
+        let client = self.bucket_client(bucket).await;
+        let mut request = client.get_object().bucket(bucket).key(key);
         if let Some(version_id) = version_id {
             request = request.version_id(version_id);
         }
 
         let result = request.send().await;
         let output = result.map_err(|e| AppError::new("Failed to download object", e))?;
+        let e_tag = output.e_tag().map(String::from);
 
         let mut bytes: Vec<u8> = Vec::with_capacity(size_byte);
         let mut stream = output.body;
@@ -243,7 +499,255 @@ impl Client {
             i += 1;
         }
 
-        Ok(RawObject { bytes })
+        Ok((RawObject { bytes }, e_tag))
+    }
+
+    // returns the bytes after `offset`, or None if the object does not match `e_tag` anymore
+    pub async fn download_object_from_if_match(
+        &self,
+        bucket: &str,
+        key: &str,
+        offset: usize,
+        e_tag: &str,
+    ) -> Result<Option<RawObject>> {
+        let client = self.bucket_client(bucket).await;
+        let result = client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .range(format!("bytes={}-", offset))
+            .if_match(e_tag)
+            .send()
+            .await;
+        let output = match result {
+            Ok(output) => output,
+            Err(e) => {
+                return match e.raw_response().map(|r| r.status().as_u16()) {
+                    Some(PRECONDITION_FAILED) => Ok(None),
+                    // nothing has been appended since `offset`
+                    Some(RANGE_NOT_SATISFIABLE) => Ok(Some(RawObject { bytes: Vec::new() })),
+                    _ => Err(AppError::new("Failed to download object", e)),
+                };
+            }
+        };
+
+        let body = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| AppError::new("Failed to collect body", e))?;
+        let bytes = body.into_bytes().to_vec();
+
+        Ok(Some(RawObject { bytes }))
+    }
+
+    pub async fn upload_object_if_match(
+        &self,
+        bucket: &str,
+        key: &str,
+        content_type: &str,
+        e_tag: &str,
+        obj: &RawObject,
+    ) -> Result<()> {
+        let client = self.bucket_client(bucket).await;
+        // fail if the object has been modified by someone else
+        let result = client
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .content_type(content_type)
+            .if_match(e_tag)
+            .body(ByteStream::from(obj.bytes.clone()))
+            .send()
+            .await;
+        result.map_err(|e| {
+            if e.raw_response().map(|r| r.status().as_u16()) == Some(PRECONDITION_FAILED) {
+                AppError::new("The object has been modified since it was downloaded", e)
+            } else {
+                AppError::new("Failed to upload object", e)
+            }
+        })?;
+        Ok(())
+    }
+
+    pub async fn upload_object(&self, bucket: &str, key: &str, bytes: Vec<u8>) -> Result<()> {
+        let client = self.bucket_client(bucket).await;
+        let result = client
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from(bytes))
+            .send()
+            .await;
+        result.map_err(|e| AppError::new("Failed to upload object", e))?;
+        Ok(())
+    }
+
+    // copies onto the same key when changing only the storage class
+    pub async fn copy_object(
+        &self,
+        src_bucket: &str,
+        src_key: &str,
+        dst_bucket: &str,
+        dst_key: &str,
+        size_byte: usize,
+        storage_class: Option<&str>,
+    ) -> Result<()> {
+        let client = self.bucket_client(dst_bucket).await;
+        let encoded_key: Vec<String> = src_key
+            .split('/')
+            .map(|s| urlencoding::encode(s).into_owned())
+            .collect();
+        let copy_source = format!("{}/{}", src_bucket, encoded_key.join("/"));
+        if size_byte as u64 > COPY_OBJECT_MAX_SIZE {
+            return self
+                .copy_object_multipart(
+                    &copy_source,
+                    src_bucket,
+                    src_key,
+                    dst_bucket,
+                    dst_key,
+                    size_byte,
+                    storage_class,
+                )
+                .await;
+        }
+        let result = client
+            .copy_object()
+            .copy_source(copy_source)
+            .bucket(dst_bucket)
+            .key(dst_key)
+            .set_storage_class(storage_class.map(StorageClass::from))
+            .send()
+            .await;
+        result.map_err(|e| AppError::new("Failed to copy object", e))?;
+        Ok(())
+    }
+
+    // copies the parts with UploadPartCopy, and the content type and the metadata are taken over
+    // like CopyObject does
+    #[allow(clippy::too_many_arguments)]
+    async fn copy_object_multipart(
+        &self,
+        copy_source: &str,
+        src_bucket: &str,
+        src_key: &str,
+        dst_bucket: &str,
+        dst_key: &str,
+        size_byte: usize,
+        storage_class: Option<&str>,
+    ) -> Result<()> {
+        let src_client = self.bucket_client(src_bucket).await;
+        let result = src_client
+            .head_object()
+            .bucket(src_bucket)
+            .key(src_key)
+            .send()
+            .await;
+        let head = result.map_err(|e| AppError::new("Failed to copy object", e))?;
+
+        let client = self.bucket_client(dst_bucket).await;
+        let result = client
+            .create_multipart_upload()
+            .bucket(dst_bucket)
+            .key(dst_key)
+            .set_content_type(head.content_type().map(String::from))
+            .set_metadata(head.metadata().cloned())
+            .set_storage_class(storage_class.map(StorageClass::from))
+            .send()
+            .await;
+        let output = result.map_err(|e| AppError::new("Failed to copy object", e))?;
+        let upload_id = output
+            .upload_id()
+            .ok_or_else(|| AppError::msg("Failed to copy object: no upload id"))?;
+
+        let size_byte = size_byte as u64;
+        let part_size = copy_part_size(size_byte);
+        let mut parts = Vec::new();
+        for (i, start) in (0..size_byte).step_by(part_size as usize).enumerate() {
+            let end = (start + part_size).min(size_byte) - 1;
+            let part_number = i as i32 + 1;
+            let result = client
+                .upload_part_copy()
+                .bucket(dst_bucket)
+                .key(dst_key)
+                .upload_id(upload_id)
+                .part_number(part_number)
+                .copy_source(copy_source)
+                .copy_source_range(format!("bytes={}-{}", start, end))
+                .send()
+                .await;
+            let output = match result {
+                Ok(output) => output,
+                Err(e) => {
+                    // the uploaded parts are kept (and charged) unless the upload is aborted
+                    let _ = client
+                        .abort_multipart_upload()
+                        .bucket(dst_bucket)
+                        .key(dst_key)
+                        .upload_id(upload_id)
+                        .send()
+                        .await;
+                    return Err(AppError::new("Failed to copy object", e));
+                }
+            };
+            let part = CompletedPart::builder()
+                .set_e_tag(
+                    output
+                        .copy_part_result()
+                        .and_then(|r| r.e_tag())
+                        .map(String::from),
+                )
+                .part_number(part_number)
+                .build();
+            parts.push(part);
+        }
+
+        let upload = CompletedMultipartUpload::builder()
+            .set_parts(Some(parts))
+            .build();
+        let result = client
+            .complete_multipart_upload()
+            .bucket(dst_bucket)
+            .key(dst_key)
+            .upload_id(upload_id)
+            .multipart_upload(upload)
+            .send()
+            .await;
+        result.map_err(|e| AppError::new("Failed to copy object", e))?;
+        Ok(())
+    }
+
+    pub async fn delete_objects(&self, bucket: &str, keys: &[String]) -> Result<()> {
+        let client = self.bucket_client(bucket).await;
+        for chunk in keys.chunks(DELETE_OBJECTS_MAX_KEYS) {
+            let objects = chunk
+                .iter()
+                .map(|key| ObjectIdentifier::builder().key(key).build())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| AppError::new("Failed to delete objects", e))?;
+            let delete = Delete::builder()
+                .set_objects(Some(objects))
+                .quiet(true)
+                .build()
+                .map_err(|e| AppError::new("Failed to delete objects", e))?;
+            let result = client
+                .delete_objects()
+                .bucket(bucket)
+                .delete(delete)
+                .send()
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to delete objects", e))?;
+            if let Some(err) = output.errors().first() {
+                let msg = format!(
+                    "Failed to delete object: {} ({})",
+                    err.key().unwrap_or_default(),
+                    err.message().unwrap_or_default()
+                );
+                return Err(AppError::msg(msg));
+            }
+        }
+        Ok(())
     }
 
     pub fn open_management_console_buckets(&self) -> Result<()> {
@@ -257,7 +761,9 @@ impl Client {
     pub fn open_management_console_list(&self, bucket: &str, prefix: &str) -> Result<()> {
         let path = format!(
             "https://s3.console.aws.amazon.com/s3/buckets/{}?region={}&prefix={}",
-            bucket, self.region, prefix
+            bucket,
+            self.cached_bucket_region(bucket),
+            prefix
         );
         open::that(path).map_err(AppError::error)
     }
@@ -270,7 +776,10 @@ impl Client {
     ) -> Result<()> {
         let path = format!(
             "https://s3.console.aws.amazon.com/s3/object/{}?region={}&prefix={}{}",
-            bucket, self.region, prefix, name
+            bucket,
+            self.cached_bucket_region(bucket),
+            prefix,
+            name
         );
         open::that(path).map_err(AppError::error)
     }
@@ -303,11 +812,77 @@ fn objects_output_to_files(output: &ListObjectsV2Output) -> Vec<ObjectItem> {
                 name,
                 size_byte,
                 last_modified,
+                e_tag: Some(object_e_tag(file)),
+                storage_class: Some(object_storage_class(file)),
+            }
+        })
+        .collect()
+}
+
+fn objects_output_to_relative_files(output: &ListObjectsV2Output, prefix: &str) -> Vec<ObjectItem> {
+    let objects = output.contents();
+    objects
+        .iter()
+        .filter_map(|file| {
+            let path = file.key().unwrap();
+            let name = path.strip_prefix(prefix).unwrap_or(path);
+            // skip the folder placeholder objects
+            if name.is_empty() || name.ends_with(DELIMITER) {
+                return None;
+            }
+            let size_byte = file.size().unwrap() as usize;
+            let last_modified = convert_datetime(file.last_modified().unwrap());
+            Some(ObjectItem::File {
+                name: name.to_string(),
+                size_byte,
+                last_modified,
+                e_tag: Some(object_e_tag(file)),
+                storage_class: Some(object_storage_class(file)),
+            })
+        })
+        .collect()
+}
+
+fn objects_output_to_summaries(output: &ListObjectsV2Output) -> Vec<ObjectSummary> {
+    let objects = output.contents();
+    objects
+        .iter()
+        .filter_map(|file| {
+            let key = file.key().unwrap();
+            // skip the folder placeholder objects
+            if key.ends_with(DELIMITER) {
+                return None;
             }
+            let size_byte = file.size().unwrap() as usize;
+            let last_modified = convert_datetime(file.last_modified().unwrap());
+            let e_tag = object_e_tag(file);
+            let storage_class = object_storage_class(file);
+            Some(ObjectSummary {
+                key: key.to_string(),
+                size_byte,
+                last_modified,
+                e_tag,
+                storage_class,
+            })
         })
         .collect()
 }
 
+fn object_e_tag(object: &Object) -> String {
+    object
+        .e_tag()
+        .unwrap_or_default()
+        .trim_matches('"')
+        .to_string()
+}
+
+fn object_storage_class(object: &Object) -> String {
+    object
+        .storage_class()
+        .map_or(DEFAULT_STORAGE_CLASS, |s| s.as_str())
+        .to_string()
+}
+
 fn parse_path(path: &str, dir: bool) -> Vec<String> {
     let ss: Vec<String> = path.split(DELIMITER).map(String::from).collect();
     if dir {
@@ -322,3 +897,60 @@ fn convert_datetime(dt: &aws_smithy_types::DateTime) -> chrono::DateTime<chrono:
     let nanos = dt.as_nanos();
     chrono::Local.timestamp_nanos(nanos as i64)
 }
+
+// the errors which will not be resolved by retrying, e.g. denied by the policy or not supported by the service
+fn is_region_unavailable(e: &SdkError<GetBucketLocationError>) -> bool {
+    let status = e.raw_response().map(|r| r.status().as_u16());
+    matches!(e.code(), Some("AccessDenied" | "NotImplemented"))
+        || matches!(status, Some(FORBIDDEN | NOT_IMPLEMENTED))
+}
+
+// the parts are enlarged for the huge objects, since an upload can have at most 10,000 parts
+fn copy_part_size(size_byte: u64) -> u64 {
+    size_byte
+        .div_ceil(MULTIPART_MAX_PARTS)
+        .max(COPY_PART_MIN_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_s3::config::http::HttpResponse;
+    use aws_smithy_types::{body::SdkBody, error::ErrorMetadata};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Some("AccessDenied"), 403, true)]
+    #[case(Some("NotImplemented"), 501, true)]
+    #[case(None, 403, true)]
+    #[case(Some("InternalError"), 500, false)]
+    #[case(Some("SlowDown"), 503, false)]
+    fn test_is_region_unavailable(
+        #[case] code: Option<&str>,
+        #[case] status: u16,
+        #[case] expected: bool,
+    ) {
+        let mut meta = ErrorMetadata::builder();
+        if let Some(code) = code {
+            meta = meta.code(code);
+        }
+        let err = GetBucketLocationError::generic(meta.build());
+        let response = HttpResponse::new(status.try_into().unwrap(), SdkBody::empty());
+        let e = SdkError::service_error(err, response);
+        assert_eq!(is_region_unavailable(&e), expected);
+    }
+
+    #[test]
+    fn test_copy_part_size() {
+        const GIB: u64 = 1024 * 1024 * 1024;
+        assert_eq!(copy_part_size(6 * GIB), COPY_PART_MIN_SIZE);
+        assert_eq!(copy_part_size(5000 * GIB), COPY_PART_MIN_SIZE);
+
+        // the maximum object size (5 TiB) fits in the maximum number of parts
+        let size = 5 * 1024 * GIB;
+        let part_size = copy_part_size(size);
+        assert!(part_size > COPY_PART_MIN_SIZE);
+        assert!(size.div_ceil(part_size) <= MULTIPART_MAX_PARTS);
+    }
+}
@@ -1,8 +1,10 @@
-use std::{env, path::PathBuf};
+use std::{collections::HashMap, env, path::PathBuf};
 
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
 
+use crate::util::extension_from_file_name;
+
 const STU_ROOT_DIR_ENV_VAR: &str = "STU_ROOT_DIR";
 
 const APP_BASE_DIR: &str = ".stu";
@@ -10,6 +12,10 @@ const CONFIG_FILE_NAME: &str = "config.toml";
 const ERROR_LOG_FILE_NAME: &str = "error.log";
 const DEBUG_LOG_FILE_NAME: &str = "debug.log";
 const DOWNLOAD_DIR: &str = "download";
+const BOOKMARKS_FILE_NAME: &str = "bookmarks.json";
+const HISTORY_FILE_NAME: &str = "history.json";
+const STATS_FILE_NAME: &str = "stats.json";
+const SORT_FILE_NAME: &str = "sort.json";
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
@@ -17,6 +23,10 @@ pub struct Config {
     pub download_dir: String,
     #[serde(default)]
     pub preview: PreviewConfig,
+    #[serde(default)]
+    pub open: OpenConfig,
+    #[serde(default)]
+    pub list: ListConfig,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -25,12 +35,103 @@ pub struct PreviewConfig {
     pub highlight: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct OpenConfig {
+    #[serde(default)]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub extensions: HashMap<String, String>,
+    #[serde(default)]
+    pub mime_types: HashMap<String, String>,
+}
+
+impl OpenConfig {
+    // Priority: extension > exact MIME type > MIME type wildcard (e.g. `text/*`) > default
+    pub fn command_for(&self, file_name: &str, content_type: &str) -> Option<&str> {
+        let extension = extension_from_file_name(file_name).to_lowercase();
+        let mime_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        let mime_wildcard = mime_type
+            .split_once('/')
+            .map(|(t, _)| format!("{}/*", t))
+            .unwrap_or_default();
+
+        self.extensions
+            .iter()
+            .find(|(ext, _)| !extension.is_empty() && ext.to_lowercase() == extension)
+            .or_else(|| {
+                self.mime_types
+                    .iter()
+                    .find(|(mime, _)| mime.to_lowercase() == mime_type)
+            })
+            .or_else(|| {
+                self.mime_types
+                    .iter()
+                    .find(|(mime, _)| mime.to_lowercase() == mime_wildcard)
+            })
+            .map(|(_, command)| command.as_str())
+            .or(self.default.as_deref())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ListConfig {
+    #[serde(default = "default_list_columns")]
+    pub columns: Vec<ListColumn>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListColumn {
+    Name,
+    Size,
+    LastModified,
+    StorageClass,
+    #[serde(rename = "etag")]
+    ETag,
+    ContentType,
+}
+
+impl Default for ListConfig {
+    fn default() -> Self {
+        Self {
+            columns: default_list_columns(),
+        }
+    }
+}
+
+impl ListConfig {
+    // the name is always shown (first if not specified), and the duplicates are ignored
+    pub fn columns(&self) -> Vec<ListColumn> {
+        let mut columns = Vec::with_capacity(self.columns.len() + 1);
+        if !self.columns.contains(&ListColumn::Name) {
+            columns.push(ListColumn::Name);
+        }
+        for column in &self.columns {
+            if !columns.contains(column) {
+                columns.push(*column);
+            }
+        }
+        columns
+    }
+}
+
+fn default_list_columns() -> Vec<ListColumn> {
+    vec![ListColumn::Name, ListColumn::LastModified, ListColumn::Size]
+}
+
 impl Default for Config {
     fn default() -> Self {
         let download_dir = default_download_dir();
         Self {
             download_dir,
             preview: PreviewConfig::default(),
+            open: OpenConfig::default(),
+            list: ListConfig::default(),
         }
     }
 }
@@ -70,6 +171,30 @@ impl Config {
         Ok(String::from(path.to_string_lossy()))
     }
 
+    pub fn bookmarks_path(&self) -> anyhow::Result<String> {
+        let dir = Config::get_app_base_dir()?;
+        let path = dir.join(BOOKMARKS_FILE_NAME);
+        Ok(String::from(path.to_string_lossy()))
+    }
+
+    pub fn history_path(&self) -> anyhow::Result<String> {
+        let dir = Config::get_app_base_dir()?;
+        let path = dir.join(HISTORY_FILE_NAME);
+        Ok(String::from(path.to_string_lossy()))
+    }
+
+    pub fn stats_path(&self) -> anyhow::Result<String> {
+        let dir = Config::get_app_base_dir()?;
+        let path = dir.join(STATS_FILE_NAME);
+        Ok(String::from(path.to_string_lossy()))
+    }
+
+    pub fn sort_path(&self) -> anyhow::Result<String> {
+        let dir = Config::get_app_base_dir()?;
+        let path = dir.join(SORT_FILE_NAME);
+        Ok(String::from(path.to_string_lossy()))
+    }
+
     fn get_app_base_dir() -> anyhow::Result<PathBuf> {
         match env::var(STU_ROOT_DIR_ENV_VAR) {
             Ok(dir) => Ok(PathBuf::from(dir)),
@@ -82,3 +207,63 @@ impl Config {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_config_command_for() {
+        let config = OpenConfig {
+            default: Some("less".to_string()),
+            extensions: HashMap::from([("csv".to_string(), "vd".to_string())]),
+            mime_types: HashMap::from([
+                ("image/*".to_string(), "xdg-open".to_string()),
+                ("image/svg+xml".to_string(), "inkscape".to_string()),
+            ]),
+        };
+
+        assert_eq!(config.command_for("a.CSV", "text/csv"), Some("vd"));
+        assert_eq!(config.command_for("a.png", "image/png"), Some("xdg-open"));
+        assert_eq!(
+            config.command_for("a.svg", "image/svg+xml; charset=utf-8"),
+            Some("inkscape")
+        );
+        assert_eq!(config.command_for("a.txt", "text/plain"), Some("less"));
+        assert_eq!(
+            OpenConfig::default().command_for("a.txt", "text/plain"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_list_config_columns() {
+        let config: ListConfig = serde_json::from_str(
+            r#"{ "columns": ["size", "etag", "name", "size", "storage_class", "content_type"] }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.columns(),
+            [
+                ListColumn::Size,
+                ListColumn::ETag,
+                ListColumn::Name,
+                ListColumn::StorageClass,
+                ListColumn::ContentType,
+            ]
+        );
+
+        let config: ListConfig =
+            serde_json::from_str(r#"{ "columns": ["last_modified"] }"#).unwrap();
+        assert_eq!(
+            config.columns(),
+            [ListColumn::Name, ListColumn::LastModified]
+        );
+
+        let config: ListConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            config.columns(),
+            [ListColumn::Name, ListColumn::LastModified, ListColumn::Size]
+        );
+    }
+}
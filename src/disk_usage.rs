@@ -0,0 +1,226 @@
+use std::cmp::Reverse;
+
+use crate::object::{ObjectItem, ObjectKey, PrefixSize};
+
+// a directory or an object in the tree built from a recursive listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageNode {
+    pub name: String,
+    pub size: PrefixSize,
+    pub is_dir: bool,
+    // sorted by size, largest first
+    pub children: Vec<UsageNode>,
+}
+
+impl UsageNode {
+    // `items` are the objects with the names relative to the root
+    pub fn build(items: &[ObjectItem]) -> UsageNode {
+        let mut root = UsageNode::dir(String::new());
+        for item in items {
+            if let ObjectItem::File {
+                name, size_byte, ..
+            } = item
+            {
+                let path: Vec<&str> = name.split('/').collect();
+                root.insert(&path, *size_byte);
+            }
+        }
+        root.sort();
+        root
+    }
+
+    fn dir(name: String) -> UsageNode {
+        UsageNode {
+            name,
+            size: PrefixSize::default(),
+            is_dir: true,
+            children: Vec::new(),
+        }
+    }
+
+    fn insert(&mut self, path: &[&str], size_byte: usize) {
+        self.size += PrefixSize {
+            size_byte,
+            count: 1,
+        };
+        match path {
+            [name] => self.children.push(UsageNode {
+                name: name.to_string(),
+                size: PrefixSize {
+                    size_byte,
+                    count: 1,
+                },
+                is_dir: false,
+                children: Vec::new(),
+            }),
+            [name, rest @ ..] => {
+                let child = match self
+                    .children
+                    .iter()
+                    .position(|c| c.is_dir && c.name == *name)
+                {
+                    Some(i) => &mut self.children[i],
+                    None => {
+                        self.children.push(UsageNode::dir(name.to_string()));
+                        self.children.last_mut().unwrap()
+                    }
+                };
+                child.insert(rest, size_byte);
+            }
+            [] => {}
+        }
+    }
+
+    fn sort(&mut self) {
+        self.sort_children();
+        for child in &mut self.children {
+            child.sort();
+        }
+    }
+
+    fn sort_children(&mut self) {
+        self.children
+            .sort_by_key(|c| (Reverse(c.size.size_byte), c.name.clone()));
+    }
+
+    pub fn get(&self, path: &[String]) -> Option<&UsageNode> {
+        match path {
+            [] => Some(self),
+            [name, rest @ ..] => self
+                .children
+                .iter()
+                .find(|c| c.is_dir && &c.name == name)
+                .and_then(|c| c.get(rest)),
+        }
+    }
+
+    // removes the node and subtracts its size from the ancestors,
+    // a file and a directory can have the same name, so `is_dir` tells which one is removed
+    pub fn remove(&mut self, path: &[String], is_dir: bool) -> Option<PrefixSize> {
+        let removed = match path {
+            [] => return None,
+            [name] => {
+                let i = self
+                    .children
+                    .iter()
+                    .position(|c| c.is_dir == is_dir && &c.name == name)?;
+                self.children.remove(i).size
+            }
+            [name, rest @ ..] => {
+                let i = self
+                    .children
+                    .iter()
+                    .position(|c| c.is_dir && &c.name == name)?;
+                let removed = self.children[i].remove(rest, is_dir)?;
+                // a prefix without any objects no longer exists
+                if self.children[i].size.count == 0 {
+                    self.children.remove(i);
+                }
+                removed
+            }
+        };
+        self.size.size_byte -= removed.size_byte;
+        self.size.count -= removed.count;
+        self.sort_children();
+        Some(removed)
+    }
+
+    // the sizes of all directories in the tree, `key` is the key of this node
+    pub fn prefix_sizes(&self, key: &ObjectKey) -> Vec<(ObjectKey, PrefixSize)> {
+        let mut sizes = vec![(key.clone(), self.size)];
+        for child in self.children.iter().filter(|c| c.is_dir) {
+            let mut child_key = key.clone();
+            child_key.object_path.push(child.name.clone());
+            sizes.extend(child.prefix_sizes(&child_key));
+        }
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+
+    use super::*;
+
+    fn file(name: &str, size_byte: usize) -> ObjectItem {
+        ObjectItem::File {
+            name: name.to_string(),
+            size_byte,
+            last_modified: Local::now(),
+            e_tag: None,
+            storage_class: None,
+        }
+    }
+
+    fn path(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_build_and_remove() {
+        let items = vec![
+            file("a/x.txt", 10),
+            file("a/b/y.txt", 30),
+            file("a/b/z.txt", 5),
+            file("c.txt", 20),
+        ];
+        let mut root = UsageNode::build(&items);
+
+        let size = |size_byte, count| PrefixSize { size_byte, count };
+        assert_eq!(root.size, size(65, 4));
+        let names: Vec<&str> = root.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["a", "c.txt"]);
+
+        let a = root.get(&path(&["a"])).unwrap();
+        assert_eq!(a.size, size(45, 3));
+        let names: Vec<&str> = a.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["b", "x.txt"]);
+
+        let key = ObjectKey {
+            bucket_name: "bucket-1".to_string(),
+            object_path: path(&["p"]),
+        };
+        let sizes = root.prefix_sizes(&key);
+        assert_eq!(sizes.len(), 3);
+        assert_eq!(sizes[2].0.object_path, path(&["p", "a", "b"]));
+        assert_eq!(sizes[2].1, size(35, 2));
+
+        // the ancestors are updated and sorted again
+        assert_eq!(root.remove(&path(&["a", "b"]), true), Some(size(35, 2)));
+        assert_eq!(root.size, size(30, 2));
+        let names: Vec<&str> = root.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["c.txt", "a"]);
+        assert_eq!(root.remove(&path(&["a", "b"]), true), None);
+
+        // the empty directory is removed with its last object
+        assert_eq!(
+            root.remove(&path(&["a", "x.txt"]), false),
+            Some(size(10, 1))
+        );
+        let names: Vec<&str> = root.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["c.txt"]);
+    }
+
+    #[test]
+    fn test_remove_file_and_dir_with_same_name() {
+        let items = vec![file("a", 5), file("a/x.txt", 10), file("b/y.txt", 20)];
+        let size = |size_byte, count| PrefixSize { size_byte, count };
+
+        // only the file is removed, even though it comes after the directory
+        let mut root = UsageNode::build(&items);
+        assert_eq!(root.remove(&path(&["a"]), false), Some(size(5, 1)));
+        assert_eq!(root.get(&path(&["a"])).unwrap().size, size(10, 1));
+        assert_eq!(root.size, size(30, 2));
+
+        let mut root = UsageNode::build(&items);
+        assert_eq!(root.remove(&path(&["a"]), true), Some(size(10, 1)));
+        assert!(root.get(&path(&["a"])).is_none());
+        let names: Vec<&str> = root.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["b", "a"]);
+
+        // a file is not removed as a directory
+        let mut root = UsageNode::build(&items);
+        assert_eq!(root.remove(&path(&["b", "y.txt"]), true), None);
+    }
+}
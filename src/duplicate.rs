@@ -0,0 +1,113 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use crate::object::ObjectSummary;
+
+// objects with the same size and ETag, the oldest first
+#[derive(Debug, Clone)]
+pub struct DuplicateGroup {
+    pub size_byte: usize,
+    pub e_tag: String,
+    pub objects: Vec<ObjectSummary>,
+}
+
+impl DuplicateGroup {
+    // the ETag of a multipart object is not the MD5 of its content but `<md5 of the part md5s>-<parts>`,
+    // so only the copies uploaded with the same part size share it
+    pub fn is_multipart(&self) -> bool {
+        is_multipart_e_tag(&self.e_tag)
+    }
+
+    // the bytes which can be freed by keeping only one copy
+    pub fn wasted_bytes(&self) -> usize {
+        self.size_byte * (self.objects.len() - 1)
+    }
+}
+
+fn is_multipart_e_tag(e_tag: &str) -> bool {
+    e_tag
+        .rsplit_once('-')
+        .is_some_and(|(_, parts)| !parts.is_empty() && parts.chars().all(|c| c.is_ascii_digit()))
+}
+
+// sorted by the wasted bytes, largest first
+pub fn find_duplicates(objects: Vec<ObjectSummary>) -> Vec<DuplicateGroup> {
+    let mut map: HashMap<(usize, String), Vec<ObjectSummary>> = HashMap::new();
+    for obj in objects {
+        // empty objects all have the same ETag, and the ETag may be missing on some services
+        if obj.size_byte == 0 || obj.e_tag.is_empty() {
+            continue;
+        }
+        map.entry((obj.size_byte, obj.e_tag.clone()))
+            .or_default()
+            .push(obj);
+    }
+
+    let mut groups: Vec<DuplicateGroup> = map
+        .into_iter()
+        .filter(|(_, objects)| objects.len() > 1)
+        .map(|((size_byte, e_tag), mut objects)| {
+            objects.sort_by(|a, b| {
+                a.last_modified
+                    .cmp(&b.last_modified)
+                    .then_with(|| a.key.cmp(&b.key))
+            });
+            DuplicateGroup {
+                size_byte,
+                e_tag,
+                objects,
+            }
+        })
+        .collect();
+    groups.sort_by_key(|g| (Reverse(g.wasted_bytes()), g.objects[0].key.clone()));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Local, TimeZone};
+
+    use super::*;
+
+    fn obj(key: &str, size_byte: usize, e_tag: &str, days: i64) -> ObjectSummary {
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        ObjectSummary {
+            key: key.to_string(),
+            size_byte,
+            last_modified: now - Duration::days(days),
+            e_tag: e_tag.to_string(),
+            storage_class: "STANDARD".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_find_duplicates() {
+        let objects = vec![
+            obj("a/1.txt", 10, "aaa", 1),
+            obj("b/1.txt", 10, "aaa", 3),
+            obj("c/1.txt", 10, "aaa", 2),
+            // same ETag with a different size
+            obj("d/1.txt", 20, "aaa", 1),
+            obj("a/2.bin", 100, "bbb-2", 1),
+            obj("b/2.bin", 100, "bbb-2", 1),
+            // same content uploaded with a different part size
+            obj("c/2.bin", 100, "ccc-4", 1),
+            obj("a/empty", 0, "d41d8", 1),
+            obj("b/empty", 0, "d41d8", 1),
+        ];
+        let groups = find_duplicates(objects);
+
+        assert_eq!(groups.len(), 2);
+
+        assert_eq!(groups[0].e_tag, "bbb-2");
+        assert!(groups[0].is_multipart());
+        assert_eq!(groups[0].wasted_bytes(), 100);
+        let keys: Vec<&str> = groups[0].objects.iter().map(|o| o.key.as_str()).collect();
+        assert_eq!(keys, ["a/2.bin", "b/2.bin"]);
+
+        assert_eq!(groups[1].e_tag, "aaa");
+        assert!(!groups[1].is_multipart());
+        assert_eq!(groups[1].wasted_bytes(), 20);
+        let keys: Vec<&str> = groups[1].objects.iter().map(|o| o.key.as_str()).collect();
+        assert_eq!(keys, ["b/1.txt", "c/1.txt", "a/1.txt"]);
+    }
+}
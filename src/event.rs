@@ -1,25 +1,47 @@
 use std::{
+    collections::HashMap,
     fmt::{self, Debug, Formatter},
-    sync::mpsc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
     thread,
+    time::Duration,
 };
 
 use crossterm::event::KeyEvent;
 
 use crate::{
     client::Client,
+    duplicate::DuplicateGroup,
     error::{AppError, Result},
-    object::{BucketItem, FileDetail, FileVersion, ObjectItem, ObjectKey, RawObject},
+    external::{EditingObject, OpeningObject},
+    object::{
+        BucketItem, FileDetail, FileVersion, ObjectItem, ObjectKey, ObjectLocation, PrefixSize,
+        RawObject,
+    },
+    sort::ObjectListSort,
+    stats::BucketStats,
 };
 
+const TERMINAL_EVENT_POLL_TIMEOUT: Duration = Duration::from_millis(100);
+
 #[derive(Debug)]
 pub enum AppEventType {
     Key(KeyEvent),
     Resize(usize, usize),
-    Initialize(Client, Option<String>),
+    Initialize(Client, Option<String>, Option<ObjectLocation>),
     CompleteInitialize(Result<CompleteInitializeResult>),
+    BucketListLoadRegions(Vec<String>),
+    CompleteLoadBucketRegions(CompleteLoadBucketRegionsResult),
     LoadObjects,
     CompleteLoadObjects(Result<CompleteLoadObjectsResult>),
+    LoadObjectsRecursive,
+    CompleteLoadObjectsRecursive(Result<CompleteLoadObjectsResult>),
+    LoadFinderItems,
+    SearchFinderItems(String),
+    CompleteLoadFinderItems(Result<CompleteLoadFinderItemsResult>),
+    CompleteMoveTo(Result<CompleteMoveToResult>),
     LoadObject,
     CompleteLoadObject(Result<CompleteLoadObjectResult>),
     DownloadObject(FileDetail, Option<String>),
@@ -27,9 +49,52 @@ pub enum AppEventType {
     CompleteDownloadObject(Result<CompleteDownloadObjectResult>),
     PreviewObject(FileDetail, Option<String>),
     CompletePreviewObject(Result<CompletePreviewObjectResult>),
+    PreviewFollowObject(FileDetail, usize, usize, Option<String>),
+    CompletePreviewFollowObject(usize, Result<CompletePreviewFollowObjectResult>),
+    EditObject(FileDetail),
+    CompleteEditObject(Result<CompleteEditObjectResult>),
+    OpenEditor(Box<EditingObject>),
+    UploadEditedObject(FileDetail, String, RawObject),
+    CompleteUploadEditedObject(Result<CompleteUploadEditedObjectResult>),
+    OpenObject(FileDetail, Option<String>),
+    CompleteOpenObject(Result<CompleteOpenObjectResult>),
+    OpenWithCommand(Box<OpeningObject>),
+    OpenBucketStats,
+    LoadBucketStats(String),
+    CompleteLoadBucketStats(Result<CompleteLoadBucketStatsResult>),
     BucketListMoveDown,
     ObjectListMoveDown,
     ObjectListMoveUp,
+    ObjectListMoveToObject(String),
+    ObjectListSaveSort(ObjectListSort),
+    ObjectListDownloadObjects(Vec<ObjectItem>),
+    ObjectListDeleteObjects(Vec<ObjectItem>),
+    ObjectListCopyObjects(Vec<ObjectItem>, String),
+    ObjectListChangeStorageClass(Vec<ObjectItem>, String),
+    CompleteBulkObjects(Result<CompleteBulkObjectsResult>),
+    CompleteTransfer(Result<CompleteTransferResult>),
+    ObjectListCalculatePrefixSizes(Vec<String>),
+    CompleteCalculatePrefixSize(Result<CompleteCalculatePrefixSizeResult>),
+    ObjectListLoadContentTypes,
+    CompleteLoadContentTypes(CompleteLoadContentTypesResult),
+    OpenDiskUsage,
+    CompleteOpenDiskUsage(Result<CompleteOpenDiskUsageResult>),
+    DiskUsageDeleteItem(ObjectKey, ObjectItem),
+    CompleteDiskUsageDelete(Result<CompleteDiskUsageDeleteResult>),
+    OpenDuplicates,
+    CompleteOpenDuplicates(Result<CompleteOpenDuplicatesResult>),
+    DuplicatesDeleteObjects(String, Vec<String>),
+    CompleteDuplicatesDelete(Result<CompleteDuplicatesDeleteResult>),
+    MoveToInput(String),
+    AddBookmark,
+    OpenBookmarks,
+    BookmarksMoveTo(ObjectLocation),
+    RenameBookmark(usize, Option<String>),
+    DeleteBookmark(usize),
+    HistoryBack,
+    HistoryForward,
+    OpenHistory,
+    HistoryMoveTo(ObjectLocation),
     BackToBucketList,
     OpenPreview(FileDetail, Option<String>),
     DetailDownloadObject(FileDetail, Option<String>),
@@ -61,6 +126,18 @@ impl CompleteInitializeResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompleteLoadBucketRegionsResult {
+    // by bucket name, the buckets whose region could not be loaded are not included
+    pub regions: HashMap<String, String>,
+}
+
+impl CompleteLoadBucketRegionsResult {
+    pub fn new(regions: HashMap<String, String>) -> CompleteLoadBucketRegionsResult {
+        CompleteLoadBucketRegionsResult { regions }
+    }
+}
+
 #[derive(Debug)]
 pub struct CompleteLoadObjectsResult {
     pub items: Vec<ObjectItem>,
@@ -73,6 +150,197 @@ impl CompleteLoadObjectsResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompleteLoadBucketStatsResult {
+    pub stats: BucketStats,
+}
+
+impl CompleteLoadBucketStatsResult {
+    pub fn new(stats: Result<BucketStats>) -> Result<CompleteLoadBucketStatsResult> {
+        let stats = stats?;
+        Ok(CompleteLoadBucketStatsResult { stats })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadFinderItemsResult {
+    pub items: Vec<ObjectItem>,
+    pub finder_id: usize,
+    pub done: bool,
+    // whether the loading was stopped before all the items were listed
+    pub truncated: bool,
+}
+
+impl CompleteLoadFinderItemsResult {
+    pub fn new(
+        items: Result<Vec<ObjectItem>>,
+        finder_id: usize,
+        done: bool,
+        truncated: bool,
+    ) -> Result<CompleteLoadFinderItemsResult> {
+        let items = items?;
+        Ok(CompleteLoadFinderItemsResult {
+            items,
+            finder_id,
+            done,
+            truncated,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteMoveToResult {
+    pub location: ObjectLocation,
+    pub loaded: Vec<(ObjectKey, Vec<ObjectItem>)>,
+}
+
+impl CompleteMoveToResult {
+    pub fn new(
+        location: ObjectLocation,
+        loaded: Result<Vec<(ObjectKey, Vec<ObjectItem>)>>,
+    ) -> Result<CompleteMoveToResult> {
+        let loaded = loaded?;
+        Ok(CompleteMoveToResult { location, loaded })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteBulkObjectsResult {
+    pub msg: String,
+}
+
+impl CompleteBulkObjectsResult {
+    pub fn new(msg: Result<String>) -> Result<CompleteBulkObjectsResult> {
+        let msg = msg?;
+        Ok(CompleteBulkObjectsResult { msg })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteTransferResult {
+    pub msg: String,
+    pub loaded: Vec<(ObjectKey, Vec<ObjectItem>)>,
+}
+
+impl CompleteTransferResult {
+    pub fn new(
+        msg: Result<String>,
+        loaded: Vec<(ObjectKey, Vec<ObjectItem>)>,
+    ) -> Result<CompleteTransferResult> {
+        let msg = msg?;
+        Ok(CompleteTransferResult { msg, loaded })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteCalculatePrefixSizeResult {
+    pub key: ObjectKey,
+    pub size: PrefixSize,
+}
+
+impl CompleteCalculatePrefixSizeResult {
+    pub fn new(
+        key: ObjectKey,
+        size: Result<PrefixSize>,
+    ) -> Result<CompleteCalculatePrefixSizeResult> {
+        let size = size?;
+        Ok(CompleteCalculatePrefixSizeResult { key, size })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadContentTypesResult {
+    pub key: ObjectKey,
+    // by the name of the object, None if failed to load
+    pub content_types: HashMap<String, Option<String>>,
+}
+
+impl CompleteLoadContentTypesResult {
+    pub fn new(
+        key: ObjectKey,
+        content_types: HashMap<String, Option<String>>,
+    ) -> CompleteLoadContentTypesResult {
+        CompleteLoadContentTypesResult { key, content_types }
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteOpenDiskUsageResult {
+    pub key: ObjectKey,
+    pub items: Vec<ObjectItem>,
+}
+
+impl CompleteOpenDiskUsageResult {
+    pub fn new(
+        key: ObjectKey,
+        items: Result<Vec<ObjectItem>>,
+    ) -> Result<CompleteOpenDiskUsageResult> {
+        let items = items?;
+        Ok(CompleteOpenDiskUsageResult { key, items })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteDiskUsageDeleteResult {
+    pub msg: String,
+    // the key of the deleted object or prefix
+    pub key: ObjectKey,
+    pub is_dir: bool,
+    pub loaded: Vec<(ObjectKey, Vec<ObjectItem>)>,
+}
+
+impl CompleteDiskUsageDeleteResult {
+    pub fn new(
+        msg: Result<String>,
+        key: ObjectKey,
+        is_dir: bool,
+        loaded: Vec<(ObjectKey, Vec<ObjectItem>)>,
+    ) -> Result<CompleteDiskUsageDeleteResult> {
+        let msg = msg?;
+        Ok(CompleteDiskUsageDeleteResult {
+            msg,
+            key,
+            is_dir,
+            loaded,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteOpenDuplicatesResult {
+    pub key: ObjectKey,
+    pub groups: Vec<DuplicateGroup>,
+}
+
+impl CompleteOpenDuplicatesResult {
+    pub fn new(
+        key: ObjectKey,
+        groups: Result<Vec<DuplicateGroup>>,
+    ) -> Result<CompleteOpenDuplicatesResult> {
+        let groups = groups?;
+        Ok(CompleteOpenDuplicatesResult { key, groups })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteDuplicatesDeleteResult {
+    pub msg: String,
+    // the full keys of the deleted objects
+    pub keys: Vec<String>,
+    pub loaded: Vec<(ObjectKey, Vec<ObjectItem>)>,
+}
+
+impl CompleteDuplicatesDeleteResult {
+    pub fn new(
+        msg: Result<String>,
+        keys: Vec<String>,
+        loaded: Vec<(ObjectKey, Vec<ObjectItem>)>,
+    ) -> Result<CompleteDuplicatesDeleteResult> {
+        let msg = msg?;
+        Ok(CompleteDuplicatesDeleteResult { msg, keys, loaded })
+    }
+}
+
 #[derive(Debug)]
 pub struct CompleteLoadObjectResult {
     pub detail: Box<FileDetail>, // to avoid "warning: large size difference between variants" for AppEventType
@@ -134,6 +402,93 @@ impl CompletePreviewObjectResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompletePreviewFollowObjectResult {
+    pub obj: RawObject,
+    pub e_tag: Option<String>,
+    pub reset: bool,
+}
+
+impl CompletePreviewFollowObjectResult {
+    pub fn new(
+        result: Result<(RawObject, Option<String>, bool)>,
+    ) -> Result<CompletePreviewFollowObjectResult> {
+        let (obj, e_tag, reset) = result?;
+        Ok(CompletePreviewFollowObjectResult { obj, e_tag, reset })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteEditObjectResult {
+    pub obj: RawObject,
+    pub e_tag: String,
+    pub file_detail: FileDetail,
+}
+
+impl CompleteEditObjectResult {
+    pub fn new(
+        obj: Result<(RawObject, Option<String>)>,
+        file_detail: FileDetail,
+    ) -> Result<CompleteEditObjectResult> {
+        let (obj, e_tag) = obj?;
+        // the ETag is required to detect the modifications while editing
+        let e_tag = e_tag.ok_or_else(|| AppError::msg("Failed to get the ETag of the object"))?;
+        Ok(CompleteEditObjectResult {
+            obj,
+            e_tag,
+            file_detail,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteUploadEditedObjectResult {
+    pub detail: Box<FileDetail>,
+    pub versions: Vec<FileVersion>,
+    pub map_key: ObjectKey,
+    pub obj: RawObject,
+}
+
+impl CompleteUploadEditedObjectResult {
+    pub fn new(
+        detail: Result<FileDetail>,
+        versions: Result<Vec<FileVersion>>,
+        map_key: ObjectKey,
+        obj: RawObject,
+    ) -> Result<CompleteUploadEditedObjectResult> {
+        let detail = Box::new(detail?);
+        let versions = versions?;
+        Ok(CompleteUploadEditedObjectResult {
+            detail,
+            versions,
+            map_key,
+            obj,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteOpenObjectResult {
+    pub obj: RawObject,
+    pub file_detail: FileDetail,
+    pub command: String,
+}
+
+impl CompleteOpenObjectResult {
+    pub fn new(
+        obj: Result<RawObject>,
+        file_detail: FileDetail,
+        command: String,
+    ) -> Result<CompleteOpenObjectResult> {
+        let obj = obj?;
+        Ok(CompleteOpenObjectResult {
+            obj,
+            file_detail,
+            command,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct Sender {
     tx: mpsc::Sender<AppEventType>,
@@ -153,21 +508,62 @@ impl Sender {
 
 pub struct Receiver {
     rx: mpsc::Receiver<AppEventType>,
+    reader: Arc<TerminalEventReader>,
 }
 
 impl Receiver {
     pub fn recv(&self) -> AppEventType {
         self.rx.recv().unwrap()
     }
+
+    // Stop reading terminal events so that an external command can use the terminal.
+    pub fn pause_terminal_events(&self) {
+        self.reader.paused.store(true, Ordering::SeqCst);
+        // wait for the current read to finish
+        drop(self.reader.reading.lock());
+    }
+
+    pub fn resume_terminal_events(&self) {
+        self.reader.paused.store(false, Ordering::SeqCst);
+    }
+}
+
+#[derive(Default)]
+struct TerminalEventReader {
+    paused: AtomicBool,
+    reading: Mutex<()>,
 }
 
 pub fn new() -> (Sender, Receiver) {
     let (tx, rx) = mpsc::channel();
+    let reader = Arc::new(TerminalEventReader::default());
     let tx = Sender { tx };
-    let rx = Receiver { rx };
+    let rx = Receiver {
+        rx,
+        reader: reader.clone(),
+    };
 
     let event_tx = tx.clone();
     thread::spawn(move || loop {
+        let Ok(reading) = reader.reading.lock() else {
+            return;
+        };
+        if reader.paused.load(Ordering::SeqCst) {
+            drop(reading);
+            thread::sleep(TERMINAL_EVENT_POLL_TIMEOUT);
+            continue;
+        }
+
+        match crossterm::event::poll(TERMINAL_EVENT_POLL_TIMEOUT) {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(e) => {
+                let e = AppError::new("Failed to read event", e);
+                event_tx.send(AppEventType::NotifyError(e));
+                continue;
+            }
+        }
+
         match crossterm::event::read() {
             Ok(e) => match e {
                 crossterm::event::Event::Key(key) => {
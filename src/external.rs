@@ -0,0 +1,220 @@
+use std::{
+    env,
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use tempfile::{Builder, TempDir};
+
+use crate::{
+    error::{AppError, Result},
+    file::join_key_path,
+    object::{FileDetail, RawObject},
+};
+
+const DEFAULT_EDITOR: &str = "vi";
+
+// commands which hand the file to another application and exit without waiting for it
+const DETACHED_COMMANDS: &[&str] = &[
+    "open",
+    "xdg-open",
+    "gio",
+    "gnome-open",
+    "kde-open",
+    "wslview",
+    "cygstart",
+    "explorer.exe",
+];
+
+#[derive(Debug)]
+pub struct TempObjectFile {
+    path: PathBuf,
+    _dir: TempDir, // the directory is removed when dropped
+}
+
+impl TempObjectFile {
+    // the directory is created only for the owner, and the file is readable only by the owner
+    pub fn new(file_name: &str, obj: &RawObject) -> Result<TempObjectFile> {
+        let mut builder = Builder::new();
+        builder.prefix("stu-");
+        #[cfg(unix)]
+        builder.permissions(std::os::unix::fs::PermissionsExt::from_mode(0o700));
+        let dir = builder
+            .tempdir()
+            .map_err(|e| AppError::new("Failed to create temporary directory", e))?;
+        // keep the file name so that commands can detect the file type
+        let path = join_key_path(dir.path(), file_name)?;
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut f = options
+            .open(&path)
+            .map_err(|e| AppError::new("Failed to create file", e))?;
+        f.write_all(&obj.bytes)
+            .map_err(|e| AppError::new("Failed to write file", e))?;
+
+        Ok(TempObjectFile { path, _dir: dir })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn read(&self) -> Result<Vec<u8>> {
+        std::fs::read(&self.path).map_err(|e| AppError::new("Failed to read file", e))
+    }
+}
+
+#[derive(Debug)]
+pub struct EditingObject {
+    pub file_detail: FileDetail,
+    // ETag of the original content, used to detect the modifications by others on upload
+    pub e_tag: String,
+    pub original: RawObject,
+    file: TempObjectFile,
+}
+
+impl EditingObject {
+    pub fn new(
+        file_detail: FileDetail,
+        e_tag: String,
+        original: RawObject,
+    ) -> Result<EditingObject> {
+        let file = TempObjectFile::new(&file_detail.name, &original)?;
+        Ok(EditingObject {
+            file_detail,
+            e_tag,
+            original,
+            file,
+        })
+    }
+
+    pub fn editor_command(&self) -> Command {
+        let editor = env::var("VISUAL")
+            .or_else(|_| env::var("EDITOR"))
+            .unwrap_or_else(|_| DEFAULT_EDITOR.to_string());
+        build_command(&editor, self.file.path())
+    }
+
+    pub fn read_edited(&self) -> Result<Vec<u8>> {
+        self.file.read()
+    }
+}
+
+#[derive(Debug)]
+pub struct OpeningObject {
+    pub file: TempObjectFile,
+    command: String,
+}
+
+impl OpeningObject {
+    pub fn new(
+        file_detail: &FileDetail,
+        obj: &RawObject,
+        command: String,
+    ) -> Result<OpeningObject> {
+        let file = TempObjectFile::new(&file_detail.name, obj)?;
+        Ok(OpeningObject { file, command })
+    }
+
+    pub fn command(&self) -> Command {
+        build_command(&self.command, self.file.path())
+    }
+
+    // the file must be kept after the command exits, as it may be read later by another application
+    pub fn is_detached(&self) -> bool {
+        let program = self.command.split_whitespace().next().unwrap_or_default();
+        let name = Path::new(program)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        DETACHED_COMMANDS.contains(&name.as_str())
+    }
+}
+
+pub fn run_command(mut cmd: Command) -> Result<()> {
+    let program = cmd.get_program().to_string_lossy().to_string();
+    let status = cmd
+        .status()
+        .map_err(|e| AppError::new(format!("Failed to run '{}'", program), e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        let msg = format!("'{}' exited with {}", program, status);
+        Err(AppError::msg(msg))
+    }
+}
+
+// `command` may contain arguments, like `code --wait`
+fn build_command(command: &str, path: &Path) -> Command {
+    let mut words = command.split_whitespace();
+    let mut cmd = Command::new(words.next().unwrap_or(DEFAULT_EDITOR));
+    cmd.args(words).arg(path);
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_command() {
+        let path = Path::new("/tmp/file.json");
+
+        let cmd = build_command("vim", path);
+        assert_eq!(cmd.get_program(), "vim");
+        assert_eq!(cmd.get_args().collect::<Vec<_>>(), ["/tmp/file.json"]);
+
+        let cmd = build_command("code --wait", path);
+        assert_eq!(cmd.get_program(), "code");
+        assert_eq!(
+            cmd.get_args().collect::<Vec<_>>(),
+            ["--wait", "/tmp/file.json"]
+        );
+
+        let cmd = build_command("", path);
+        assert_eq!(cmd.get_program(), "vi");
+    }
+
+    #[test]
+    fn test_temp_object_file() {
+        let obj = RawObject {
+            bytes: b"hello".to_vec(),
+        };
+        let file = TempObjectFile::new("file.txt", &obj).unwrap();
+        assert_eq!(file.read().unwrap(), b"hello");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(file.path()).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+            let dir_mode = std::fs::metadata(file.path().parent().unwrap())
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(dir_mode & 0o777, 0o700);
+        }
+
+        let path = file.path().to_path_buf();
+        drop(file);
+        assert!(!path.exists());
+
+        assert!(TempObjectFile::new("..", &obj).is_err());
+    }
+
+    #[test]
+    fn test_opening_object_is_detached() {
+        let opening = |command: &str| OpeningObject {
+            file: TempObjectFile::new("file.txt", &RawObject { bytes: vec![] }).unwrap(),
+            command: command.to_string(),
+        };
+        assert!(opening("xdg-open").is_detached());
+        assert!(opening("/usr/bin/open -a Preview").is_detached());
+        assert!(!opening("less").is_detached());
+        assert!(!opening("code --wait").is_detached());
+    }
+}
@@ -1,12 +1,15 @@
 use arboard::Clipboard;
-use chrono::Local;
+use chrono::{DateTime, Local};
 use std::{
     fs::{File, OpenOptions},
     io::{BufWriter, Write},
-    path::Path,
+    path::{Component, Path, PathBuf},
 };
 
-use crate::error::{AppError, Result};
+use crate::{
+    error::{AppError, Result},
+    object::ObjectItem,
+};
 
 pub fn save_binary(path: &str, bytes: &[u8]) -> Result<()> {
     create_dirs(path)?;
@@ -52,8 +55,154 @@ fn create_dirs(path: &str) -> Result<()> {
     }
 }
 
+// directories first, then files, each sorted by name
+pub fn read_local_dir(dir: &Path) -> Result<Vec<ObjectItem>> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| AppError::new("Failed to read directory", e))?;
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| AppError::new("Failed to read directory", e))?;
+        let metadata = entry
+            .metadata()
+            .map_err(|e| AppError::new("Failed to read metadata", e))?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if metadata.is_dir() {
+            dirs.push(ObjectItem::Dir { name });
+        } else {
+            let last_modified = metadata
+                .modified()
+                .map(DateTime::<Local>::from)
+                .unwrap_or_default();
+            files.push(ObjectItem::File {
+                name,
+                size_byte: metadata.len() as usize,
+                last_modified,
+                e_tag: None,
+                storage_class: None,
+            });
+        }
+    }
+    dirs.sort_by(|a, b| a.name().cmp(b.name()));
+    files.sort_by(|a, b| a.name().cmp(b.name()));
+    Ok(dirs.into_iter().chain(files).collect())
+}
+
+// returns the paths relative to `dir` joined with `/`, and the sizes
+pub fn list_local_files(dir: &Path) -> Result<Vec<(String, usize)>> {
+    let mut files = Vec::new();
+    for item in read_local_dir(dir)? {
+        match item {
+            ObjectItem::Dir { name } => {
+                for (file_name, size_byte) in list_local_files(&dir.join(&name))? {
+                    files.push((format!("{}/{}", name, file_name), size_byte));
+                }
+            }
+            ObjectItem::File {
+                name, size_byte, ..
+            } => files.push((name, size_byte)),
+        }
+    }
+    Ok(files)
+}
+
+pub fn copy_local_file(from: &Path, to: &Path) -> Result<()> {
+    create_dirs(&to.to_string_lossy())?;
+    std::fs::copy(from, to).map_err(|e| AppError::new("Failed to copy file", e))?;
+    Ok(())
+}
+
+// joins the slash-separated object key (relative to some prefix) to `dir`,
+// the keys which could resolve outside of `dir` (`..`, absolute or empty components) are refused
+pub fn join_key_path(dir: &Path, key: &str) -> Result<PathBuf> {
+    let mut path = dir.to_path_buf();
+    for name in key.split('/') {
+        let mut components = Path::new(name).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(c)), None) if c == name => path.push(c),
+            _ => return Err(AppError::msg(format!("Unsafe object key: {}", key))),
+        }
+    }
+    Ok(path)
+}
+
+// removes the directories under `dir` (including itself) which have become empty
+pub fn remove_empty_dirs(dir: &Path) -> Result<()> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| AppError::new("Failed to read directory", e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| AppError::new("Failed to read directory", e))?;
+        let is_dir = entry
+            .file_type()
+            .map_err(|e| AppError::new("Failed to read directory", e))?
+            .is_dir();
+        if is_dir {
+            remove_empty_dirs(&entry.path())?;
+        }
+    }
+    let is_empty = std::fs::read_dir(dir)
+        .map_err(|e| AppError::new("Failed to read directory", e))?
+        .next()
+        .is_none();
+    if is_empty {
+        std::fs::remove_dir(dir).map_err(|e| AppError::new("Failed to remove directory", e))?;
+    }
+    Ok(())
+}
+
 pub fn copy_to_clipboard(value: String) -> Result<()> {
     Clipboard::new()
         .and_then(|mut c| c.set_text(value))
         .map_err(|e| AppError::new("Failed to copy to clipboard", e))
 }
+
+pub fn paste_from_clipboard() -> Result<String> {
+    Clipboard::new()
+        .and_then(|mut c| c.get_text())
+        .map_err(|e| AppError::new("Failed to paste from clipboard", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_key_path() {
+        let dir = Path::new("/tmp/download");
+
+        assert_eq!(
+            join_key_path(dir, "a/b.txt").unwrap(),
+            PathBuf::from("/tmp/download/a/b.txt")
+        );
+        assert_eq!(
+            join_key_path(dir, "a..b/.c").unwrap(),
+            PathBuf::from("/tmp/download/a..b/.c")
+        );
+
+        for key in [
+            "a/../../../.bashrc",
+            "..",
+            "/etc/passwd",
+            "a//b",
+            "a/./b",
+            "a/",
+            "",
+        ] {
+            assert!(join_key_path(dir, key).is_err(), "{}", key);
+        }
+    }
+
+    #[test]
+    fn test_remove_empty_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("root");
+        std::fs::create_dir_all(root.join("a/b")).unwrap();
+        std::fs::create_dir_all(root.join("c")).unwrap();
+        std::fs::write(root.join("c/file"), "x").unwrap();
+
+        remove_empty_dirs(&root).unwrap();
+
+        assert!(!root.join("a").exists());
+        assert!(root.join("c/file").exists());
+    }
+}
@@ -0,0 +1,502 @@
+use std::{cmp::Ordering, ops::Range};
+
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use regex::{Regex, RegexBuilder};
+
+use crate::object::{BucketItem, ObjectItem};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    #[default]
+    Substring,
+    Regex,
+    Glob,
+    Fuzzy,
+}
+
+impl FilterMode {
+    pub fn next(self) -> FilterMode {
+        match self {
+            FilterMode::Substring => FilterMode::Regex,
+            FilterMode::Regex => FilterMode::Glob,
+            FilterMode::Glob => FilterMode::Fuzzy,
+            FilterMode::Fuzzy => FilterMode::Substring,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FilterOptions {
+    pub mode: FilterMode,
+    pub ignore_case: bool,
+}
+
+impl FilterOptions {
+    pub fn dialog_title(&self) -> &'static str {
+        match (self.mode, self.ignore_case) {
+            (FilterMode::Substring, false) => "Filter (substring)",
+            (FilterMode::Substring, true) => "Filter (substring, ignore case)",
+            (FilterMode::Regex, false) => "Filter (regex)",
+            (FilterMode::Regex, true) => "Filter (regex, ignore case)",
+            (FilterMode::Glob, false) => "Filter (glob)",
+            (FilterMode::Glob, true) => "Filter (glob, ignore case)",
+            (FilterMode::Fuzzy, false) => "Filter (fuzzy)",
+            (FilterMode::Fuzzy, true) => "Filter (fuzzy, ignore case)",
+        }
+    }
+}
+
+// the query compiled according to the options
+#[derive(Default)]
+pub enum ListFilter {
+    #[default]
+    All,
+    Regex(Regex),
+    Fuzzy(Box<SkimMatcherV2>, String),
+    // the query could not be compiled
+    Nothing,
+}
+
+impl std::fmt::Debug for ListFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListFilter::All => write!(f, "All"),
+            ListFilter::Regex(re) => write!(f, "Regex({})", re),
+            ListFilter::Fuzzy(_, query) => write!(f, "Fuzzy({})", query),
+            ListFilter::Nothing => write!(f, "Nothing"),
+        }
+    }
+}
+
+impl ListFilter {
+    pub fn new(query: &str, options: FilterOptions) -> ListFilter {
+        if query.is_empty() {
+            return ListFilter::All;
+        }
+        let pattern = match options.mode {
+            FilterMode::Substring => regex::escape(query),
+            FilterMode::Regex => query.to_string(),
+            FilterMode::Glob => glob_to_regex(query),
+            FilterMode::Fuzzy => {
+                let matcher = SkimMatcherV2::default();
+                let matcher = if options.ignore_case {
+                    matcher.ignore_case()
+                } else {
+                    matcher.respect_case()
+                };
+                return ListFilter::Fuzzy(Box::new(matcher), query.to_string());
+            }
+        };
+        match RegexBuilder::new(&pattern)
+            .case_insensitive(options.ignore_case)
+            .build()
+        {
+            Ok(re) => ListFilter::Regex(re),
+            Err(_) => ListFilter::Nothing,
+        }
+    }
+
+    // returns the byte ranges to highlight if `s` matches
+    pub fn find(&self, s: &str) -> Option<Vec<Range<usize>>> {
+        match self {
+            ListFilter::All => Some(Vec::new()),
+            ListFilter::Regex(re) => re.find(s).map(|m| vec![m.range()]),
+            ListFilter::Fuzzy(matcher, query) => matcher
+                .fuzzy_indices(s, query)
+                .map(|(_, indices)| char_indices_to_ranges(s, &indices)),
+            ListFilter::Nothing => None,
+        }
+    }
+}
+
+// only `*` and `?` are special, and the whole name must match
+fn glob_to_regex(glob: &str) -> String {
+    let mut pattern = String::from("^");
+    for c in glob.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    pattern
+}
+
+fn char_indices_to_ranges(s: &str, indices: &[usize]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (i, (start, c)) in s.char_indices().enumerate() {
+        if !indices.contains(&i) {
+            continue;
+        }
+        let end = start + c.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    ranges
+}
+
+// splits `s` into the segments with a flag whether the segment is matched
+pub fn split_matches<'a>(s: &'a str, ranges: &[Range<usize>]) -> Vec<(&'a str, bool)> {
+    let mut segments = Vec::new();
+    let mut pos = 0;
+    for range in ranges {
+        if pos < range.start {
+            segments.push((&s[pos..range.start], false));
+        }
+        if range.start < range.end {
+            segments.push((&s[range.clone()], true));
+        }
+        pos = range.end;
+    }
+    if pos < s.len() {
+        segments.push((&s[pos..], false));
+    }
+    segments
+}
+
+// the name filter combined with the attribute filters like `size>100MB` or `ext:csv` (AND)
+#[derive(Debug, Default)]
+pub struct ObjectFilter {
+    name: ListFilter,
+    attrs: Vec<AttrFilter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AttrFilter {
+    Size(Vec<Ordering>, usize),
+    // compared with the last modified time
+    Modified(Vec<Ordering>, DateTime<Local>),
+    Ext(Vec<String>),
+    Dir(bool),
+}
+
+impl ObjectFilter {
+    // the tokens which are not attribute filters are used as the name query
+    pub fn new(query: &str, options: FilterOptions, now: DateTime<Local>) -> ObjectFilter {
+        let mut names = Vec::new();
+        let mut attrs = Vec::new();
+        for token in query.split_whitespace() {
+            match parse_attr_filter(token, now) {
+                Some(attr) => attrs.push(attr),
+                None => names.push(token),
+            }
+        }
+        let name = if attrs.is_empty() {
+            // keep the spaces in the query as they are
+            ListFilter::new(query, options)
+        } else {
+            ListFilter::new(&names.join(" "), options)
+        };
+        ObjectFilter { name, attrs }
+    }
+
+    pub fn name_filter(&self) -> &ListFilter {
+        &self.name
+    }
+
+    pub fn matches(&self, item: &ObjectItem) -> bool {
+        self.name.find(item.name()).is_some() && self.attrs.iter().all(|attr| attr.matches(item))
+    }
+}
+
+impl AttrFilter {
+    fn matches(&self, item: &ObjectItem) -> bool {
+        match (self, item) {
+            (AttrFilter::Dir(dir), item) => *dir == matches!(item, ObjectItem::Dir { .. }),
+            (AttrFilter::Size(ords, size), ObjectItem::File { size_byte, .. }) => {
+                ords.contains(&size_byte.cmp(size))
+            }
+            (AttrFilter::Modified(ords, time), ObjectItem::File { last_modified, .. }) => {
+                ords.contains(&last_modified.cmp(time))
+            }
+            (AttrFilter::Ext(exts), ObjectItem::File { name, .. }) => name
+                .rsplit_once('.')
+                .is_some_and(|(_, ext)| exts.iter().any(|e| e.eq_ignore_ascii_case(ext))),
+            // the sizes and the dates of the directories are unknown
+            (_, ObjectItem::Dir { .. }) => false,
+        }
+    }
+}
+
+fn parse_attr_filter(token: &str, now: DateTime<Local>) -> Option<AttrFilter> {
+    if let Some(value) = token.strip_prefix("ext:") {
+        let exts: Vec<String> = value
+            .split(',')
+            .map(|e| e.trim_start_matches('.').to_string())
+            .filter(|e| !e.is_empty())
+            .collect();
+        return (!exts.is_empty()).then_some(AttrFilter::Ext(exts));
+    }
+    if let Some(value) = token.strip_prefix("type:") {
+        return match value {
+            "dir" => Some(AttrFilter::Dir(true)),
+            "file" => Some(AttrFilter::Dir(false)),
+            _ => None,
+        };
+    }
+    if let Some(rest) = token.strip_prefix("size") {
+        let (ords, value) = parse_operator(rest)?;
+        return parse_size(value).map(|size| AttrFilter::Size(ords, size));
+    }
+    if let Some(rest) = token.strip_prefix("modified") {
+        let (ords, value) = parse_operator(rest)?;
+        if let Some(date) = parse_date(value) {
+            return Some(AttrFilter::Modified(ords, date));
+        }
+        // `modified<7d` means newer than 7 days ago, so the operator is reversed
+        let time = now - parse_duration(value)?;
+        let ords = ords.into_iter().map(Ordering::reverse).collect();
+        return Some(AttrFilter::Modified(ords, time));
+    }
+    None
+}
+
+// the name filter combined with `region:us-east-1` (or `region:eu-,ap-` by prefixes)
+#[derive(Debug, Default)]
+pub struct BucketFilter {
+    name: ListFilter,
+    regions: Vec<String>,
+}
+
+impl BucketFilter {
+    pub fn new(query: &str, options: FilterOptions) -> BucketFilter {
+        let mut names = Vec::new();
+        let mut regions = Vec::new();
+        for token in query.split_whitespace() {
+            match token.strip_prefix("region:") {
+                Some(value) => regions.extend(
+                    value
+                        .split(',')
+                        .filter(|r| !r.is_empty())
+                        .map(|r| r.to_ascii_lowercase()),
+                ),
+                None => names.push(token),
+            }
+        }
+        let name = if names.len() == query.split_whitespace().count() {
+            // keep the spaces in the query as they are
+            ListFilter::new(query, options)
+        } else {
+            ListFilter::new(&names.join(" "), options)
+        };
+        BucketFilter { name, regions }
+    }
+
+    pub fn name_filter(&self) -> &ListFilter {
+        &self.name
+    }
+
+    pub fn has_region(&self) -> bool {
+        !self.regions.is_empty()
+    }
+
+    pub fn matches(&self, item: &BucketItem) -> bool {
+        let region_matched = self.regions.is_empty()
+            || item
+                .region
+                .as_ref()
+                .is_some_and(|region| self.regions.iter().any(|r| region.starts_with(r)));
+        region_matched && self.name.find(&item.name).is_some()
+    }
+}
+
+fn parse_operator(s: &str) -> Option<(Vec<Ordering>, &str)> {
+    let operators = [
+        (">=", vec![Ordering::Greater, Ordering::Equal]),
+        ("<=", vec![Ordering::Less, Ordering::Equal]),
+        (">", vec![Ordering::Greater]),
+        ("<", vec![Ordering::Less]),
+        ("=", vec![Ordering::Equal]),
+    ];
+    operators
+        .into_iter()
+        .find_map(|(op, ords)| s.strip_prefix(op).map(|value| (ords, value)))
+}
+
+// units are binary (KB = 1024 bytes), as the sizes are shown
+fn parse_size(s: &str) -> Option<usize> {
+    let pos = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (num, unit) = s.split_at(pos);
+    let num: f64 = num.parse().ok()?;
+    let exp = match unit.to_ascii_uppercase().as_str() {
+        "" | "B" => 0,
+        "K" | "KB" | "KIB" => 1,
+        "M" | "MB" | "MIB" => 2,
+        "G" | "GB" | "GIB" => 3,
+        "T" | "TB" | "TIB" => 4,
+        _ => return None,
+    };
+    Some((num * 1024_f64.powi(exp)) as usize)
+}
+
+fn parse_duration(s: &str) -> Option<Duration> {
+    // the unit may be a multibyte character while typing
+    let (pos, _) = s.char_indices().last()?;
+    let (num, unit) = s.split_at(pos);
+    let num: i64 = num.parse().ok()?;
+    match unit {
+        "h" => Duration::try_hours(num),
+        "d" => Duration::try_days(num),
+        "w" => Duration::try_weeks(num),
+        _ => None,
+    }
+}
+
+fn parse_date(s: &str) -> Option<DateTime<Local>> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?;
+    Local
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+        .earliest()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn options(mode: FilterMode, ignore_case: bool) -> FilterOptions {
+        FilterOptions { mode, ignore_case }
+    }
+
+    #[rstest]
+    #[case(FilterMode::Substring, false, "ba", "foobar", Some(vec![(3, 5)]))]
+    #[case(FilterMode::Substring, false, "BA", "foobar", None)]
+    #[case(FilterMode::Substring, true, "BA", "foobar", Some(vec![(3, 5)]))]
+    #[case(FilterMode::Substring, false, ".", "foobar", None)]
+    #[case(FilterMode::Regex, false, "o+b", "foobar", Some(vec![(1, 4)]))]
+    #[case(FilterMode::Regex, true, "^F", "foobar", Some(vec![(0, 1)]))]
+    #[case(FilterMode::Regex, false, "(", "foobar", None)]
+    #[case(FilterMode::Glob, false, "*.txt", "a.txt", Some(vec![(0, 5)]))]
+    #[case(FilterMode::Glob, false, "*.txt", "a.txt.bak", None)]
+    #[case(FilterMode::Glob, true, "?.TXT", "a.txt", Some(vec![(0, 5)]))]
+    #[case(FilterMode::Fuzzy, false, "fbr", "foobar", Some(vec![(0, 1), (3, 4), (5, 6)]))]
+    #[case(FilterMode::Fuzzy, false, "FB", "foobar", None)]
+    #[case(FilterMode::Fuzzy, true, "FB", "foobar", Some(vec![(0, 1), (3, 4)]))]
+    #[case(FilterMode::Fuzzy, false, "ab", "aあb", Some(vec![(0, 1), (4, 5)]))]
+    fn test_find(
+        #[case] mode: FilterMode,
+        #[case] ignore_case: bool,
+        #[case] query: &str,
+        #[case] s: &str,
+        #[case] expected: Option<Vec<(usize, usize)>>,
+    ) {
+        let filter = ListFilter::new(query, options(mode, ignore_case));
+        let actual = filter
+            .find(s)
+            .map(|ranges| ranges.iter().map(|r| (r.start, r.end)).collect());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_split_matches() {
+        assert_eq!(split_matches("foobar", &[]), [("foobar", false)]);
+        assert_eq!(
+            split_matches("foobar", &[0..1, 3..5]),
+            [("f", true), ("oo", false), ("ba", true), ("r", false)]
+        );
+    }
+
+    #[rstest]
+    #[case("7d", Some(Duration::days(7)))]
+    #[case("12h", Some(Duration::hours(12)))]
+    #[case("2w", Some(Duration::weeks(2)))]
+    #[case("7日", None)]
+    #[case("é", None)]
+    #[case("d", None)]
+    #[case("", None)]
+    #[case("9999999999999999w", None)]
+    fn test_parse_duration(#[case] s: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(parse_duration(s), expected);
+    }
+
+    #[test]
+    fn test_object_filter() {
+        let now = Local.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap();
+        let file = |name: &str, size_byte: usize, days: i64| ObjectItem::File {
+            name: name.to_string(),
+            size_byte,
+            last_modified: now - Duration::days(days),
+            e_tag: None,
+            storage_class: None,
+        };
+        let items = [
+            ObjectItem::Dir {
+                name: "logs".to_string(),
+            },
+            file("a.parquet", 200 * 1024 * 1024, 1),
+            file("b.PARQUET", 1024, 30),
+            file("c.csv", 100 * 1024 * 1024, 3),
+            file("logs.txt", 10, 0),
+        ];
+        let filter_names = |query: &str| -> Vec<&str> {
+            let filter = ObjectFilter::new(query, FilterOptions::default(), now);
+            items
+                .iter()
+                .filter(|item| filter.matches(item))
+                .map(|item| item.name())
+                .collect()
+        };
+
+        assert_eq!(filter_names("size>100MB"), ["a.parquet"]);
+        assert_eq!(filter_names("size>=100MB"), ["a.parquet", "c.csv"]);
+        assert_eq!(filter_names("size<1k"), ["logs.txt"]);
+        assert_eq!(
+            filter_names("modified<7d"),
+            ["a.parquet", "c.csv", "logs.txt"]
+        );
+        assert_eq!(filter_names("modified>1w"), ["b.PARQUET"]);
+        assert_eq!(filter_names("modified<2024-01-09"), ["b.PARQUET", "c.csv"]);
+        assert_eq!(filter_names("ext:parquet"), ["a.parquet", "b.PARQUET"]);
+        assert_eq!(filter_names("ext:csv,.txt"), ["c.csv", "logs.txt"]);
+        assert_eq!(filter_names("type:dir"), ["logs"]);
+        assert_eq!(filter_names("log type:file"), ["logs.txt"]);
+        assert_eq!(filter_names("ext:parquet modified<7d"), ["a.parquet"]);
+        // not an attribute filter
+        assert_eq!(filter_names("size>abc"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_bucket_filter() {
+        let items: Vec<BucketItem> = [
+            ("logs", Some("us-east-1")),
+            ("backup", Some("eu-west-1")),
+            ("backup-logs", Some("eu-central-1")),
+            ("private", None),
+        ]
+        .into_iter()
+        .map(|(name, region)| BucketItem {
+            name: name.into(),
+            creation_date: None,
+            region: region.map(String::from),
+        })
+        .collect();
+        let filter_names = |query: &str| -> Vec<&str> {
+            let filter = BucketFilter::new(query, FilterOptions::default());
+            items
+                .iter()
+                .filter(|item| filter.matches(item))
+                .map(|item| item.name.as_str())
+                .collect()
+        };
+
+        assert_eq!(filter_names("region:us-east-1"), ["logs"]);
+        assert_eq!(filter_names("region:eu-"), ["backup", "backup-logs"]);
+        assert_eq!(filter_names("logs region:EU-"), ["backup-logs"]);
+        assert_eq!(
+            filter_names("region:us-east-1,eu-west-1"),
+            ["logs", "backup"]
+        );
+        assert_eq!(filter_names("back"), ["backup", "backup-logs"]);
+        assert_eq!(
+            filter_names("region:"),
+            ["logs", "backup", "backup-logs", "private"]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+pub mod avro;
+pub mod orc;
+
+use serde_json::Value;
+
+use crate::{error::Result, util::extension_from_file_name};
+
+pub const MAX_SAMPLE_RECORDS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerFormat {
+    Avro,
+    Orc,
+}
+
+impl ContainerFormat {
+    pub fn detect(file_name: &str, bytes: &[u8]) -> Option<ContainerFormat> {
+        match extension_from_file_name(file_name).to_lowercase().as_str() {
+            "avro" => return Some(ContainerFormat::Avro),
+            "orc" => return Some(ContainerFormat::Orc),
+            _ => {}
+        }
+        if avro::has_magic(bytes) {
+            Some(ContainerFormat::Avro)
+        } else if orc::has_magic(bytes) {
+            Some(ContainerFormat::Orc)
+        } else {
+            None
+        }
+    }
+
+    pub fn str(&self) -> &'static str {
+        match self {
+            ContainerFormat::Avro => "Avro",
+            ContainerFormat::Orc => "ORC",
+        }
+    }
+
+    pub fn read(&self, bytes: &[u8]) -> Result<ContainerContent> {
+        match self {
+            ContainerFormat::Avro => avro::read(bytes, MAX_SAMPLE_RECORDS),
+            ContainerFormat::Orc => orc::read(bytes, MAX_SAMPLE_RECORDS),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ContainerContent {
+    pub format: ContainerFormat,
+    pub properties: Vec<(String, String)>,
+    pub schema: Vec<String>,
+    pub total_records: u64,
+    pub records: Vec<Value>,
+}
+
+pub fn format_decimal(unscaled: i128, scale: u32) -> String {
+    if scale == 0 {
+        return unscaled.to_string();
+    }
+    let sign = if unscaled < 0 { "-" } else { "" };
+    let digits = unscaled.unsigned_abs().to_string();
+    let scale = scale as usize;
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, int_part, frac_part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_container_format() {
+        assert_eq!(
+            ContainerFormat::detect("a.avro", b""),
+            Some(ContainerFormat::Avro)
+        );
+        assert_eq!(
+            ContainerFormat::detect("a.ORC", b""),
+            Some(ContainerFormat::Orc)
+        );
+        assert_eq!(
+            ContainerFormat::detect("a", b"Obj\x01xxx"),
+            Some(ContainerFormat::Avro)
+        );
+        assert_eq!(
+            ContainerFormat::detect("a.bin", b"ORCxxx"),
+            Some(ContainerFormat::Orc)
+        );
+        assert_eq!(ContainerFormat::detect("a.txt", b"hello"), None);
+    }
+
+    #[test]
+    fn test_format_decimal() {
+        assert_eq!(format_decimal(12345, 2), "123.45");
+        assert_eq!(format_decimal(-5, 3), "-0.005");
+        assert_eq!(format_decimal(42, 0), "42");
+    }
+}
@@ -0,0 +1,752 @@
+use std::{collections::HashMap, io::Read};
+
+use chrono::{DateTime, NaiveDate};
+use flate2::{read::DeflateDecoder, Crc};
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    error::{AppError, Result},
+    format::{format_decimal, ContainerContent, ContainerFormat},
+};
+
+const MAGIC: &[u8] = b"Obj\x01";
+const SYNC_SIZE: usize = 16;
+
+// limits against corrupted data, items such as nulls can be encoded in zero bytes
+// so the counts in the data are not bounded by its size
+const MAX_COLLECTION_ITEMS: u64 = 1_000_000;
+const MAX_DEPTH: usize = 256;
+
+pub fn has_magic(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+pub fn read(bytes: &[u8], max_records: usize) -> Result<ContainerContent> {
+    if !has_magic(bytes) {
+        return Err(AppError::msg("Not an Avro object container file"));
+    }
+    let mut reader = Reader::new(&bytes[MAGIC.len()..]);
+
+    let metadata = read_metadata(&mut reader)?;
+    let sync = reader.read_fixed(SYNC_SIZE)?;
+
+    let schema_json = metadata
+        .get("avro.schema")
+        .ok_or_else(|| AppError::msg("Avro schema not found in file header"))?;
+    let schema_value: Value = serde_json::from_slice(schema_json)
+        .map_err(|e| AppError::new("Failed to parse Avro schema", e))?;
+    let mut names = HashMap::new();
+    let schema = parse_schema(&schema_value, None, &mut names)?;
+
+    let codec = metadata
+        .get("avro.codec")
+        .map(|c| String::from_utf8_lossy(c).to_string())
+        .unwrap_or_else(|| "null".to_string());
+
+    let mut total_records: u64 = 0;
+    let mut records = Vec::new();
+    while !reader.is_empty() {
+        let count = reader.read_long()?;
+        if count < 0 {
+            return Err(AppError::msg("Invalid Avro block count"));
+        }
+        let data = reader.read_bytes()?;
+        if reader.read_fixed(SYNC_SIZE)? != sync {
+            return Err(AppError::msg("Invalid Avro sync marker"));
+        }
+        total_records = total_records.saturating_add(count as u64);
+
+        if records.len() < max_records {
+            let data = decompress(&codec, data)?;
+            let mut block_reader = Reader::new(&data);
+            for _ in 0..count {
+                if records.len() >= max_records {
+                    break;
+                }
+                records.push(decode_value(&mut block_reader, &schema, &names, 0)?);
+            }
+        }
+    }
+
+    let properties = vec![("Codec".to_string(), codec)];
+    let schema_lines = serde_json::to_string_pretty(&schema_value)
+        .unwrap_or_default()
+        .lines()
+        .map(String::from)
+        .collect();
+
+    Ok(ContainerContent {
+        format: ContainerFormat::Avro,
+        properties,
+        schema: schema_lines,
+        total_records,
+        records,
+    })
+}
+
+fn read_metadata(reader: &mut Reader) -> Result<HashMap<String, Vec<u8>>> {
+    let mut metadata = HashMap::new();
+    let mut total = 0;
+    loop {
+        let count = reader.read_block_count(&mut total)?;
+        if count == 0 {
+            break;
+        }
+        for _ in 0..count {
+            let key = reader.read_string()?;
+            let value = reader.read_bytes()?.to_vec();
+            metadata.insert(key, value);
+        }
+    }
+    Ok(metadata)
+}
+
+fn decompress(codec: &str, data: &[u8]) -> Result<Vec<u8>> {
+    match codec {
+        "null" => Ok(data.to_vec()),
+        "deflate" => {
+            let mut buf = Vec::new();
+            DeflateDecoder::new(data)
+                .read_to_end(&mut buf)
+                .map_err(|e| AppError::new("Failed to decompress Avro block", e))?;
+            Ok(buf)
+        }
+        "snappy" => {
+            // the compressed data is followed by the big-endian CRC32 of the uncompressed data
+            let (data, checksum) = data
+                .split_last_chunk::<4>()
+                .ok_or_else(|| AppError::msg("Invalid Avro snappy block"))?;
+            let buf = snap::raw::Decoder::new()
+                .decompress_vec(data)
+                .map_err(|e| AppError::new("Failed to decompress Avro block", e))?;
+            let mut crc = Crc::new();
+            crc.update(&buf);
+            if crc.sum() != u32::from_be_bytes(*checksum) {
+                return Err(AppError::msg("Invalid Avro snappy block checksum"));
+            }
+            Ok(buf)
+        }
+        "zstandard" => zstd::stream::decode_all(data)
+            .map_err(|e| AppError::new("Failed to decompress Avro block", e)),
+        _ => Err(AppError::msg(format!("Unsupported Avro codec: {}", codec))),
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Schema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Vec<(String, Schema)>),
+    Enum(Vec<String>),
+    Array(Box<Schema>),
+    Map(Box<Schema>),
+    Union(Vec<Schema>),
+    Fixed(usize),
+    Ref(String),
+    Date,
+    TimestampMillis,
+    TimestampMicros,
+    Decimal(Box<Schema>, u32),
+}
+
+fn parse_schema(
+    value: &Value,
+    namespace: Option<&str>,
+    names: &mut HashMap<String, Schema>,
+) -> Result<Schema> {
+    match value {
+        Value::String(s) => {
+            Ok(parse_primitive(s).unwrap_or_else(|| Schema::Ref(full_name(s, namespace))))
+        }
+        Value::Array(branches) => {
+            let branches = branches
+                .iter()
+                .map(|b| parse_schema(b, namespace, names))
+                .collect::<Result<Vec<Schema>>>()?;
+            Ok(Schema::Union(branches))
+        }
+        Value::Object(obj) => {
+            let type_value = obj
+                .get("type")
+                .ok_or_else(|| AppError::msg("Avro schema has no type"))?;
+            let type_name = match type_value {
+                Value::String(s) => s.as_str(),
+                // nested complex type such as {"type": {"type": "array", ...}}
+                _ => return parse_schema(type_value, namespace, names),
+            };
+
+            let name = obj.get("name").and_then(Value::as_str);
+            let namespace = obj.get("namespace").and_then(Value::as_str).or(namespace);
+
+            let schema = match type_name {
+                "record" | "error" => {
+                    let name = full_name(name.unwrap_or_default(), namespace);
+                    // register first to allow recursive references
+                    names.insert(name.clone(), Schema::Record(Vec::new()));
+                    let record_namespace = name.rsplit_once('.').map(|(ns, _)| ns.to_string());
+                    let fields = obj
+                        .get("fields")
+                        .and_then(Value::as_array)
+                        .ok_or_else(|| AppError::msg("Avro record has no fields"))?
+                        .iter()
+                        .map(|field| {
+                            let field_name = field
+                                .get("name")
+                                .and_then(Value::as_str)
+                                .unwrap_or_default()
+                                .to_string();
+                            let field_type = field
+                                .get("type")
+                                .ok_or_else(|| AppError::msg("Avro field has no type"))?;
+                            let field_schema =
+                                parse_schema(field_type, record_namespace.as_deref(), names)?;
+                            Ok((field_name, field_schema))
+                        })
+                        .collect::<Result<Vec<(String, Schema)>>>()?;
+                    let schema = Schema::Record(fields);
+                    names.insert(name, schema.clone());
+                    schema
+                }
+                "enum" => {
+                    let symbols = obj
+                        .get("symbols")
+                        .and_then(Value::as_array)
+                        .map(|symbols| {
+                            symbols
+                                .iter()
+                                .map(|s| s.as_str().unwrap_or_default().to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    let schema = Schema::Enum(symbols);
+                    names.insert(
+                        full_name(name.unwrap_or_default(), namespace),
+                        schema.clone(),
+                    );
+                    schema
+                }
+                "fixed" => {
+                    let size = obj.get("size").and_then(Value::as_u64).unwrap_or_default();
+                    let schema = Schema::Fixed(size as usize);
+                    names.insert(
+                        full_name(name.unwrap_or_default(), namespace),
+                        schema.clone(),
+                    );
+                    parse_logical(obj, schema)
+                }
+                "array" => {
+                    let items = obj
+                        .get("items")
+                        .ok_or_else(|| AppError::msg("Avro array has no items"))?;
+                    Schema::Array(Box::new(parse_schema(items, namespace, names)?))
+                }
+                "map" => {
+                    let values = obj
+                        .get("values")
+                        .ok_or_else(|| AppError::msg("Avro map has no values"))?;
+                    Schema::Map(Box::new(parse_schema(values, namespace, names)?))
+                }
+                s => match parse_primitive(s) {
+                    Some(schema) => parse_logical(obj, schema),
+                    None => Schema::Ref(full_name(s, namespace)),
+                },
+            };
+            Ok(schema)
+        }
+        _ => Err(AppError::msg("Invalid Avro schema")),
+    }
+}
+
+fn parse_primitive(s: &str) -> Option<Schema> {
+    let schema = match s {
+        "null" => Schema::Null,
+        "boolean" => Schema::Boolean,
+        "int" => Schema::Int,
+        "long" => Schema::Long,
+        "float" => Schema::Float,
+        "double" => Schema::Double,
+        "bytes" => Schema::Bytes,
+        "string" => Schema::String,
+        _ => return None,
+    };
+    Some(schema)
+}
+
+fn parse_logical(obj: &Map<String, Value>, schema: Schema) -> Schema {
+    match (obj.get("logicalType").and_then(Value::as_str), &schema) {
+        (Some("date"), Schema::Int) => Schema::Date,
+        (Some("timestamp-millis"), Schema::Long) => Schema::TimestampMillis,
+        (Some("timestamp-micros"), Schema::Long) => Schema::TimestampMicros,
+        (Some("decimal"), Schema::Bytes | Schema::Fixed(_)) => {
+            let scale = obj.get("scale").and_then(Value::as_u64).unwrap_or_default();
+            Schema::Decimal(Box::new(schema), scale as u32)
+        }
+        _ => schema,
+    }
+}
+
+fn full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) if !ns.is_empty() && !name.contains('.') => format!("{}.{}", ns, name),
+        _ => name.to_string(),
+    }
+}
+
+fn decode_value(
+    reader: &mut Reader,
+    schema: &Schema,
+    names: &HashMap<String, Schema>,
+    depth: usize,
+) -> Result<Value> {
+    if depth > MAX_DEPTH {
+        return Err(AppError::msg("Avro data is nested too deeply"));
+    }
+    let value = match schema {
+        Schema::Null => Value::Null,
+        Schema::Boolean => Value::Bool(reader.read_byte()? != 0),
+        Schema::Int | Schema::Long => Value::from(reader.read_long()?),
+        Schema::Float => {
+            let bytes = reader.read_fixed(4)?;
+            let f = f32::from_le_bytes(bytes.try_into().unwrap());
+            float_value(f as f64)
+        }
+        Schema::Double => {
+            let bytes = reader.read_fixed(8)?;
+            float_value(f64::from_le_bytes(bytes.try_into().unwrap()))
+        }
+        Schema::Bytes => Value::String(String::from_utf8_lossy(reader.read_bytes()?).into()),
+        Schema::String => Value::String(reader.read_string()?),
+        Schema::Record(fields) => {
+            let mut map = Map::new();
+            for (name, field_schema) in fields {
+                map.insert(
+                    name.clone(),
+                    decode_value(reader, field_schema, names, depth + 1)?,
+                );
+            }
+            Value::Object(map)
+        }
+        Schema::Enum(symbols) => {
+            let i = reader.read_long()? as usize;
+            let symbol = symbols
+                .get(i)
+                .ok_or_else(|| AppError::msg("Invalid Avro enum index"))?;
+            Value::String(symbol.clone())
+        }
+        Schema::Array(items) => {
+            let mut values = Vec::new();
+            let mut total = 0;
+            loop {
+                let count = reader.read_block_count(&mut total)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    values.push(decode_value(reader, items, names, depth + 1)?);
+                }
+            }
+            Value::Array(values)
+        }
+        Schema::Map(values) => {
+            let mut map = Map::new();
+            let mut total = 0;
+            loop {
+                let count = reader.read_block_count(&mut total)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    let key = reader.read_string()?;
+                    map.insert(key, decode_value(reader, values, names, depth + 1)?);
+                }
+            }
+            Value::Object(map)
+        }
+        Schema::Union(branches) => {
+            let i = reader.read_long()? as usize;
+            let branch = branches
+                .get(i)
+                .ok_or_else(|| AppError::msg("Invalid Avro union index"))?;
+            decode_value(reader, branch, names, depth + 1)?
+        }
+        Schema::Fixed(size) => {
+            Value::String(String::from_utf8_lossy(reader.read_fixed(*size)?).into())
+        }
+        Schema::Ref(name) => {
+            let schema = names
+                .get(name)
+                .or_else(|| {
+                    // fall back to the simple name
+                    let simple = name.rsplit('.').next().unwrap_or_default();
+                    names
+                        .iter()
+                        .find(|(n, _)| n.rsplit('.').next() == Some(simple))
+                        .map(|(_, s)| s)
+                })
+                .ok_or_else(|| AppError::msg(format!("Unknown Avro type: {}", name)))?;
+            decode_value(reader, schema, names, depth + 1)?
+        }
+        Schema::Date => {
+            let days = reader.read_long()?;
+            NaiveDate::from_ymd_opt(1970, 1, 1)
+                .and_then(|epoch| epoch.checked_add_signed(chrono::Duration::days(days)))
+                .map(|d| Value::String(d.to_string()))
+                .unwrap_or(Value::from(days))
+        }
+        Schema::TimestampMillis => {
+            let millis = reader.read_long()?;
+            DateTime::from_timestamp_millis(millis)
+                .map(|dt| Value::String(dt.to_rfc3339()))
+                .unwrap_or(Value::from(millis))
+        }
+        Schema::TimestampMicros => {
+            let micros = reader.read_long()?;
+            DateTime::from_timestamp_micros(micros)
+                .map(|dt| Value::String(dt.to_rfc3339()))
+                .unwrap_or(Value::from(micros))
+        }
+        Schema::Decimal(underlying, scale) => {
+            let bytes = match underlying.as_ref() {
+                Schema::Fixed(size) => reader.read_fixed(*size)?,
+                _ => reader.read_bytes()?,
+            };
+            Value::String(format_decimal_bytes(bytes, *scale))
+        }
+    };
+    Ok(value)
+}
+
+fn float_value(f: f64) -> Value {
+    Number::from_f64(f)
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(f.to_string()))
+}
+
+fn format_decimal_bytes(bytes: &[u8], scale: u32) -> String {
+    // two's-complement big-endian unscaled value
+    let mut n: i128 = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+        -1
+    } else {
+        0
+    };
+    for b in bytes {
+        n = (n << 8) | (*b as i128);
+    }
+    format_decimal(n, scale)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn read_byte(&mut self) -> Result<u8> {
+        let b = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| AppError::msg("Unexpected end of Avro data"))?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn read_fixed(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| AppError::msg("Unexpected end of Avro data"))?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_long(&mut self) -> Result<i64> {
+        let mut n: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let b = self.read_byte()?;
+            n |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+            if shift > 63 {
+                return Err(AppError::msg("Invalid Avro varint"));
+            }
+        }
+        Ok(((n >> 1) as i64) ^ -((n & 1) as i64))
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8]> {
+        let n = self.read_long()?;
+        if n < 0 {
+            return Err(AppError::msg("Invalid Avro length"));
+        }
+        self.read_fixed(n as usize)
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        let bytes = self.read_bytes()?;
+        Ok(String::from_utf8_lossy(bytes).into())
+    }
+
+    // `total` is the sum of the counts of the previous blocks of the same array or map
+    fn read_block_count(&mut self, total: &mut u64) -> Result<u64> {
+        let count = self.read_long()?;
+        if count < 0 {
+            // negative count is followed by the block size in bytes
+            self.read_long()?;
+        }
+        let count = count.unsigned_abs();
+        *total = total.saturating_add(count);
+        if *total > MAX_COLLECTION_ITEMS {
+            return Err(AppError::msg("Too many items in Avro array or map"));
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::DeflateEncoder, Compression};
+    use serde_json::json;
+
+    use super::*;
+
+    fn long(n: i64) -> Vec<u8> {
+        let mut z = ((n << 1) ^ (n >> 63)) as u64;
+        let mut buf = Vec::new();
+        loop {
+            if z & !0x7f == 0 {
+                buf.push(z as u8);
+                return buf;
+            }
+            buf.push(((z & 0x7f) | 0x80) as u8);
+            z >>= 7;
+        }
+    }
+
+    fn string(s: &str) -> Vec<u8> {
+        let mut buf = long(s.len() as i64);
+        buf.extend(s.as_bytes());
+        buf
+    }
+
+    fn container(schema: &str, codec: &str, blocks: &[(i64, Vec<u8>)]) -> Vec<u8> {
+        let sync = [7u8; SYNC_SIZE];
+        let mut buf = MAGIC.to_vec();
+        buf.extend(long(2));
+        buf.extend(string("avro.schema"));
+        buf.extend(string(schema));
+        buf.extend(string("avro.codec"));
+        buf.extend(string(codec));
+        buf.extend(long(0));
+        buf.extend(sync);
+        for (count, data) in blocks {
+            let data = match codec {
+                "deflate" => {
+                    let mut e = DeflateEncoder::new(Vec::new(), Compression::default());
+                    e.write_all(data).unwrap();
+                    e.finish().unwrap()
+                }
+                "snappy" => {
+                    let mut buf = snap::raw::Encoder::new().compress_vec(data).unwrap();
+                    let mut crc = Crc::new();
+                    crc.update(data);
+                    buf.extend(crc.sum().to_be_bytes());
+                    buf
+                }
+                "zstandard" => zstd::stream::encode_all(data.as_slice(), 0).unwrap(),
+                _ => data.clone(),
+            };
+            buf.extend(long(*count));
+            buf.extend(long(data.len() as i64));
+            buf.extend(data);
+            buf.extend(sync);
+        }
+        buf
+    }
+
+    const SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "namespace": "test",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": "string"},
+            {"name": "email", "type": ["null", "string"]},
+            {"name": "tags", "type": {"type": "array", "items": "string"}},
+            {"name": "kind", "type": {"type": "enum", "name": "Kind", "symbols": ["A", "B"]}},
+            {"name": "birthday", "type": {"type": "int", "logicalType": "date"}}
+        ]
+    }"#;
+
+    fn user(id: i64, name: &str, email: Option<&str>, tags: &[&str], kind: i64) -> Vec<u8> {
+        let mut buf = long(id);
+        buf.extend(string(name));
+        match email {
+            Some(email) => {
+                buf.extend(long(1));
+                buf.extend(string(email));
+            }
+            None => buf.extend(long(0)),
+        }
+        if !tags.is_empty() {
+            buf.extend(long(tags.len() as i64));
+            for tag in tags {
+                buf.extend(string(tag));
+            }
+        }
+        buf.extend(long(0));
+        buf.extend(long(kind));
+        buf.extend(long(19723));
+        buf
+    }
+
+    #[test]
+    fn test_read_avro() {
+        for codec in ["null", "deflate", "snappy", "zstandard"] {
+            let mut block1 = user(1, "alice", Some("a@example.com"), &["x", "y"], 0);
+            block1.extend(user(2, "bob", None, &[], 1));
+            let block2 = user(3, "carol", None, &["z"], 1);
+            let bytes = container(SCHEMA, codec, &[(2, block1), (1, block2)]);
+
+            let content = read(&bytes, 2).unwrap();
+
+            assert_eq!(content.format, ContainerFormat::Avro);
+            assert_eq!(content.total_records, 3);
+            assert_eq!(
+                content.records,
+                vec![
+                    json!({"id": 1, "name": "alice", "email": "a@example.com", "tags": ["x", "y"], "kind": "A", "birthday": "2024-01-01"}),
+                    json!({"id": 2, "name": "bob", "email": null, "tags": [], "kind": "B", "birthday": "2024-01-01"}),
+                ]
+            );
+            assert_eq!(content.properties, vec![("Codec".into(), codec.into())]);
+        }
+    }
+
+    #[test]
+    fn test_read_avro_recursive_schema() {
+        let schema = r#"{
+            "type": "record",
+            "name": "Node",
+            "fields": [
+                {"name": "value", "type": "int"},
+                {"name": "next", "type": ["null", "Node"]}
+            ]
+        }"#;
+        let mut data = long(1);
+        data.extend(long(1));
+        data.extend(long(2));
+        data.extend(long(0));
+        let bytes = container(schema, "null", &[(1, data)]);
+
+        let content = read(&bytes, 10).unwrap();
+
+        assert_eq!(
+            content.records,
+            vec![json!({"value": 1, "next": {"value": 2, "next": null}})]
+        );
+    }
+
+    #[test]
+    fn test_read_avro_unsupported_codec() {
+        let bytes = container(SCHEMA, "bzip2", &[(1, vec![0])]);
+        let err = read(&bytes, 10).unwrap_err();
+        assert_eq!(err.msg, "Unsupported Avro codec: bzip2");
+    }
+
+    #[test]
+    fn test_read_avro_truncated() {
+        let data = user(1, "alice", Some("a@example.com"), &["x", "y"], 0);
+        let bytes = container(SCHEMA, "snappy", &[(1, data)]);
+        // the file without any blocks is valid
+        let header_len = container(SCHEMA, "snappy", &[]).len();
+
+        for n in (0..bytes.len()).filter(|n| *n != header_len) {
+            assert!(read(&bytes[..n], 10).is_err());
+        }
+    }
+
+    #[test]
+    fn test_read_avro_corrupted_block() {
+        let header = container(SCHEMA, "null", &[]);
+
+        let mut bytes = header.clone();
+        bytes.extend(long(1));
+        bytes.extend(long(-5));
+        assert_eq!(read(&bytes, 10).unwrap_err().msg, "Invalid Avro length");
+
+        let mut bytes = header.clone();
+        bytes.extend(long(i64::MAX));
+        bytes.extend(long(i64::MAX));
+        assert_eq!(
+            read(&bytes, 10).unwrap_err().msg,
+            "Unexpected end of Avro data"
+        );
+
+        let mut bytes = header.clone();
+        bytes.extend(long(-1));
+        bytes.extend(long(0));
+        assert_eq!(
+            read(&bytes, 10).unwrap_err().msg,
+            "Invalid Avro block count"
+        );
+
+        let mut bytes = container(SCHEMA, "null", &[(1, user(1, "a", None, &[], 0))]);
+        *bytes.last_mut().unwrap() = 0;
+        assert_eq!(
+            read(&bytes, 10).unwrap_err().msg,
+            "Invalid Avro sync marker"
+        );
+    }
+
+    #[test]
+    fn test_read_avro_too_many_items() {
+        let schema = r#"{"type": "array", "items": "null"}"#;
+        let mut data = long(i64::MAX);
+        data.extend(long(0));
+        let bytes = container(schema, "null", &[(1, data)]);
+
+        let err = read(&bytes, 10).unwrap_err();
+        assert_eq!(err.msg, "Too many items in Avro array or map");
+    }
+
+    #[test]
+    fn test_read_avro_nested_too_deeply() {
+        let schema = r#"{
+            "type": "record",
+            "name": "Node",
+            "fields": [{"name": "next", "type": ["null", "Node"]}]
+        }"#;
+        let mut data = long(1).repeat(1000);
+        data.extend(long(0));
+        let bytes = container(schema, "null", &[(1, data)]);
+
+        let err = read(&bytes, 10).unwrap_err();
+        assert_eq!(err.msg, "Avro data is nested too deeply");
+    }
+
+    #[test]
+    fn test_read_not_avro() {
+        assert!(read(b"hello", 10).is_err());
+    }
+}
@@ -0,0 +1,1368 @@
+use std::{collections::HashMap, io::Read};
+
+use chrono::{DateTime, NaiveDate};
+use flate2::read::DeflateDecoder;
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    error::{AppError, Result},
+    format::{format_decimal, ContainerContent, ContainerFormat},
+};
+
+const MAGIC: &[u8] = b"ORC";
+
+// seconds from 1970-01-01 to 2015-01-01
+const TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+pub fn has_magic(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+pub fn read(bytes: &[u8], max_records: usize) -> Result<ContainerContent> {
+    if !has_magic(bytes) || bytes.len() < MAGIC.len() + 1 {
+        return Err(AppError::msg("Not an ORC file"));
+    }
+
+    let ps_len = *bytes.last().unwrap() as usize;
+    let ps_end = bytes.len() - 1;
+    let ps_start = ps_end
+        .checked_sub(ps_len)
+        .ok_or_else(|| AppError::msg("Invalid ORC postscript"))?;
+    let post_script = PostScript::parse(&bytes[ps_start..ps_end])?;
+    let compression =
+        Compression::from_kind(post_script.compression, post_script.compression_block_size)?;
+
+    let footer_start = ps_start
+        .checked_sub(post_script.footer_length)
+        .ok_or_else(|| AppError::msg("Invalid ORC footer"))?;
+    let footer_bytes = compression.decompress(&bytes[footer_start..ps_start])?;
+    let footer = Footer::parse(&footer_bytes)?;
+
+    if footer.types.is_empty() {
+        return Err(AppError::msg("ORC file has no schema"));
+    }
+
+    let mut records = Vec::new();
+    for stripe in &footer.stripes {
+        if records.len() >= max_records {
+            break;
+        }
+        let n = (stripe.number_of_rows as usize).min(max_records - records.len());
+        let reader = StripeReader::new(bytes, stripe, compression, &footer.types)?;
+        records.extend(reader.read_column(0, n)?);
+    }
+
+    let properties = vec![
+        ("Compression".to_string(), compression.str().to_string()),
+        ("Stripes".to_string(), footer.stripes.len().to_string()),
+    ];
+
+    Ok(ContainerContent {
+        format: ContainerFormat::Orc,
+        properties,
+        schema: schema_lines(&footer.types),
+        total_records: footer.number_of_rows,
+        records,
+    })
+}
+
+fn schema_lines(types: &[Type]) -> Vec<String> {
+    let root = &types[0];
+    if root.kind == TypeKind::Struct {
+        root.field_names
+            .iter()
+            .zip(&root.subtypes)
+            .map(|(name, id)| format!("{}: {}", name, type_string(types, *id)))
+            .collect()
+    } else {
+        vec![type_string(types, 0)]
+    }
+}
+
+fn type_string(types: &[Type], id: usize) -> String {
+    let Some(t) = types.get(id) else {
+        return "unknown".to_string();
+    };
+    match t.kind {
+        TypeKind::Boolean => "boolean".into(),
+        TypeKind::Byte => "tinyint".into(),
+        TypeKind::Short => "smallint".into(),
+        TypeKind::Int => "int".into(),
+        TypeKind::Long => "bigint".into(),
+        TypeKind::Float => "float".into(),
+        TypeKind::Double => "double".into(),
+        TypeKind::String => "string".into(),
+        TypeKind::Binary => "binary".into(),
+        TypeKind::Timestamp => "timestamp".into(),
+        TypeKind::TimestampInstant => "timestamp with local time zone".into(),
+        TypeKind::Date => "date".into(),
+        TypeKind::Varchar => format!("varchar({})", t.maximum_length),
+        TypeKind::Char => format!("char({})", t.maximum_length),
+        TypeKind::Decimal => format!("decimal({},{})", t.precision, t.scale),
+        TypeKind::List => format!("array<{}>", subtype_string(types, t, 0)),
+        TypeKind::Map => format!(
+            "map<{},{}>",
+            subtype_string(types, t, 0),
+            subtype_string(types, t, 1)
+        ),
+        TypeKind::Struct => {
+            let fields: Vec<String> = t
+                .field_names
+                .iter()
+                .zip(&t.subtypes)
+                .map(|(name, id)| format!("{}:{}", name, type_string(types, *id)))
+                .collect();
+            format!("struct<{}>", fields.join(","))
+        }
+        TypeKind::Union => {
+            let branches: Vec<String> = t
+                .subtypes
+                .iter()
+                .map(|id| type_string(types, *id))
+                .collect();
+            format!("uniontype<{}>", branches.join(","))
+        }
+        TypeKind::Unknown => "unknown".into(),
+    }
+}
+
+fn subtype_string(types: &[Type], t: &Type, i: usize) -> String {
+    t.subtypes
+        .get(i)
+        .map(|id| type_string(types, *id))
+        .unwrap_or_else(|| "unknown".into())
+}
+
+// default of the compressionBlockSize in the postscript
+const DEFAULT_COMPRESSION_BLOCK_SIZE: usize = 256 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compression {
+    None,
+    Zlib,
+    Snappy,
+    // chunks are raw LZ4 blocks, so the maximum size of the decompressed chunk is kept
+    Lz4(usize),
+    Zstd,
+}
+
+impl Compression {
+    fn from_kind(kind: u64, block_size: usize) -> Result<Compression> {
+        match kind {
+            0 => Ok(Compression::None),
+            1 => Ok(Compression::Zlib),
+            2 => Ok(Compression::Snappy),
+            3 => Err(AppError::msg("Unsupported ORC compression: LZO")),
+            4 => Ok(Compression::Lz4(block_size)),
+            5 => Ok(Compression::Zstd),
+            _ => Err(AppError::msg("Unknown ORC compression")),
+        }
+    }
+
+    fn str(&self) -> &'static str {
+        match self {
+            Compression::None => "NONE",
+            Compression::Zlib => "ZLIB",
+            Compression::Snappy => "SNAPPY",
+            Compression::Lz4(_) => "LZ4",
+            Compression::Zstd => "ZSTD",
+        }
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>> {
+        if *self == Compression::None {
+            return Ok(bytes.to_vec());
+        }
+        let mut buf = Vec::new();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            if pos + 3 > bytes.len() {
+                return Err(AppError::msg("Invalid ORC compression chunk"));
+            }
+            let header = bytes[pos] as usize
+                | (bytes[pos + 1] as usize) << 8
+                | (bytes[pos + 2] as usize) << 16;
+            let is_original = header & 1 == 1;
+            let len = header >> 1;
+            pos += 3;
+            let chunk = bytes
+                .get(pos..pos + len)
+                .ok_or_else(|| AppError::msg("Invalid ORC compression chunk"))?;
+            if is_original {
+                buf.extend(chunk);
+            } else {
+                self.decompress_chunk(chunk, &mut buf)?;
+            }
+            pos += len;
+        }
+        Ok(buf)
+    }
+
+    fn decompress_chunk(&self, chunk: &[u8], buf: &mut Vec<u8>) -> Result<()> {
+        match self {
+            Compression::None => buf.extend(chunk),
+            Compression::Zlib => {
+                DeflateDecoder::new(chunk)
+                    .read_to_end(buf)
+                    .map_err(|e| AppError::new("Failed to decompress ORC data", e))?;
+            }
+            Compression::Snappy => {
+                let data = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(|e| AppError::new("Failed to decompress ORC data", e))?;
+                buf.extend(data);
+            }
+            Compression::Lz4(block_size) => {
+                let data = lz4_flex::block::decompress(chunk, *block_size)
+                    .map_err(|e| AppError::new("Failed to decompress ORC data", e))?;
+                buf.extend(data);
+            }
+            Compression::Zstd => {
+                zstd::stream::read::Decoder::new(chunk)
+                    .and_then(|mut decoder| decoder.read_to_end(buf))
+                    .map_err(|e| AppError::new("Failed to decompress ORC data", e))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct PostScript {
+    footer_length: usize,
+    compression: u64,
+    compression_block_size: usize,
+}
+
+impl PostScript {
+    fn parse(bytes: &[u8]) -> Result<PostScript> {
+        let mut ps = PostScript {
+            footer_length: 0,
+            compression: 0,
+            compression_block_size: DEFAULT_COMPRESSION_BLOCK_SIZE,
+        };
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (1, Wire::Varint(n)) => ps.footer_length = n as usize,
+                (2, Wire::Varint(n)) => ps.compression = n,
+                (3, Wire::Varint(n)) => ps.compression_block_size = n as usize,
+                _ => {}
+            }
+        }
+        Ok(ps)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Footer {
+    stripes: Vec<StripeInformation>,
+    types: Vec<Type>,
+    number_of_rows: u64,
+}
+
+impl Footer {
+    fn parse(bytes: &[u8]) -> Result<Footer> {
+        let mut footer = Footer::default();
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (3, Wire::Bytes(b)) => footer.stripes.push(StripeInformation::parse(b)?),
+                (4, Wire::Bytes(b)) => footer.types.push(Type::parse(b)?),
+                (6, Wire::Varint(n)) => footer.number_of_rows = n,
+                _ => {}
+            }
+        }
+        Ok(footer)
+    }
+}
+
+#[derive(Debug, Default)]
+struct StripeInformation {
+    offset: usize,
+    index_length: usize,
+    data_length: usize,
+    footer_length: usize,
+    number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn parse(bytes: &[u8]) -> Result<StripeInformation> {
+        let mut stripe = StripeInformation::default();
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (1, Wire::Varint(n)) => stripe.offset = n as usize,
+                (2, Wire::Varint(n)) => stripe.index_length = n as usize,
+                (3, Wire::Varint(n)) => stripe.data_length = n as usize,
+                (4, Wire::Varint(n)) => stripe.footer_length = n as usize,
+                (5, Wire::Varint(n)) => stripe.number_of_rows = n,
+                _ => {}
+            }
+        }
+        Ok(stripe)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum TypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+    #[default]
+    Unknown,
+}
+
+impl TypeKind {
+    fn from_kind(kind: u64) -> TypeKind {
+        match kind {
+            0 => TypeKind::Boolean,
+            1 => TypeKind::Byte,
+            2 => TypeKind::Short,
+            3 => TypeKind::Int,
+            4 => TypeKind::Long,
+            5 => TypeKind::Float,
+            6 => TypeKind::Double,
+            7 => TypeKind::String,
+            8 => TypeKind::Binary,
+            9 => TypeKind::Timestamp,
+            10 => TypeKind::List,
+            11 => TypeKind::Map,
+            12 => TypeKind::Struct,
+            13 => TypeKind::Union,
+            14 => TypeKind::Decimal,
+            15 => TypeKind::Date,
+            16 => TypeKind::Varchar,
+            17 => TypeKind::Char,
+            18 => TypeKind::TimestampInstant,
+            _ => TypeKind::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Type {
+    kind: TypeKind,
+    subtypes: Vec<usize>,
+    field_names: Vec<String>,
+    maximum_length: u64,
+    precision: u64,
+    scale: u64,
+}
+
+impl Type {
+    fn parse(bytes: &[u8]) -> Result<Type> {
+        let mut t = Type::default();
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (1, Wire::Varint(n)) => t.kind = TypeKind::from_kind(n),
+                (2, Wire::Varint(n)) => t.subtypes.push(n as usize),
+                (2, Wire::Bytes(b)) => {
+                    // packed
+                    let mut r = ProtoReader::new(b);
+                    while !r.is_empty() {
+                        t.subtypes.push(r.read_varint()? as usize);
+                    }
+                }
+                (3, Wire::Bytes(b)) => t.field_names.push(String::from_utf8_lossy(b).into()),
+                (4, Wire::Varint(n)) => t.maximum_length = n,
+                (5, Wire::Varint(n)) => t.precision = n,
+                (6, Wire::Varint(n)) => t.scale = n,
+                _ => {}
+            }
+        }
+        Ok(t)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    Other,
+}
+
+impl StreamKind {
+    fn from_kind(kind: u64) -> StreamKind {
+        match kind {
+            0 => StreamKind::Present,
+            1 => StreamKind::Data,
+            2 => StreamKind::Length,
+            3 => StreamKind::DictionaryData,
+            5 => StreamKind::Secondary,
+            _ => StreamKind::Other,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct ColumnEncoding {
+    kind: u64,
+    dictionary_size: usize,
+}
+
+impl ColumnEncoding {
+    fn is_v2(&self) -> bool {
+        // DIRECT_V2 = 2, DICTIONARY_V2 = 3
+        self.kind >= 2
+    }
+
+    fn is_dictionary(&self) -> bool {
+        // DICTIONARY = 1, DICTIONARY_V2 = 3
+        self.kind == 1 || self.kind == 3
+    }
+}
+
+struct StripeReader<'a> {
+    streams: HashMap<(usize, StreamKind), Vec<u8>>,
+    encodings: Vec<ColumnEncoding>,
+    types: &'a [Type],
+}
+
+impl<'a> StripeReader<'a> {
+    fn new(
+        bytes: &[u8],
+        stripe: &StripeInformation,
+        compression: Compression,
+        types: &'a [Type],
+    ) -> Result<StripeReader<'a>> {
+        let footer_start = stripe.offset + stripe.index_length + stripe.data_length;
+        let footer_bytes = bytes
+            .get(footer_start..footer_start + stripe.footer_length)
+            .ok_or_else(|| AppError::msg("Invalid ORC stripe footer"))?;
+        let footer_bytes = compression.decompress(footer_bytes)?;
+
+        let mut stream_infos = Vec::new();
+        let mut encodings = Vec::new();
+        let mut reader = ProtoReader::new(&footer_bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match (field, value) {
+                (1, Wire::Bytes(b)) => {
+                    let mut kind = 0;
+                    let mut column = 0;
+                    let mut length = 0;
+                    let mut r = ProtoReader::new(b);
+                    while let Some((f, v)) = r.next_field()? {
+                        match (f, v) {
+                            (1, Wire::Varint(n)) => kind = n,
+                            (2, Wire::Varint(n)) => column = n as usize,
+                            (3, Wire::Varint(n)) => length = n as usize,
+                            _ => {}
+                        }
+                    }
+                    stream_infos.push((StreamKind::from_kind(kind), column, length));
+                }
+                (2, Wire::Bytes(b)) => {
+                    let mut encoding = ColumnEncoding::default();
+                    let mut r = ProtoReader::new(b);
+                    while let Some((f, v)) = r.next_field()? {
+                        match (f, v) {
+                            (1, Wire::Varint(n)) => encoding.kind = n,
+                            (2, Wire::Varint(n)) => encoding.dictionary_size = n as usize,
+                            _ => {}
+                        }
+                    }
+                    encodings.push(encoding);
+                }
+                _ => {}
+            }
+        }
+
+        let mut streams = HashMap::new();
+        let mut offset = stripe.offset;
+        for (kind, column, length) in stream_infos {
+            if kind != StreamKind::Other {
+                let stream = bytes
+                    .get(offset..offset + length)
+                    .ok_or_else(|| AppError::msg("Invalid ORC stream"))?;
+                streams.insert((column, kind), compression.decompress(stream)?);
+            }
+            offset += length;
+        }
+
+        Ok(StripeReader {
+            streams,
+            encodings,
+            types,
+        })
+    }
+
+    fn stream(&self, column: usize, kind: StreamKind) -> &[u8] {
+        self.streams
+            .get(&(column, kind))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn encoding(&self, column: usize) -> ColumnEncoding {
+        self.encodings.get(column).copied().unwrap_or_default()
+    }
+
+    fn read_ints(
+        &self,
+        column: usize,
+        kind: StreamKind,
+        n: usize,
+        signed: bool,
+    ) -> Result<Vec<i64>> {
+        let data = self.stream(column, kind);
+        if self.encoding(column).is_v2() {
+            decode_int_rle_v2(data, n, signed)
+        } else {
+            decode_int_rle_v1(data, n, signed)
+        }
+    }
+
+    fn read_column(&self, column: usize, n: usize) -> Result<Vec<Value>> {
+        let t = self
+            .types
+            .get(column)
+            .ok_or_else(|| AppError::msg("Invalid ORC column"))?;
+
+        let present = match self.streams.get(&(column, StreamKind::Present)) {
+            Some(data) => decode_boolean_rle(data, n)?,
+            None => vec![true; n],
+        };
+        let count = present.iter().filter(|p| **p).count();
+
+        let values = self.read_values(column, t, count)?;
+
+        let mut values = values.into_iter();
+        let ret = present
+            .into_iter()
+            .map(|p| {
+                if p {
+                    values.next().unwrap_or(Value::Null)
+                } else {
+                    Value::Null
+                }
+            })
+            .collect();
+        Ok(ret)
+    }
+
+    fn read_values(&self, column: usize, t: &Type, n: usize) -> Result<Vec<Value>> {
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        let values = match t.kind {
+            TypeKind::Boolean => decode_boolean_rle(self.stream(column, StreamKind::Data), n)?
+                .into_iter()
+                .map(Value::Bool)
+                .collect(),
+            TypeKind::Byte => decode_byte_rle(self.stream(column, StreamKind::Data), n)?
+                .into_iter()
+                .map(|b| Value::from(b as i8))
+                .collect(),
+            TypeKind::Short | TypeKind::Int | TypeKind::Long => self
+                .read_ints(column, StreamKind::Data, n, true)?
+                .into_iter()
+                .map(Value::from)
+                .collect(),
+            TypeKind::Float => self
+                .stream(column, StreamKind::Data)
+                .chunks_exact(4)
+                .take(n)
+                .map(|b| float_value(f32::from_le_bytes(b.try_into().unwrap()) as f64))
+                .collect(),
+            TypeKind::Double => self
+                .stream(column, StreamKind::Data)
+                .chunks_exact(8)
+                .take(n)
+                .map(|b| float_value(f64::from_le_bytes(b.try_into().unwrap())))
+                .collect(),
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => self
+                .read_strings(column, n)?
+                .into_iter()
+                .map(Value::String)
+                .collect(),
+            TypeKind::Date => self
+                .read_ints(column, StreamKind::Data, n, true)?
+                .into_iter()
+                .map(|days| {
+                    NaiveDate::from_ymd_opt(1970, 1, 1)
+                        .and_then(|epoch| epoch.checked_add_signed(chrono::Duration::days(days)))
+                        .map(|d| Value::String(d.to_string()))
+                        .unwrap_or(Value::from(days))
+                })
+                .collect(),
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = self.read_ints(column, StreamKind::Data, n, true)?;
+                let nanos = self.read_ints(column, StreamKind::Secondary, n, false)?;
+                seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(s, ns)| {
+                        let ns = decode_nanos(ns as u64)?;
+                        let value = s
+                            .checked_add(TIMESTAMP_BASE_SECONDS)
+                            .and_then(|s| DateTime::from_timestamp(s, ns))
+                            .map(|dt| Value::String(dt.naive_utc().to_string()))
+                            .unwrap_or(Value::Null);
+                        Ok(value)
+                    })
+                    .collect::<Result<_>>()?
+            }
+            TypeKind::Decimal => {
+                let unscaled = decode_varint128_values(self.stream(column, StreamKind::Data), n)?;
+                let scales = self.read_ints(column, StreamKind::Secondary, n, true)?;
+                unscaled
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(v, scale)| Value::String(format_decimal(v, scale as u32)))
+                    .collect()
+            }
+            TypeKind::Struct => {
+                let mut children = Vec::new();
+                for (name, id) in t.field_names.iter().zip(&t.subtypes) {
+                    children.push((name, self.read_column(*id, n)?));
+                }
+                (0..n)
+                    .map(|i| {
+                        let map: Map<String, Value> = children
+                            .iter()
+                            .map(|(name, values)| (name.to_string(), values[i].clone()))
+                            .collect();
+                        Value::Object(map)
+                    })
+                    .collect()
+            }
+            TypeKind::List => {
+                let lengths = self.read_ints(column, StreamKind::Length, n, false)?;
+                let total = lengths.iter().sum::<i64>() as usize;
+                let child = t.subtypes.first().copied().unwrap_or_default();
+                let mut items = self.read_column(child, total)?.into_iter();
+                lengths
+                    .into_iter()
+                    .map(|len| Value::Array(items.by_ref().take(len as usize).collect()))
+                    .collect()
+            }
+            TypeKind::Map => {
+                let lengths = self.read_ints(column, StreamKind::Length, n, false)?;
+                let total = lengths.iter().sum::<i64>() as usize;
+                let key_column = t.subtypes.first().copied().unwrap_or_default();
+                let value_column = t.subtypes.get(1).copied().unwrap_or_default();
+                let keys = self.read_column(key_column, total)?;
+                let values = self.read_column(value_column, total)?;
+                let mut entries = keys.into_iter().zip(values);
+                lengths
+                    .into_iter()
+                    .map(|len| {
+                        let map: Map<String, Value> = entries
+                            .by_ref()
+                            .take(len as usize)
+                            .map(|(k, v)| match k {
+                                Value::String(s) => (s, v),
+                                k => (k.to_string(), v),
+                            })
+                            .collect();
+                        Value::Object(map)
+                    })
+                    .collect()
+            }
+            TypeKind::Union | TypeKind::Unknown => {
+                vec![Value::String("<unsupported>".into()); n]
+            }
+        };
+        Ok(values)
+    }
+
+    fn read_strings(&self, column: usize, n: usize) -> Result<Vec<String>> {
+        let encoding = self.encoding(column);
+        if encoding.is_dictionary() {
+            let dictionary_size = encoding.dictionary_size;
+            let lengths = self.read_ints(column, StreamKind::Length, dictionary_size, false)?;
+            let dictionary =
+                split_by_lengths(self.stream(column, StreamKind::DictionaryData), &lengths)?;
+            let indices = self.read_ints(column, StreamKind::Data, n, false)?;
+            indices
+                .into_iter()
+                .map(|i| {
+                    dictionary
+                        .get(i as usize)
+                        .cloned()
+                        .ok_or_else(|| AppError::msg("Invalid ORC dictionary index"))
+                })
+                .collect()
+        } else {
+            let lengths = self.read_ints(column, StreamKind::Length, n, false)?;
+            split_by_lengths(self.stream(column, StreamKind::Data), &lengths)
+        }
+    }
+}
+
+fn split_by_lengths(data: &[u8], lengths: &[i64]) -> Result<Vec<String>> {
+    let mut pos: usize = 0;
+    lengths
+        .iter()
+        .map(|len| {
+            let len =
+                usize::try_from(*len).map_err(|_| AppError::msg("Invalid ORC string length"))?;
+            let s = pos
+                .checked_add(len)
+                .and_then(|end| data.get(pos..end))
+                .ok_or_else(|| AppError::msg("Unexpected end of ORC stream"))?;
+            pos += len;
+            Ok(String::from_utf8_lossy(s).into())
+        })
+        .collect()
+}
+
+// the trailing zeros are encoded in the lowest 3 bits
+fn decode_nanos(n: u64) -> Result<u32> {
+    let zeros = n & 7;
+    let nanos = n >> 3;
+    let nanos = if zeros == 0 {
+        Some(nanos)
+    } else {
+        nanos.checked_mul(10u64.pow(zeros as u32 + 1))
+    };
+    nanos
+        .and_then(|nanos| u32::try_from(nanos).ok())
+        .ok_or_else(|| AppError::msg("Invalid ORC timestamp nanoseconds"))
+}
+
+fn float_value(f: f64) -> Value {
+    Number::from_f64(f)
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(f.to_string()))
+}
+
+fn decode_varint128_values(data: &[u8], n: usize) -> Result<Vec<i128>> {
+    let mut reader = ByteReader::new(data);
+    (0..n)
+        .map(|_| {
+            let mut v: u128 = 0;
+            let mut shift = 0;
+            loop {
+                let b = reader.read_byte()?;
+                if shift < 128 {
+                    v |= ((b & 0x7f) as u128) << shift;
+                }
+                if b & 0x80 == 0 {
+                    break;
+                }
+                shift += 7;
+            }
+            Ok(((v >> 1) as i128) ^ -((v & 1) as i128))
+        })
+        .collect()
+}
+
+fn decode_byte_rle(data: &[u8], n: usize) -> Result<Vec<u8>> {
+    let mut reader = ByteReader::new(data);
+    let mut values = Vec::with_capacity(n);
+    while values.len() < n {
+        let control = reader.read_byte()?;
+        if control < 0x80 {
+            let count = control as usize + 3;
+            let value = reader.read_byte()?;
+            values.extend(std::iter::repeat_n(value, count));
+        } else {
+            let count = 0x100 - control as usize;
+            for _ in 0..count {
+                values.push(reader.read_byte()?);
+            }
+        }
+    }
+    values.truncate(n);
+    Ok(values)
+}
+
+fn decode_boolean_rle(data: &[u8], n: usize) -> Result<Vec<bool>> {
+    let bytes = decode_byte_rle(data, n.div_ceil(8))?;
+    let values = bytes
+        .iter()
+        .flat_map(|b| (0..8).rev().map(move |i| (b >> i) & 1 == 1))
+        .take(n)
+        .collect();
+    Ok(values)
+}
+
+fn decode_int_rle_v1(data: &[u8], n: usize, signed: bool) -> Result<Vec<i64>> {
+    let mut reader = ByteReader::new(data);
+    let mut values = Vec::with_capacity(n);
+    while values.len() < n {
+        let control = reader.read_byte()?;
+        if control < 0x80 {
+            let count = control as usize + 3;
+            let delta = reader.read_byte()? as i8 as i64;
+            let base = reader.read_int(signed)?;
+            for i in 0..count as i64 {
+                values.push(base.wrapping_add(i * delta));
+            }
+        } else {
+            let count = 0x100 - control as usize;
+            for _ in 0..count {
+                values.push(reader.read_int(signed)?);
+            }
+        }
+    }
+    values.truncate(n);
+    Ok(values)
+}
+
+fn decode_int_rle_v2(data: &[u8], n: usize, signed: bool) -> Result<Vec<i64>> {
+    let mut reader = ByteReader::new(data);
+    let mut values = Vec::with_capacity(n);
+    while values.len() < n {
+        let first = reader.read_byte()?;
+        match first >> 6 {
+            0 => read_short_repeat(&mut reader, first, signed, &mut values)?,
+            1 => read_direct(&mut reader, first, signed, &mut values)?,
+            2 => read_patched_base(&mut reader, first, &mut values)?,
+            _ => read_delta(&mut reader, first, signed, &mut values)?,
+        }
+    }
+    values.truncate(n);
+    Ok(values)
+}
+
+fn read_short_repeat(
+    reader: &mut ByteReader,
+    first: u8,
+    signed: bool,
+    values: &mut Vec<i64>,
+) -> Result<()> {
+    let width = ((first >> 3) & 0x07) as usize + 1;
+    let count = (first & 0x07) as usize + 3;
+    let mut value = reader.read_be(width)? as i64;
+    if signed {
+        value = zigzag_decode(value as u64);
+    }
+    values.extend(std::iter::repeat_n(value, count));
+    Ok(())
+}
+
+fn read_direct(
+    reader: &mut ByteReader,
+    first: u8,
+    signed: bool,
+    values: &mut Vec<i64>,
+) -> Result<()> {
+    let width = decode_bit_width((first >> 1) & 0x1f);
+    let len = read_run_length(reader, first)?;
+    for v in reader.read_bit_packed(len, width)? {
+        values.push(if signed { zigzag_decode(v) } else { v as i64 });
+    }
+    Ok(())
+}
+
+fn read_patched_base(reader: &mut ByteReader, first: u8, values: &mut Vec<i64>) -> Result<()> {
+    let width = decode_bit_width((first >> 1) & 0x1f);
+    let len = read_run_length(reader, first)?;
+
+    let third = reader.read_byte()?;
+    let base_width = ((third >> 5) & 0x07) as usize + 1;
+    let patch_width = decode_bit_width(third & 0x1f);
+
+    let fourth = reader.read_byte()?;
+    let patch_gap_width = ((fourth >> 5) & 0x07) as usize + 1;
+    let patch_list_len = (fourth & 0x1f) as usize;
+
+    let mut base = reader.read_be(base_width)? as i64;
+    let sign_mask = 1i64 << (base_width * 8 - 1);
+    if base & sign_mask != 0 {
+        base &= !sign_mask;
+        base = -base;
+    }
+
+    let mut unpacked = reader.read_bit_packed(len, width)?;
+
+    if patch_width + patch_gap_width > 64 {
+        return Err(AppError::msg("Invalid ORC patched base run"));
+    }
+    let patch_entry_width = closest_fixed_bits(patch_width + patch_gap_width);
+    let patches = reader.read_bit_packed(patch_list_len, patch_entry_width)?;
+    let patch_mask = if patch_width >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << patch_width) - 1
+    };
+
+    let mut index = 0;
+    for patch in patches {
+        let gap = (patch >> patch_width) as usize;
+        let value = patch & patch_mask;
+        index += gap;
+        if value != 0 || gap != 255 {
+            let target = unpacked
+                .get_mut(index)
+                .ok_or_else(|| AppError::msg("Invalid ORC patch index"))?;
+            *target |= value.checked_shl(width as u32).unwrap_or_default();
+        }
+    }
+
+    values.extend(unpacked.into_iter().map(|v| base.wrapping_add(v as i64)));
+    Ok(())
+}
+
+fn read_delta(
+    reader: &mut ByteReader,
+    first: u8,
+    signed: bool,
+    values: &mut Vec<i64>,
+) -> Result<()> {
+    let width_code = (first >> 1) & 0x1f;
+    let width = if width_code == 0 {
+        0
+    } else {
+        decode_bit_width(width_code)
+    };
+    let len = read_run_length(reader, first)?;
+
+    let base = reader.read_int(signed)?;
+    values.push(base);
+
+    let delta_base = reader.read_int(true)?;
+    if width == 0 {
+        // fixed delta
+        let mut prev = base;
+        for _ in 1..len {
+            prev = prev.wrapping_add(delta_base);
+            values.push(prev);
+        }
+    } else {
+        let mut prev = base.wrapping_add(delta_base);
+        values.push(prev);
+        for delta in reader.read_bit_packed(len.saturating_sub(2), width)? {
+            if delta_base < 0 {
+                prev = prev.wrapping_sub(delta as i64);
+            } else {
+                prev = prev.wrapping_add(delta as i64);
+            }
+            values.push(prev);
+        }
+    }
+    Ok(())
+}
+
+fn read_run_length(reader: &mut ByteReader, first: u8) -> Result<usize> {
+    let second = reader.read_byte()?;
+    Ok((((first as usize) & 0x01) << 8 | second as usize) + 1)
+}
+
+fn decode_bit_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(n: usize) -> usize {
+    match n {
+        0 => 1,
+        1..=24 => n,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn zigzag_decode(n: u64) -> i64 {
+    ((n >> 1) as i64) ^ -((n & 1) as i64)
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn read_byte(&mut self) -> Result<u8> {
+        let b = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| AppError::msg("Unexpected end of ORC stream"))?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn read_be(&mut self, width: usize) -> Result<u64> {
+        let mut v = 0;
+        for _ in 0..width {
+            v = (v << 8) | self.read_byte()? as u64;
+        }
+        Ok(v)
+    }
+
+    fn read_varint(&mut self) -> Result<u64> {
+        let mut v: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let b = self.read_byte()?;
+            if shift < 64 {
+                v |= ((b & 0x7f) as u64) << shift;
+            }
+            if b & 0x80 == 0 {
+                return Ok(v);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_int(&mut self, signed: bool) -> Result<i64> {
+        let v = self.read_varint()?;
+        Ok(if signed { zigzag_decode(v) } else { v as i64 })
+    }
+
+    fn read_bit_packed(&mut self, n: usize, width: usize) -> Result<Vec<u64>> {
+        let mut values = Vec::with_capacity(n);
+        let mut current: u64 = 0;
+        let mut bits_left = 0;
+        for _ in 0..n {
+            let mut v: u64 = 0;
+            let mut needed = width;
+            while needed > 0 {
+                if bits_left == 0 {
+                    current = self.read_byte()? as u64;
+                    bits_left = 8;
+                }
+                let take = needed.min(bits_left);
+                let shift = bits_left - take;
+                let bits = (current >> shift) & ((1u64 << take) - 1);
+                v = (v << take) | bits;
+                bits_left -= take;
+                needed -= take;
+            }
+            values.push(v);
+        }
+        Ok(values)
+    }
+}
+
+enum Wire<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+struct ProtoReader<'a> {
+    reader: ByteReader<'a>,
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            reader: ByteReader::new(bytes),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.reader.pos >= self.reader.bytes.len()
+    }
+
+    fn read_varint(&mut self) -> Result<u64> {
+        self.reader.read_varint()
+    }
+
+    fn next_field(&mut self) -> Result<Option<(u64, Wire<'a>)>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let key = self.reader.read_varint()?;
+        let field = key >> 3;
+        let value = match key & 0x07 {
+            0 => Wire::Varint(self.reader.read_varint()?),
+            1 => {
+                self.skip(8)?;
+                Wire::Fixed
+            }
+            2 => {
+                let len = self.reader.read_varint()? as usize;
+                let start = self.reader.pos;
+                self.skip(len)?;
+                Wire::Bytes(&self.reader.bytes[start..start + len])
+            }
+            5 => {
+                self.skip(4)?;
+                Wire::Fixed
+            }
+            _ => return Err(AppError::msg("Invalid ORC metadata")),
+        };
+        Ok(Some((field, value)))
+    }
+
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if self.reader.pos + n > self.reader.bytes.len() {
+            return Err(AppError::msg("Invalid ORC metadata"));
+        }
+        self.reader.pos += n;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::DeflateEncoder, Compression as FlateCompression};
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_split_by_lengths() {
+        assert_eq!(
+            split_by_lengths(b"abcde", &[2, 0, 3]).unwrap(),
+            vec!["ab", "", "cde"]
+        );
+        let err = split_by_lengths(b"abcde", &[2, 4]).unwrap_err();
+        assert_eq!(err.msg, "Unexpected end of ORC stream");
+        let err = split_by_lengths(b"abcde", &[2, -1]).unwrap_err();
+        assert_eq!(err.msg, "Invalid ORC string length");
+        assert!(split_by_lengths(b"abcde", &[2, i64::MAX]).is_err());
+    }
+
+    #[test]
+    fn test_decode_nanos() {
+        assert_eq!(decode_nanos(123 << 3).unwrap(), 123);
+        // 2 trailing zeros are encoded as 1, and 3 as 2
+        assert_eq!(decode_nanos((5 << 3) | 1).unwrap(), 500);
+        assert_eq!(decode_nanos((5 << 3) | 2).unwrap(), 5000);
+        let err = decode_nanos(u64::MAX).unwrap_err();
+        assert_eq!(err.msg, "Invalid ORC timestamp nanoseconds");
+    }
+
+    #[test]
+    fn test_decode_byte_rle() {
+        assert_eq!(decode_byte_rle(&[0x61, 0x00], 100).unwrap(), vec![0; 100]);
+        assert_eq!(
+            decode_byte_rle(&[0xfe, 0x44, 0x45], 2).unwrap(),
+            vec![0x44, 0x45]
+        );
+    }
+
+    #[test]
+    fn test_decode_boolean_rle() {
+        assert_eq!(
+            decode_boolean_rle(&[0xff, 0x80], 3).unwrap(),
+            vec![true, false, false]
+        );
+    }
+
+    #[test]
+    fn test_decode_int_rle_v1() {
+        assert_eq!(
+            decode_int_rle_v1(&[0x61, 0x00, 0x07], 100, false).unwrap(),
+            vec![7; 100]
+        );
+        assert_eq!(
+            decode_int_rle_v1(&[0x61, 0xff, 0x64], 100, false).unwrap(),
+            (1..=100).rev().collect::<Vec<i64>>()
+        );
+        assert_eq!(
+            decode_int_rle_v1(&[0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b], 5, false).unwrap(),
+            vec![2, 3, 6, 7, 11]
+        );
+    }
+
+    #[test]
+    fn test_decode_int_rle_v2() {
+        // examples from the ORC specification
+        assert_eq!(
+            decode_int_rle_v2(&[0x0a, 0x27, 0x10], 5, false).unwrap(),
+            vec![10000; 5]
+        );
+        assert_eq!(
+            decode_int_rle_v2(
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                4,
+                false
+            )
+            .unwrap(),
+            vec![23713, 43806, 57005, 48879]
+        );
+        assert_eq!(
+            decode_int_rle_v2(
+                &[
+                    0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c,
+                    0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe,
+                    0xfc, 0xe8
+                ],
+                20,
+                true
+            )
+            .unwrap(),
+            vec![
+                2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120,
+                2130, 2140, 2150, 2160, 2170, 2180, 2190
+            ]
+        );
+        assert_eq!(
+            decode_int_rle_v2(&[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46], 10, false)
+                .unwrap(),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+
+    fn varint(mut n: u64) -> Vec<u8> {
+        let mut buf = Vec::new();
+        loop {
+            if n < 0x80 {
+                buf.push(n as u8);
+                return buf;
+            }
+            buf.push((n as u8 & 0x7f) | 0x80);
+            n >>= 7;
+        }
+    }
+
+    fn field_varint(field: u64, n: u64) -> Vec<u8> {
+        let mut buf = varint(field << 3);
+        buf.extend(varint(n));
+        buf
+    }
+
+    fn field_bytes(field: u64, bytes: &[u8]) -> Vec<u8> {
+        let mut buf = varint((field << 3) | 2);
+        buf.extend(varint(bytes.len() as u64));
+        buf.extend(bytes);
+        buf
+    }
+
+    fn compress(bytes: &[u8], kind: u64) -> Vec<u8> {
+        let compressed = match kind {
+            0 => return bytes.to_vec(),
+            1 => {
+                let mut e = DeflateEncoder::new(Vec::new(), FlateCompression::default());
+                e.write_all(bytes).unwrap();
+                e.finish().unwrap()
+            }
+            2 => snap::raw::Encoder::new().compress_vec(bytes).unwrap(),
+            4 => lz4_flex::block::compress(bytes),
+            5 => zstd::stream::encode_all(bytes, 0).unwrap(),
+            _ => unreachable!(),
+        };
+        let header = compressed.len() << 1;
+        let mut buf = vec![header as u8, (header >> 8) as u8, (header >> 16) as u8];
+        buf.extend(compressed);
+        buf
+    }
+
+    // struct<id:int,name:string> with rows (1, "a"), (2, null), (3, "ccc")
+    fn orc_file(compression: u64) -> Vec<u8> {
+        let streams: Vec<(u64, u64, Vec<u8>)> = vec![
+            // id: DATA, delta run 1, 2, 3 (signed)
+            (1, 1, vec![0xc0, 0x02, 0x02, 0x02]),
+            // name: PRESENT 1, 0, 1
+            (0, 2, vec![0xff, 0xa0]),
+            // name: LENGTH direct 1, 3 (unsigned, width 2)
+            (2, 2, vec![0x42, 0x01, 0x70]),
+            // name: DATA
+            (1, 2, b"accc".to_vec()),
+        ];
+
+        let mut buf = MAGIC.to_vec();
+        let stripe_offset = buf.len();
+        let mut stripe_footer = Vec::new();
+        let mut data_length = 0;
+        for (kind, column, data) in &streams {
+            let data = compress(data, compression);
+            let mut stream = field_varint(1, *kind);
+            stream.extend(field_varint(2, *column));
+            stream.extend(field_varint(3, data.len() as u64));
+            stripe_footer.extend(field_bytes(1, &stream));
+            data_length += data.len();
+            buf.extend(data);
+        }
+        for _ in 0..3 {
+            stripe_footer.extend(field_bytes(2, &field_varint(1, 2)));
+        }
+        let stripe_footer = compress(&stripe_footer, compression);
+        let stripe_footer_length = stripe_footer.len();
+        buf.extend(stripe_footer);
+
+        let mut stripe = field_varint(1, stripe_offset as u64);
+        stripe.extend(field_varint(2, 0));
+        stripe.extend(field_varint(3, data_length as u64));
+        stripe.extend(field_varint(4, stripe_footer_length as u64));
+        stripe.extend(field_varint(5, 3));
+
+        let mut root = field_varint(1, 12);
+        root.extend(field_bytes(2, &[1, 2]));
+        root.extend(field_bytes(3, b"id"));
+        root.extend(field_bytes(3, b"name"));
+
+        let mut footer = field_bytes(3, &stripe);
+        footer.extend(field_bytes(4, &root));
+        footer.extend(field_bytes(4, &field_varint(1, 3)));
+        footer.extend(field_bytes(4, &field_varint(1, 7)));
+        footer.extend(field_varint(6, 3));
+        let footer = compress(&footer, compression);
+
+        let mut ps = field_varint(1, footer.len() as u64);
+        ps.extend(field_varint(2, compression));
+        ps.extend(field_bytes(8000, MAGIC));
+
+        buf.extend(footer);
+        buf.extend(&ps);
+        buf.push(ps.len() as u8);
+        buf
+    }
+
+    #[test]
+    fn test_read_orc() {
+        for compression in [0, 1, 2, 4, 5] {
+            let bytes = orc_file(compression);
+            let content = read(&bytes, 10).unwrap();
+
+            assert_eq!(content.format, ContainerFormat::Orc);
+            assert_eq!(content.total_records, 3);
+            assert_eq!(content.schema, vec!["id: int", "name: string"]);
+            assert_eq!(
+                content.records,
+                vec![
+                    json!({"id": 1, "name": "a"}),
+                    json!({"id": 2, "name": null}),
+                    json!({"id": 3, "name": "ccc"}),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_orc_max_records() {
+        let bytes = orc_file(0);
+        let content = read(&bytes, 2).unwrap();
+
+        assert_eq!(content.total_records, 3);
+        assert_eq!(content.records.len(), 2);
+    }
+}
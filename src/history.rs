@@ -0,0 +1,226 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{AppError, Result},
+    file::save_binary,
+    object::{ObjectKey, ObjectLocation},
+};
+
+const MAX_ENTRIES: usize = 100;
+const MAX_RECENT: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VisitedLocation {
+    pub key: ObjectKey,
+    #[serde(default)]
+    pub is_object: bool,
+}
+
+impl VisitedLocation {
+    pub fn new(key: ObjectKey, is_object: bool) -> VisitedLocation {
+        VisitedLocation { key, is_object }
+    }
+
+    pub fn uri(&self) -> String {
+        self.key.uri(self.is_object)
+    }
+
+    pub fn location(&self) -> ObjectLocation {
+        ObjectLocation::new(self.key.clone(), self.is_object)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    // back/forward entries of the current session, oldest first
+    entries: Vec<VisitedLocation>,
+    position: usize,
+    // the position of the entry moved to by back/forward, committed when it is visited
+    moving: Option<usize>,
+    // recently visited locations across sessions, most recent first
+    recent: Vec<VisitedLocation>,
+    // None if the file could not be loaded, to avoid overwriting it
+    path: Option<String>,
+}
+
+impl History {
+    pub fn load(path: &str) -> Result<History> {
+        let recent = if Path::new(path).exists() {
+            let bytes =
+                std::fs::read(path).map_err(|e| AppError::new("Failed to read history", e))?;
+            serde_json::from_slice(&bytes)
+                .map_err(|e| AppError::new("Failed to parse history", e))?
+        } else {
+            Vec::new()
+        };
+        Ok(History {
+            recent,
+            path: Some(path.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn recent(&self) -> &[VisitedLocation] {
+        &self.recent
+    }
+
+    pub fn current(&self) -> Option<&VisitedLocation> {
+        self.entries.get(self.position)
+    }
+
+    pub fn visit(&mut self, location: VisitedLocation) -> Result<()> {
+        // if the move has failed, the current location is visited again and nothing changes
+        if let Some(position) = self.moving.take() {
+            if self.entries[position] == location {
+                self.position = position;
+                return Ok(());
+            }
+        }
+        if self.current() == Some(&location) {
+            return Ok(());
+        }
+
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.position + 1);
+        }
+        self.entries.push(location.clone());
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.position = self.entries.len() - 1;
+
+        self.recent.retain(|l| l != &location);
+        self.recent.insert(0, location);
+        self.recent.truncate(MAX_RECENT);
+        self.save()
+    }
+
+    // the position does not change until the returned location is visited
+    pub fn back(&mut self) -> Option<&VisitedLocation> {
+        if self.position == 0 {
+            return None;
+        }
+        self.move_to(self.position - 1)
+    }
+
+    pub fn forward(&mut self) -> Option<&VisitedLocation> {
+        if self.position + 1 >= self.entries.len() {
+            return None;
+        }
+        self.move_to(self.position + 1)
+    }
+
+    fn move_to(&mut self, position: usize) -> Option<&VisitedLocation> {
+        self.moving = Some(position);
+        self.entries.get(position)
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let bytes = serde_json::to_vec_pretty(&self.recent)
+            .map_err(|e| AppError::new("Failed to serialize history", e))?;
+        save_binary(path, &bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loc(bucket: &str, path: &[&str]) -> VisitedLocation {
+        let key = ObjectKey {
+            bucket_name: bucket.to_string(),
+            object_path: path.iter().map(|s| s.to_string()).collect(),
+        };
+        VisitedLocation::new(key, false)
+    }
+
+    // move and complete it by visiting the location, like the app does after the move
+    fn back(history: &mut History) -> Option<VisitedLocation> {
+        let location = history.back().cloned()?;
+        history.visit(location.clone()).unwrap();
+        Some(location)
+    }
+
+    fn forward(history: &mut History) -> Option<VisitedLocation> {
+        let location = history.forward().cloned()?;
+        history.visit(location.clone()).unwrap();
+        Some(location)
+    }
+
+    #[test]
+    fn test_back_and_forward() {
+        let mut history = History::default();
+        assert_eq!(history.back(), None);
+
+        history.visit(loc("bucket-1", &[])).unwrap();
+        history.visit(loc("bucket-1", &["a"])).unwrap();
+        history.visit(loc("bucket-1", &["a"])).unwrap();
+        history.visit(loc("bucket-2", &[])).unwrap();
+
+        assert_eq!(back(&mut history), Some(loc("bucket-1", &["a"])));
+        assert_eq!(back(&mut history), Some(loc("bucket-1", &[])));
+        assert_eq!(back(&mut history), None);
+        assert_eq!(forward(&mut history), Some(loc("bucket-1", &["a"])));
+
+        // visiting a new location drops the forward entries
+        history.visit(loc("bucket-3", &[])).unwrap();
+        assert_eq!(forward(&mut history), None);
+        assert_eq!(back(&mut history), Some(loc("bucket-1", &["a"])));
+
+        // moving back and forth does not change the recent list order
+        assert_eq!(
+            history.recent(),
+            [
+                loc("bucket-3", &[]),
+                loc("bucket-2", &[]),
+                loc("bucket-1", &["a"]),
+                loc("bucket-1", &[]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failed_move() {
+        let mut history = History::default();
+        history.visit(loc("bucket-1", &[])).unwrap();
+        history.visit(loc("bucket-1", &["a"])).unwrap();
+        history.visit(loc("bucket-1", &["b"])).unwrap();
+        assert_eq!(back(&mut history), Some(loc("bucket-1", &["a"])));
+
+        // the move to bucket-1 has failed, so the current location is visited again
+        assert_eq!(history.back(), Some(&loc("bucket-1", &[])));
+        history.visit(loc("bucket-1", &["a"])).unwrap();
+        assert_eq!(history.current(), Some(&loc("bucket-1", &["a"])));
+
+        // the forward entry is kept
+        assert_eq!(forward(&mut history), Some(loc("bucket-1", &["b"])));
+        assert_eq!(back(&mut history), Some(loc("bucket-1", &["a"])));
+        assert_eq!(back(&mut history), Some(loc("bucket-1", &[])));
+    }
+
+    #[test]
+    fn test_save_and_load_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.json");
+        let path = path.to_string_lossy();
+
+        let mut history = History::load(&path).unwrap();
+        assert!(history.recent().is_empty());
+
+        history.visit(loc("bucket-1", &["a"])).unwrap();
+        history.visit(loc("bucket-2", &[])).unwrap();
+        history.visit(loc("bucket-1", &["a"])).unwrap();
+
+        let mut history = History::load(&path).unwrap();
+        assert_eq!(
+            history.recent(),
+            [loc("bucket-1", &["a"]), loc("bucket-2", &[])]
+        );
+        assert_eq!(history.back(), None);
+    }
+}
@@ -1,19 +1,29 @@
 mod app;
+mod bookmark;
 mod client;
 mod config;
 mod constant;
+mod disk_usage;
+mod duplicate;
 mod error;
 mod event;
+mod external;
 mod file;
+mod filter;
+mod format;
+mod history;
 mod macros;
 mod object;
 mod pages;
 mod run;
+mod sort;
+mod stats;
+mod transfer;
 mod ui;
 mod util;
 mod widget;
 
-use clap::{arg, Parser};
+use clap::Parser;
 use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
@@ -35,6 +45,7 @@ use tracing_subscriber::fmt::time::ChronoLocal;
 use crate::app::App;
 use crate::client::Client;
 use crate::config::Config;
+use crate::object::ObjectLocation;
 
 /// STU - S3 Terminal UI
 #[derive(Parser)]
@@ -56,6 +67,10 @@ struct Args {
     #[arg(short, long, value_name = "NAME")]
     bucket: Option<String>,
 
+    /// Open the prefix or the object directly (e.g. s3://bucket/prefix/, s3://bucket/key)
+    #[arg(value_name = "S3_URI", value_parser = parse_location, conflicts_with = "bucket")]
+    location: Option<ObjectLocation>,
+
     /// Output debug logs
     #[arg(long)]
     debug: bool,
@@ -98,7 +113,12 @@ async fn run<B: Backend>(
 
     spawn(async move {
         let client = Client::new(args.region, args.endpoint_url, args.profile).await;
-        tx.send(AppEventType::Initialize(client, args.bucket));
+        let bucket = args
+            .location
+            .as_ref()
+            .map(|location| location.key.bucket_name.clone())
+            .or(args.bucket);
+        tx.send(AppEventType::Initialize(client, bucket, args.location));
     });
 
     run::run(&mut app, terminal, rx).await?;
@@ -106,6 +126,10 @@ async fn run<B: Backend>(
     Ok(())
 }
 
+fn parse_location(s: &str) -> Result<ObjectLocation, String> {
+    ObjectLocation::parse(s).ok_or_else(|| format!("invalid S3 URI: {}", s))
+}
+
 fn get_frame_size<B: Backend>(terminal: &mut Terminal<B>) -> (usize, usize) {
     let size = terminal.get_frame().size();
     (size.width as usize, size.height as usize)
@@ -1,13 +1,18 @@
 use std::{
     collections::HashMap,
     fmt::{self, Debug, Formatter},
+    ops::AddAssign,
 };
 
 use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug)]
 pub struct BucketItem {
     pub name: String,
+    pub creation_date: Option<DateTime<Local>>,
+    // loaded in the background after the buckets are listed
+    pub region: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -19,6 +24,9 @@ pub enum ObjectItem {
         name: String,
         size_byte: usize,
         last_modified: DateTime<Local>,
+        // None for the local files
+        e_tag: Option<String>,
+        storage_class: Option<String>,
     },
 }
 
@@ -45,6 +53,34 @@ impl ObjectItem {
     }
 }
 
+// the total size and the number of the objects under a prefix
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrefixSize {
+    pub size_byte: usize,
+    pub count: usize,
+}
+
+impl PrefixSize {
+    pub fn of_items(items: &[ObjectItem]) -> PrefixSize {
+        items
+            .iter()
+            .fold(PrefixSize::default(), |acc, item| match item {
+                ObjectItem::File { size_byte, .. } => PrefixSize {
+                    size_byte: acc.size_byte + size_byte,
+                    count: acc.count + 1,
+                },
+                ObjectItem::Dir { .. } => acc,
+            })
+    }
+}
+
+impl AddAssign for PrefixSize {
+    fn add_assign(&mut self, other: PrefixSize) {
+        self.size_byte += other.size_byte;
+        self.count += other.count;
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FileDetail {
     pub name: String,
@@ -68,12 +104,25 @@ pub struct FileVersion {
     pub is_latest: bool,
 }
 
+// an object in a recursive listing, `key` is the full key in the bucket
+#[derive(Debug, Clone)]
+pub struct ObjectSummary {
+    pub key: String,
+    pub size_byte: usize,
+    pub last_modified: DateTime<Local>,
+    pub e_tag: String,
+    pub storage_class: String,
+}
+
 #[derive(Debug, Default)]
 pub struct AppObjects {
     bucket_items: Vec<BucketItem>,
     object_items_map: HashMap<ObjectKey, Vec<ObjectItem>>,
+    recursive_object_items_map: HashMap<ObjectKey, Vec<ObjectItem>>,
     detail_map: HashMap<ObjectKey, FileDetail>,
     versions_map: HashMap<ObjectKey, Vec<FileVersion>>,
+    prefix_size_map: HashMap<ObjectKey, PrefixSize>,
+    content_type_map: HashMap<ObjectKey, String>,
 }
 
 impl AppObjects {
@@ -89,10 +138,80 @@ impl AppObjects {
         self.bucket_items = items;
     }
 
+    pub fn set_bucket_regions(&mut self, regions: &HashMap<String, String>) {
+        for item in self.bucket_items.iter_mut() {
+            if let Some(region) = regions.get(&item.name) {
+                item.region = Some(region.clone());
+            }
+        }
+    }
+
     pub fn set_object_items(&mut self, key: ObjectKey, items: Vec<ObjectItem>) {
         self.object_items_map.insert(key, items);
     }
 
+    pub fn remove_object_items(&mut self, key: &ObjectKey) {
+        self.object_items_map.remove(key);
+    }
+
+    // drops every cached list and detail at or below `key`
+    pub fn remove_objects_under(&mut self, key: &ObjectKey) {
+        self.object_items_map.retain(|k, _| !k.starts_with(key));
+        // the recursive lists of the ancestors include the changed objects
+        self.recursive_object_items_map
+            .retain(|k, _| !k.starts_with(key) && !key.starts_with(k));
+        self.detail_map.retain(|k, _| !k.starts_with(key));
+        self.versions_map.retain(|k, _| !k.starts_with(key));
+        self.content_type_map.retain(|k, _| !k.starts_with(key));
+        // the sizes of the ancestors also change
+        self.prefix_size_map
+            .retain(|k, _| !k.starts_with(key) && !key.starts_with(k));
+    }
+
+    // the sizes of the prefixes directly under `key`, by name
+    pub fn get_prefix_sizes(&self, key: &ObjectKey) -> HashMap<String, PrefixSize> {
+        self.prefix_size_map
+            .iter()
+            .filter(|(k, _)| k.object_path.len() == key.object_path.len() + 1 && k.starts_with(key))
+            .map(|(k, size)| (k.object_path.last().unwrap().clone(), *size))
+            .collect()
+    }
+
+    pub fn set_prefix_size(&mut self, key: ObjectKey, size: PrefixSize) {
+        self.prefix_size_map.insert(key, size);
+    }
+
+    // the content types of the objects directly under `key` which have been loaded, by name
+    pub fn get_content_types(&self, key: &ObjectKey) -> HashMap<String, String> {
+        let is_child =
+            |k: &ObjectKey| k.object_path.len() == key.object_path.len() + 1 && k.starts_with(key);
+        let details = self
+            .detail_map
+            .iter()
+            .filter(|(k, _)| is_child(k))
+            .map(|(_, detail)| (detail.name.clone(), detail.content_type.clone()));
+        let content_types = self
+            .content_type_map
+            .iter()
+            .filter(|(k, _)| is_child(k))
+            .map(|(k, content_type)| (k.object_path.last().unwrap().clone(), content_type.clone()));
+        content_types.chain(details).collect()
+    }
+
+    pub fn set_content_type(&mut self, key: ObjectKey, content_type: String) {
+        self.content_type_map.insert(key, content_type);
+    }
+
+    pub fn get_recursive_object_items(&self, key: &ObjectKey) -> Option<Vec<ObjectItem>> {
+        self.recursive_object_items_map
+            .get(key)
+            .map(|items| items.to_vec())
+    }
+
+    pub fn set_recursive_object_items(&mut self, key: ObjectKey, items: Vec<ObjectItem>) {
+        self.recursive_object_items_map.insert(key, items);
+    }
+
     pub fn get_object_detail(&self, key: &ObjectKey) -> Option<&FileDetail> {
         self.detail_map.get(key)
     }
@@ -112,12 +231,86 @@ impl AppObjects {
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ObjectKey {
     pub bucket_name: String,
     pub object_path: Vec<String>,
 }
 
+impl ObjectKey {
+    // prefixes end with `/`, objects do not
+    pub fn uri(&self, is_object: bool) -> String {
+        let mut uri = format!("s3://{}/", self.bucket_name);
+        for (i, name) in self.object_path.iter().enumerate() {
+            uri.push_str(name);
+            if !is_object || i + 1 < self.object_path.len() {
+                uri.push('/');
+            }
+        }
+        uri
+    }
+
+    // `a/b/` for the path [a, b]
+    pub fn prefix(&self) -> String {
+        self.object_path.iter().map(|p| format!("{}/", p)).collect()
+    }
+
+    pub fn starts_with(&self, other: &ObjectKey) -> bool {
+        self.bucket_name == other.bucket_name && self.object_path.starts_with(&other.object_path)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocationType {
+    Prefix,
+    Object,
+    // resolved after the parent list is loaded
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectLocation {
+    pub key: ObjectKey,
+    pub location_type: LocationType,
+}
+
+impl ObjectLocation {
+    pub fn new(key: ObjectKey, is_object: bool) -> ObjectLocation {
+        let location_type = if is_object {
+            LocationType::Object
+        } else {
+            LocationType::Prefix
+        };
+        ObjectLocation { key, location_type }
+    }
+
+    // accepts `s3://bucket/prefix/key` or `bucket/prefix/key`
+    pub fn parse(s: &str) -> Option<ObjectLocation> {
+        let s = s.trim();
+        let s = s.strip_prefix("s3://").unwrap_or(s);
+        let (bucket_name, path) = s.split_once('/').unwrap_or((s, ""));
+        if bucket_name.is_empty() {
+            return None;
+        }
+        let location_type = if path.is_empty() || path.ends_with('/') {
+            LocationType::Prefix
+        } else {
+            LocationType::Unknown
+        };
+        let path = path.strip_suffix('/').unwrap_or(path);
+        let object_path = if path.is_empty() {
+            Vec::new()
+        } else {
+            path.split('/').map(String::from).collect()
+        };
+        let key = ObjectKey {
+            bucket_name: bucket_name.to_string(),
+            object_path,
+        };
+        Some(ObjectLocation { key, location_type })
+    }
+}
+
 #[derive(Default, Clone)]
 pub struct RawObject {
     pub bytes: Vec<u8>,
@@ -128,3 +321,100 @@ impl Debug for RawObject {
         write!(f, "RawObject {{ bytes: [u8; {}] }}", self.bytes.len())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(bucket: &str, path: &[&str], location_type: LocationType) -> ObjectLocation {
+        ObjectLocation {
+            key: ObjectKey {
+                bucket_name: bucket.to_string(),
+                object_path: path.iter().map(|s| s.to_string()).collect(),
+            },
+            location_type,
+        }
+    }
+
+    #[test]
+    fn test_parse_object_location() {
+        assert_eq!(
+            ObjectLocation::parse("s3://bucket-1"),
+            Some(location("bucket-1", &[], LocationType::Prefix))
+        );
+        assert_eq!(
+            ObjectLocation::parse("s3://bucket-1/"),
+            Some(location("bucket-1", &[], LocationType::Prefix))
+        );
+        assert_eq!(
+            ObjectLocation::parse("s3://bucket-1/dir1/dir2/"),
+            Some(location(
+                "bucket-1",
+                &["dir1", "dir2"],
+                LocationType::Prefix
+            ))
+        );
+        assert_eq!(
+            ObjectLocation::parse(" bucket-1/dir1/file1.txt\n"),
+            Some(location(
+                "bucket-1",
+                &["dir1", "file1.txt"],
+                LocationType::Unknown
+            ))
+        );
+        assert_eq!(ObjectLocation::parse("s3://"), None);
+        assert_eq!(ObjectLocation::parse(""), None);
+    }
+
+    #[test]
+    fn test_prefix_sizes() {
+        let key = |path: &[&str]| location("bucket-1", path, LocationType::Prefix).key;
+        let size = |size_byte, count| PrefixSize { size_byte, count };
+
+        let mut app_objects = AppObjects::default();
+        app_objects.set_prefix_size(key(&["a"]), size(30, 3));
+        app_objects.set_prefix_size(key(&["a", "b"]), size(20, 2));
+        app_objects.set_prefix_size(key(&["a", "c"]), size(10, 1));
+        app_objects.set_prefix_size(key(&["d"]), size(5, 1));
+
+        let sizes = app_objects.get_prefix_sizes(&key(&["a"]));
+        assert_eq!(sizes.len(), 2);
+        assert_eq!(sizes["b"], size(20, 2));
+        assert_eq!(sizes["c"], size(10, 1));
+
+        // the changed prefix and its ancestors are dropped
+        app_objects.remove_objects_under(&key(&["a", "b"]));
+        let sizes = app_objects.get_prefix_sizes(&key(&["a"]));
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes["c"], size(10, 1));
+        let sizes = app_objects.get_prefix_sizes(&key(&[]));
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes["d"], size(5, 1));
+    }
+
+    #[test]
+    fn test_remove_recursive_object_items() {
+        let key = |path: &[&str]| location("bucket-1", path, LocationType::Prefix).key;
+
+        let mut app_objects = AppObjects::default();
+        for path in [&[][..], &["a"], &["a", "b"], &["a", "b", "c"], &["d"]] {
+            app_objects.set_recursive_object_items(key(path), Vec::new());
+        }
+
+        // the changed prefix, its descendants and its ancestors are dropped
+        app_objects.remove_objects_under(&key(&["a", "b"]));
+        assert!(app_objects.get_recursive_object_items(&key(&[])).is_none());
+        assert!(app_objects
+            .get_recursive_object_items(&key(&["a"]))
+            .is_none());
+        assert!(app_objects
+            .get_recursive_object_items(&key(&["a", "b"]))
+            .is_none());
+        assert!(app_objects
+            .get_recursive_object_items(&key(&["a", "b", "c"]))
+            .is_none());
+        assert!(app_objects
+            .get_recursive_object_items(&key(&["d"]))
+            .is_some());
+    }
+}
@@ -1,9 +1,16 @@
 pub mod page;
 
+pub mod bookmarks;
 pub mod bucket_list;
+pub mod bucket_stats;
+pub mod disk_usage;
+pub mod duplicates;
 pub mod help;
+pub mod history;
 pub mod initializing;
+pub mod local_list;
 pub mod object_detail;
+pub mod object_diff;
 pub mod object_list;
 pub mod object_preview;
 
@@ -0,0 +1,286 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::ListItem,
+    Frame,
+};
+
+use crate::{
+    bookmark::Bookmark,
+    event::{AppEventType, Sender},
+    key_code, key_code_char,
+    pages::util::{build_helps, build_short_helps},
+    widget::{InputDialog, InputDialogState, ScrollList, ScrollListState},
+};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
+const URI_TEXT_COLOR: Color = Color::DarkGray;
+
+#[derive(Debug)]
+pub struct BookmarksPage {
+    bookmarks: Vec<Bookmark>,
+
+    view_state: ViewState,
+
+    list_state: ScrollListState,
+    rename_input_state: InputDialogState,
+    tx: Sender,
+}
+
+#[derive(Debug)]
+enum ViewState {
+    Default,
+    RenameDialog,
+}
+
+impl BookmarksPage {
+    pub fn new(bookmarks: Vec<Bookmark>, tx: Sender) -> Self {
+        let items_len = bookmarks.len();
+        Self {
+            bookmarks,
+            view_state: ViewState::Default,
+            list_state: ScrollListState::new(items_len),
+            rename_input_state: InputDialogState::default(),
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match self.view_state {
+            ViewState::Default => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.tx.send(AppEventType::Quit);
+                }
+                key_code!(KeyCode::Backspace) | key_code_char!('\'') => {
+                    self.tx.send(AppEventType::CloseCurrentPage);
+                }
+                key_code!(KeyCode::Enter) if self.non_empty() => {
+                    self.move_to_selected();
+                }
+                key_code_char!('j') if self.non_empty() => {
+                    self.list_state.select_next();
+                }
+                key_code_char!('k') if self.non_empty() => {
+                    self.list_state.select_prev();
+                }
+                key_code_char!('g') if self.non_empty() => {
+                    self.list_state.select_first();
+                }
+                key_code_char!('G') if self.non_empty() => {
+                    self.list_state.select_last();
+                }
+                key_code_char!('r') if self.non_empty() => {
+                    self.open_rename_dialog();
+                }
+                key_code_char!('d') if self.non_empty() => {
+                    let index = self.list_state.selected;
+                    self.tx.send(AppEventType::DeleteBookmark(index));
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::RenameDialog => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_rename_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.apply_rename();
+                }
+                _ => {
+                    self.rename_input_state.handle_key_event(key);
+                }
+            },
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        self.list_state
+            .fit_height(area.height as usize - 2 /* border */);
+        let offset = self.list_state.offset;
+        let selected = self.list_state.selected;
+
+        let show_item_count = (area.height as usize) - 2 /* border */;
+        let list_items = self
+            .bookmarks
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(show_item_count)
+            .map(|(i, bookmark)| build_list_item(bookmark, i == selected))
+            .collect();
+
+        let list = ScrollList::new(list_items);
+        f.render_stateful_widget(list, area, &mut self.list_state);
+
+        if let ViewState::RenameDialog = self.view_state {
+            let rename_dialog = InputDialog::default().title("Label").max_width(40);
+            f.render_stateful_widget(rename_dialog, area, &mut self.rename_input_state);
+
+            let (cursor_x, cursor_y) = self.rename_input_state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = match self.view_state {
+            ViewState::Default => &[
+                (&["Esc", "Ctrl-c"], "Quit app"),
+                (&["j/k"], "Select item"),
+                (&["g/G"], "Go to top/bottom"),
+                (&["Enter"], "Go to bookmarked location"),
+                (&["r"], "Rename bookmark"),
+                (&["d"], "Delete bookmark"),
+                (&["Backspace", "'"], "Close bookmarks"),
+            ],
+            ViewState::RenameDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close rename dialog"),
+                (&["Enter"], "Rename bookmark"),
+            ],
+        };
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = match self.view_state {
+            ViewState::Default => &[
+                (&["Esc"], "Quit", 0),
+                (&["j/k"], "Select", 3),
+                (&["Enter"], "Go", 1),
+                (&["r"], "Rename", 4),
+                (&["d"], "Delete", 5),
+                (&["Backspace"], "Close", 2),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::RenameDialog => &[(&["Esc"], "Close", 2), (&["Enter"], "Rename", 1)],
+        };
+        build_short_helps(helps)
+    }
+
+    pub fn set_bookmarks(&mut self, bookmarks: Vec<Bookmark>) {
+        let selected = self.list_state.selected;
+        self.list_state = ScrollListState::new(bookmarks.len());
+        self.list_state
+            .select_index(selected.min(bookmarks.len().saturating_sub(1)));
+        self.bookmarks = bookmarks;
+    }
+}
+
+impl BookmarksPage {
+    fn move_to_selected(&self) {
+        let bookmark = &self.bookmarks[self.list_state.selected];
+        self.tx
+            .send(AppEventType::BookmarksMoveTo(bookmark.location()));
+    }
+
+    fn open_rename_dialog(&mut self) {
+        self.view_state = ViewState::RenameDialog;
+        let bookmark = &self.bookmarks[self.list_state.selected];
+        if let Some(label) = &bookmark.label {
+            self.rename_input_state.insert_str(label);
+        }
+    }
+
+    fn close_rename_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+        self.rename_input_state.clear_input();
+    }
+
+    fn apply_rename(&mut self) {
+        let label = self.rename_input_state.input().trim().to_string();
+        let label = (!label.is_empty()).then_some(label);
+        let index = self.list_state.selected;
+        self.close_rename_dialog();
+        self.tx.send(AppEventType::RenameBookmark(index, label));
+    }
+
+    fn non_empty(&self) -> bool {
+        !self.bookmarks.is_empty()
+    }
+}
+
+fn build_list_item(bookmark: &Bookmark, selected: bool) -> ListItem<'_> {
+    let line = match &bookmark.label {
+        Some(label) => Line::from(vec![
+            " ".into(),
+            label.clone().bold(),
+            "  ".into(),
+            bookmark.uri().fg(URI_TEXT_COLOR),
+            " ".into(),
+        ]),
+        None => Line::from(vec![" ".into(), bookmark.uri().into(), " ".into()]),
+    };
+
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event, object::ObjectKey, set_cells};
+
+    use super::*;
+    use ratatui::{backend::TestBackend, buffer::Buffer, style::Modifier, Terminal};
+
+    #[test]
+    fn test_render() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let backend = TestBackend::new(42, 6);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut labeled = Bookmark::new(
+            ObjectKey {
+                bucket_name: "bucket-1".to_string(),
+                object_path: vec!["logs".to_string()],
+            },
+            false,
+        );
+        labeled.label = Some("logs".to_string());
+        let bookmarks = vec![
+            labeled,
+            Bookmark::new(
+                ObjectKey {
+                    bucket_name: "bucket-2".to_string(),
+                    object_path: vec!["a".to_string(), "b.txt".to_string()],
+                },
+                true,
+            ),
+        ];
+        let mut page = BookmarksPage::new(bookmarks, tx);
+
+        terminal.draw(|f| page.render(f, f.size()))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────────────────── 1 / 2 ┐",
+            "│  logs  s3://bucket-1/logs/             │",
+            "│  s3://bucket-2/a/b.txt                 │",
+            "│                                        │",
+            "│                                        │",
+            "└────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..40, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // label
+            (3..7, [1]) => modifier: Modifier::BOLD,
+            // uri
+            (9..28, [1]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+}
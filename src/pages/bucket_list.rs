@@ -1,20 +1,25 @@
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Rect,
     style::{Color, Style, Stylize},
-    text::Line,
+    text::{Line, Span},
     widgets::ListItem,
     Frame,
 };
 
 use crate::{
     event::{AppEventType, Sender},
+    file::paste_from_clipboard,
+    filter::{split_matches, BucketFilter, FilterOptions, ListFilter},
     key_code, key_code_char,
     object::BucketItem,
     pages::util::{build_helps, build_short_helps},
-    util::split_str,
+    ui::common::format_datetime,
     widget::{
         BucketListSortDialog, BucketListSortDialogState, BucketListSortType, InputDialog,
         InputDialogState, ScrollList, ScrollListState,
@@ -25,15 +30,24 @@ const SELECTED_COLOR: Color = Color::Cyan;
 const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
 const HIGHLIGHTED_ITEM_TEXT_COLOR: Color = Color::Red;
 
+const MIN_NAME_WIDTH: usize = 10;
+const REGION_WIDTH: usize = 14;
+const DATE_WIDTH: usize = 19;
+
 #[derive(Debug)]
 pub struct BucketListPage {
     bucket_items: Vec<BucketItem>,
     view_indices: Vec<usize>,
+    // the buckets whose regions have been requested, including the ones failed to load
+    region_requested: HashSet<String>,
 
     view_state: ViewState,
 
     list_state: ScrollListState,
     filter_input_state: InputDialogState,
+    filter_options: FilterOptions,
+    filter: BucketFilter,
+    go_to_input_state: InputDialogState,
     sort_dialog_state: BucketListSortDialogState,
     tx: Sender,
 }
@@ -43,6 +57,7 @@ enum ViewState {
     Default,
     FilterDialog,
     SortDialog,
+    GoToDialog,
 }
 
 impl BucketListPage {
@@ -52,9 +67,13 @@ impl BucketListPage {
         Self {
             bucket_items,
             view_indices,
+            region_requested: HashSet::new(),
             view_state: ViewState::Default,
             list_state: ScrollListState::new(items_len),
             filter_input_state: InputDialogState::default(),
+            filter_options: FilterOptions::default(),
+            filter: BucketFilter::default(),
+            go_to_input_state: InputDialogState::default(),
             sort_dialog_state: BucketListSortDialogState::default(),
             tx,
         }
@@ -94,12 +113,33 @@ impl BucketListPage {
                 key_code_char!('x') if self.non_empty() => {
                     self.tx.send(AppEventType::BucketListOpenManagementConsole);
                 }
+                key_code_char!('i') if self.non_empty() => {
+                    self.tx.send(AppEventType::OpenBucketStats);
+                }
+                key_code_char!('U') if self.non_empty() => {
+                    self.tx.send(AppEventType::OpenDiskUsage);
+                }
                 key_code_char!('/') => {
                     self.open_filter_dialog();
                 }
                 key_code_char!('o') => {
                     self.open_sort_dialog();
                 }
+                key_code_char!(':') => {
+                    self.open_go_to_dialog();
+                }
+                key_code_char!('\'') => {
+                    self.tx.send(AppEventType::OpenBookmarks);
+                }
+                key_code_char!('H') => {
+                    self.tx.send(AppEventType::HistoryBack);
+                }
+                key_code_char!('L') => {
+                    self.tx.send(AppEventType::HistoryForward);
+                }
+                key_code_char!('r', Ctrl) => {
+                    self.tx.send(AppEventType::OpenHistory);
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -112,6 +152,14 @@ impl BucketListPage {
                 key_code!(KeyCode::Enter) => {
                     self.apply_filter();
                 }
+                key_code!(KeyCode::Tab) => {
+                    self.filter_options.mode = self.filter_options.mode.next();
+                    self.filter_view_indices();
+                }
+                key_code_char!('t', Ctrl) => {
+                    self.filter_options.ignore_case = !self.filter_options.ignore_case;
+                    self.filter_view_indices();
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -138,6 +186,20 @@ impl BucketListPage {
                 }
                 _ => {}
             },
+            ViewState::GoToDialog => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_go_to_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.apply_go_to();
+                }
+                key_code_char!('v', Ctrl) => {
+                    self.paste_to_go_to_dialog();
+                }
+                _ => {
+                    self.go_to_input_state.handle_key_event(key);
+                }
+            },
         }
     }
 
@@ -145,10 +207,23 @@ impl BucketListPage {
         let offset = self.list_state.offset;
         let selected = self.list_state.selected;
 
+        let (_, region_w, _) = calc_column_widths(area.width);
+        if region_w.is_some() {
+            let show_item_count = area.height as usize - 2 /* border */;
+            let names = self
+                .view_indices
+                .iter()
+                .skip(offset)
+                .take(show_item_count)
+                .map(|&i| self.bucket_items[i].name.clone())
+                .collect();
+            self.request_regions(names);
+        }
+
         let list_items = build_list_items(
             &self.bucket_items,
             &self.view_indices,
-            self.filter_input_state.input(),
+            self.filter.name_filter(),
             offset,
             selected,
             area,
@@ -158,7 +233,9 @@ impl BucketListPage {
         f.render_stateful_widget(list, area, &mut self.list_state);
 
         if let ViewState::FilterDialog = self.view_state {
-            let filter_dialog = InputDialog::default().title("Filter").max_width(30);
+            let filter_dialog = InputDialog::default()
+                .title(self.filter_options.dialog_title())
+                .max_width(40);
             f.render_stateful_widget(filter_dialog, area, &mut self.filter_input_state);
 
             let (cursor_x, cursor_y) = self.filter_input_state.cursor();
@@ -169,6 +246,18 @@ impl BucketListPage {
             let sort_dialog = BucketListSortDialog::new(self.sort_dialog_state);
             f.render_widget(sort_dialog, area);
         }
+
+        if let ViewState::GoToDialog = self.view_state {
+            let go_to_dialog = InputDialog::default().title("Go to");
+            f.render_stateful_widget(go_to_dialog, area, &mut self.go_to_input_state);
+
+            let (cursor_x, cursor_y) = self.go_to_input_state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
+    }
+
+    pub fn is_default_view(&self) -> bool {
+        matches!(self.view_state, ViewState::Default)
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -184,6 +273,19 @@ impl BucketListPage {
                         (&["Enter"], "Open bucket"),
                         (&["/"], "Filter bucket list"),
                         (&["o"], "Sort bucket list"),
+                        (&[":"], "Go to path or S3 URI"),
+                        (&["'"], "Open bookmarks"),
+                        (&["H/L"], "Go back/forward in history"),
+                        (&["Ctrl-r"], "Open recent locations"),
+                        (&["Ctrl-t"], "Open new tab"),
+                        (&["Ctrl-w"], "Close current tab"),
+                        (&["gt/gT", "1-9"], "Switch tab"),
+                        (&["Ctrl-d"], "Toggle dual-pane mode"),
+                        (&["Ctrl-l"], "Toggle local directory"),
+                        (&["Tab"], "Switch pane"),
+                        (&["F5/F6/F7"], "Copy/Move/Sync to other pane"),
+                        (&["i"], "Show bucket stats"),
+                        (&["U"], "Open disk usage explorer"),
                         (&["x"], "Open management console in browser"),
                     ]
                 } else {
@@ -197,6 +299,19 @@ impl BucketListPage {
                         (&["Enter"], "Open bucket"),
                         (&["/"], "Filter bucket list"),
                         (&["o"], "Sort bucket list"),
+                        (&[":"], "Go to path or S3 URI"),
+                        (&["'"], "Open bookmarks"),
+                        (&["H/L"], "Go back/forward in history"),
+                        (&["Ctrl-r"], "Open recent locations"),
+                        (&["Ctrl-t"], "Open new tab"),
+                        (&["Ctrl-w"], "Close current tab"),
+                        (&["gt/gT", "1-9"], "Switch tab"),
+                        (&["Ctrl-d"], "Toggle dual-pane mode"),
+                        (&["Ctrl-l"], "Toggle local directory"),
+                        (&["Tab"], "Switch pane"),
+                        (&["F5/F6/F7"], "Copy/Move/Sync to other pane"),
+                        (&["i"], "Show bucket stats"),
+                        (&["U"], "Open disk usage explorer"),
                         (&["x"], "Open management console in browser"),
                     ]
                 }
@@ -205,6 +320,8 @@ impl BucketListPage {
                 (&["Ctrl-c"], "Quit app"),
                 (&["Esc"], "Close filter dialog"),
                 (&["Enter"], "Apply filter"),
+                (&["Tab"], "Switch mode (substring/regex/glob/fuzzy)"),
+                (&["Ctrl-t"], "Toggle case sensitivity"),
             ],
             ViewState::SortDialog => &[
                 (&["Ctrl-c"], "Quit app"),
@@ -212,6 +329,12 @@ impl BucketListPage {
                 (&["j/k"], "Select item"),
                 (&["Enter"], "Apply sort"),
             ],
+            ViewState::GoToDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close go to dialog"),
+                (&["Ctrl-v"], "Paste from clipboard"),
+                (&["Enter"], "Go to path or S3 URI"),
+            ],
         };
         build_helps(helps)
     }
@@ -244,6 +367,7 @@ impl BucketListPage {
             ViewState::FilterDialog => &[
                 (&["Esc"], "Close", 2),
                 (&["Enter"], "Filter", 1),
+                (&["Tab"], "Mode", 3),
                 (&["?"], "Help", 0),
             ],
             ViewState::SortDialog => &[
@@ -252,6 +376,11 @@ impl BucketListPage {
                 (&["Enter"], "Sort", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::GoToDialog => &[
+                (&["Esc"], "Close", 2),
+                (&["Ctrl-v"], "Paste", 3),
+                (&["Enter"], "Go", 1),
+            ],
         };
         build_short_helps(helps)
     }
@@ -302,6 +431,30 @@ impl BucketListPage {
         self.sort_view_indices();
     }
 
+    fn open_go_to_dialog(&mut self) {
+        self.view_state = ViewState::GoToDialog;
+    }
+
+    fn close_go_to_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+        self.go_to_input_state.clear_input();
+    }
+
+    fn apply_go_to(&mut self) {
+        let input = self.go_to_input_state.input().to_string();
+        self.close_go_to_dialog();
+        if !input.is_empty() {
+            self.tx.send(AppEventType::MoveToInput(input));
+        }
+    }
+
+    fn paste_to_go_to_dialog(&mut self) {
+        match paste_from_clipboard() {
+            Ok(s) => self.go_to_input_state.insert_str(&s),
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+    }
+
     fn apply_filter(&mut self) {
         self.view_state = ViewState::Default;
 
@@ -315,14 +468,15 @@ impl BucketListPage {
     }
 
     fn filter_view_indices(&mut self) {
-        let filter = self.filter_input_state.input();
+        let filter = BucketFilter::new(self.filter_input_state.input(), self.filter_options);
         self.view_indices = self
             .bucket_items
             .iter()
             .enumerate()
-            .filter(|(_, item)| item.name.contains(filter))
+            .filter(|(_, item)| filter.matches(item))
             .map(|(idx, _)| idx)
             .collect();
+        self.filter = filter;
         // reset list state
         self.list_state = ScrollListState::new(self.view_indices.len());
 
@@ -348,19 +502,104 @@ impl BucketListPage {
     }
 
     fn sort_view_indices(&mut self) {
-        let items = &self.bucket_items;
         let selected = self.sort_dialog_state.selected();
+        // sorting or filtering by region needs the regions of all the buckets
+        if self.filter.has_region()
+            || matches!(
+                selected,
+                BucketListSortType::RegionAsc | BucketListSortType::RegionDesc
+            )
+        {
+            let names = self.bucket_items.iter().map(|b| b.name.clone()).collect();
+            self.request_regions(names);
+        }
+
+        let items = &self.bucket_items;
 
         #[allow(clippy::type_complexity)]
         let sort_func: Box<dyn FnMut(&usize, &usize) -> Ordering> = match selected {
             BucketListSortType::Default => Box::new(|a, b| a.cmp(b)),
             BucketListSortType::NameAsc => Box::new(|a, b| items[*a].name.cmp(&items[*b].name)),
             BucketListSortType::NameDesc => Box::new(|a, b| items[*b].name.cmp(&items[*a].name)),
+            BucketListSortType::CreationDateAsc => {
+                Box::new(|a, b| items[*a].creation_date.cmp(&items[*b].creation_date))
+            }
+            BucketListSortType::CreationDateDesc => {
+                Box::new(|a, b| items[*b].creation_date.cmp(&items[*a].creation_date))
+            }
+            // the buckets in the same region are grouped and sorted by name
+            BucketListSortType::RegionAsc => Box::new(|a, b| {
+                let (a, b) = (&items[*a], &items[*b]);
+                a.region.cmp(&b.region).then_with(|| a.name.cmp(&b.name))
+            }),
+            BucketListSortType::RegionDesc => Box::new(|a, b| {
+                let (a, b) = (&items[*a], &items[*b]);
+                b.region.cmp(&a.region).then_with(|| a.name.cmp(&b.name))
+            }),
         };
 
         self.view_indices.sort_by(sort_func);
     }
 
+    // the region of each bucket needs a request, so they are loaded only when needed
+    fn request_regions(&mut self, names: Vec<String>) {
+        let names: Vec<String> = names
+            .into_iter()
+            .filter(|name| {
+                let loaded = self
+                    .bucket_items
+                    .iter()
+                    .any(|b| b.name == *name && b.region.is_some());
+                !loaded && !self.region_requested.contains(name)
+            })
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+        self.region_requested.extend(names.iter().cloned());
+        self.tx.send(AppEventType::BucketListLoadRegions(names));
+    }
+
+    pub fn set_regions(&mut self, regions: &HashMap<String, String>) {
+        for item in self.bucket_items.iter_mut() {
+            if let Some(region) = regions.get(&item.name) {
+                item.region = Some(region.clone());
+            }
+        }
+
+        // the region filter and sort are applied again, keeping the selected bucket
+        let selected = self
+            .view_indices
+            .get(self.list_state.selected)
+            .map(|&i| self.bucket_items[i].name.clone());
+        self.filter_view_indices();
+        if let Some(pos) = selected.and_then(|name| {
+            self.view_indices
+                .iter()
+                .position(|&i| self.bucket_items[i].name == name)
+        }) {
+            self.list_state.select_index(pos);
+        }
+    }
+
+    pub fn select_item(&mut self, name: &str) -> bool {
+        let find = |page: &Self| {
+            page.view_indices
+                .iter()
+                .position(|&i| page.bucket_items[i].name == name)
+        };
+        let mut pos = find(self);
+        if pos.is_none() && !self.filter_input_state.input().is_empty() {
+            // the bucket may be hidden by the filter
+            self.reset_filter();
+            pos = find(self);
+        }
+        if let Some(pos) = pos {
+            self.list_state.select_index(pos);
+        }
+        pos.is_some()
+    }
+
     pub fn current_selected_item(&self) -> &BucketItem {
         let i = self
             .view_indices
@@ -389,7 +628,7 @@ impl BucketListPage {
 fn build_list_items<'a>(
     current_items: &'a [BucketItem],
     view_indices: &'a [usize],
-    filter: &'a str,
+    filter: &ListFilter,
     offset: usize,
     selected: usize,
     area: Rect,
@@ -403,24 +642,69 @@ fn build_list_items<'a>(
         .enumerate()
         .map(|(idx, item)| {
             let selected = idx + offset == selected;
-            build_list_item(&item.name, selected, filter)
+            build_list_item(item, selected, filter, area.width)
         })
         .collect()
 }
 
-fn build_list_item<'a>(name: &'a str, selected: bool, filter: &'a str) -> ListItem<'a> {
-    let line = if filter.is_empty() {
-        Line::from(vec![" ".into(), name.into(), " ".into()])
+// returns the width of the name, the region (if shown) and the creation date (if shown) columns
+fn calc_column_widths(width: u16) -> (usize, Option<usize>, Option<usize>) {
+    let rest_w = (width as usize).saturating_sub(2 /* spaces */ + 4 /* border + pad */);
+    let region_col_w = REGION_WIDTH + 4 /* spaces */;
+    let date_col_w = DATE_WIDTH + 4 /* spaces */;
+    // the columns are omitted if the list is too narrow, e.g. in the dual-pane mode
+    if rest_w >= MIN_NAME_WIDTH + region_col_w + date_col_w {
+        let name_w = rest_w - region_col_w - date_col_w;
+        (name_w, Some(REGION_WIDTH), Some(DATE_WIDTH))
+    } else if rest_w >= MIN_NAME_WIDTH + region_col_w {
+        (rest_w - region_col_w, Some(REGION_WIDTH), None)
     } else {
-        let (before, highlighted, after) = split_str(name, filter).unwrap();
-        Line::from(vec![
-            " ".into(),
-            before.into(),
-            highlighted.fg(HIGHLIGHTED_ITEM_TEXT_COLOR),
-            after.into(),
-            " ".into(),
-        ])
-    };
+        (rest_w, None, None)
+    }
+}
+
+fn build_list_item<'a>(
+    item: &'a BucketItem,
+    selected: bool,
+    filter: &ListFilter,
+    width: u16,
+) -> ListItem<'a> {
+    let name = &item.name;
+    let ranges = filter.find(name).unwrap_or_default();
+    let mut spans: Vec<Span> = vec![" ".into()];
+    spans.extend(
+        split_matches(name, &ranges)
+            .into_iter()
+            .map(|(s, matched)| {
+                if matched {
+                    s.fg(HIGHLIGHTED_ITEM_TEXT_COLOR)
+                } else {
+                    s.into()
+                }
+            }),
+    );
+
+    let (name_w, region_w, date_w) = calc_column_widths(width);
+    if region_w.is_some() || date_w.is_some() {
+        spans.push(
+            " ".repeat(name_w.saturating_sub(name.chars().count()))
+                .into(),
+        );
+    }
+    if let Some(region_w) = region_w {
+        let region = item.region.as_deref().unwrap_or_default();
+        spans.push(format!("    {:<region_w$}", region, region_w = region_w).into());
+    }
+    if let Some(date_w) = date_w {
+        let date = item
+            .creation_date
+            .as_ref()
+            .map(format_datetime)
+            .unwrap_or_default();
+        spans.push(format!("    {:<date_w$}", date, date_w = date_w).into());
+    }
+    spans.push(" ".into());
+    let line = Line::from(spans);
 
     let style = if selected {
         Style::default()
@@ -437,11 +721,13 @@ mod tests {
     use crate::{event, set_cells};
 
     use super::*;
+    use chrono::{DateTime, Local, NaiveDateTime};
+    use crossterm::event::KeyModifiers;
     use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
 
     #[test]
     fn test_render_without_scroll() -> std::io::Result<()> {
-        let (tx, _) = event::new();
+        let (tx, _rx) = event::new();
         let mut terminal = setup_terminal()?;
 
         terminal.draw(|f| {
@@ -449,6 +735,8 @@ mod tests {
                 .iter()
                 .map(|name| BucketItem {
                     name: name.to_string(),
+                    creation_date: None,
+                    region: None,
                 })
                 .collect();
             let mut page = BucketListPage::new(items, tx);
@@ -480,13 +768,15 @@ mod tests {
 
     #[test]
     fn test_render_with_scroll() -> std::io::Result<()> {
-        let (tx, _) = event::new();
+        let (tx, _rx) = event::new();
         let mut terminal = setup_terminal()?;
 
         terminal.draw(|f| {
             let items = (0..16)
                 .map(|i| BucketItem {
                     name: format!("bucket{}", i + 1),
+                    creation_date: None,
+                    region: None,
                 })
                 .collect();
             let mut page = BucketListPage::new(items, tx);
@@ -517,15 +807,150 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_with_columns() -> std::io::Result<()> {
+        let (tx, _rx) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(70, 6))?;
+
+        let items = vec![
+            BucketItem {
+                name: "logs".to_string(),
+                creation_date: Some(parse_datetime("2024-01-02 13:01:02")),
+                region: Some("us-east-1".to_string()),
+            },
+            BucketItem {
+                name: "backup".to_string(),
+                creation_date: Some(parse_datetime("2023-12-31 09:00:00")),
+                region: Some("ap-southeast-1".to_string()),
+            },
+            BucketItem {
+                name: "private".to_string(),
+                creation_date: None,
+                region: None,
+            },
+        ];
+        let mut page = BucketListPage::new(items, tx);
+        let area = Rect::new(0, 0, 70, 6);
+
+        terminal.draw(|f| {
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────────────────────────────────────────────── 1 / 3 ┐",
+            "│  logs                       us-east-1         2024-01-02 13:01:02  │",
+            "│  backup                     ap-southeast-1    2023-12-31 09:00:00  │",
+            "│  private                                                           │",
+            "│                                                                    │",
+            "└────────────────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..68, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_and_filter_by_region() {
+        let (tx, _rx) = event::new();
+
+        let items = ["foo", "bar", "baz", "qux"]
+            .iter()
+            .map(|name| BucketItem {
+                name: name.to_string(),
+                creation_date: None,
+                region: None,
+            })
+            .collect();
+        let mut page = BucketListPage::new(items, tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('/')));
+        for c in "region:eu".chars() {
+            page.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        page.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        // the regions are not loaded yet
+        assert!(page.view_indices.is_empty());
+
+        let regions = [
+            ("foo", "us-east-1"),
+            ("bar", "eu-west-1"),
+            ("baz", "eu-central-1"),
+        ]
+        .into_iter()
+        .map(|(name, region)| (name.to_string(), region.to_string()))
+        .collect();
+        page.set_regions(&regions);
+
+        assert_eq!(page.view_indices, vec![1, 2]);
+
+        page.handle_key(KeyEvent::from(KeyCode::Esc));
+        page.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        for _ in 0..5 {
+            page.handle_key(KeyEvent::from(KeyCode::Char('j'))); // select RegionAsc
+        }
+        page.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(page.view_indices, vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_request_regions() -> std::io::Result<()> {
+        let (tx, rx) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(70, 4))?;
+
+        let items = ["foo", "bar", "baz", "qux"]
+            .iter()
+            .map(|name| BucketItem {
+                name: name.to_string(),
+                creation_date: None,
+                region: None,
+            })
+            .collect();
+        let mut page = BucketListPage::new(items, tx);
+
+        // only the shown buckets are requested, and only once
+        terminal.draw(|f| page.render(f, Rect::new(0, 0, 70, 4)))?;
+        assert_eq!(recv_regions_request(&rx), ["foo", "bar"]);
+        terminal.draw(|f| page.render(f, Rect::new(0, 0, 70, 4)))?;
+        assert_eq!(page.region_requested.len(), 2);
+
+        // the rest are requested to sort by region
+        page.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        for _ in 0..5 {
+            page.handle_key(KeyEvent::from(KeyCode::Char('j'))); // select RegionAsc
+        }
+        assert_eq!(recv_regions_request(&rx), ["baz", "qux"]);
+
+        Ok(())
+    }
+
+    fn recv_regions_request(rx: &event::Receiver) -> Vec<String> {
+        loop {
+            // skip other events (e.g. errors from reading terminal events)
+            if let AppEventType::BucketListLoadRegions(names) = rx.recv() {
+                return names;
+            }
+        }
+    }
+
     #[test]
     fn test_render_filter_items() -> std::io::Result<()> {
-        let (tx, _) = event::new();
+        let (tx, _rx) = event::new();
         let mut terminal = setup_terminal()?;
 
         let items = ["foo", "bar", "baz", "qux", "foobar"]
             .iter()
             .map(|name| BucketItem {
                 name: name.to_string(),
+                creation_date: None,
+                region: None,
             })
             .collect();
         let mut page = BucketListPage::new(items, tx);
@@ -543,7 +968,7 @@ mod tests {
             "┌───────────────────── 1 / 3 ┐",
             "│  bar                       │",
             "│  baz                       │",
-            "│ ╭Filter──────────────────╮ │",
+            "│ ╭Filter (substring)──────╮ │",
             "│ │ b                      │ │",
             "│ ╰────────────────────────╯ │",
             "│                            │",
@@ -597,17 +1022,19 @@ mod tests {
 
     #[test]
     fn test_render_sort_items() -> std::io::Result<()> {
-        let (tx, _) = event::new();
-        let mut terminal = setup_terminal()?;
+        let (tx, _rx) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(30, 12))?;
 
         let items = ["foo", "bar", "baz", "qux", "foobar"]
             .iter()
             .map(|name| BucketItem {
                 name: name.to_string(),
+                creation_date: None,
+                region: None,
             })
             .collect();
         let mut page = BucketListPage::new(items, tx);
-        let area = Rect::new(0, 0, 30, 10);
+        let area = Rect::new(0, 0, 30, 12);
 
         page.handle_key(KeyEvent::from(KeyCode::Char('o')));
         page.handle_key(KeyEvent::from(KeyCode::Char('j')));
@@ -620,21 +1047,21 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 5 ┐",
-            "│  qux                       │",
             "│ ╭Sort────────────────────╮ │",
             "│ │ Default                │ │",
             "│ │ Name (Asc)             │ │",
             "│ │ Name (Desc)            │ │",
+            "│ │ Creation Date (Asc)    │ │",
+            "│ │ Creation Date (Desc)   │ │",
+            "│ │ Region (Asc)           │ │",
+            "│ │ Region (Desc)          │ │",
             "│ ╰────────────────────────╯ │",
             "│                            │",
-            "│                            │",
             "└────────────────────────────┘",
         ]);
         set_cells! { expected =>
-            // selected item
-            (2..28, [1]) => bg: Color::Cyan, fg: Color::Black,
             // selected sort item
-            (4..26, [5]) => fg: Color::Cyan,
+            (4..26, [4]) => fg: Color::Cyan,
         }
 
         terminal.backend().assert_buffer(&expected);
@@ -644,12 +1071,14 @@ mod tests {
 
     #[test]
     fn test_filter_items() {
-        let (tx, _) = event::new();
+        let (tx, _rx) = event::new();
 
         let items = ["foo", "bar", "baz", "qux", "foobar"]
             .iter()
             .map(|name| BucketItem {
                 name: name.to_string(),
+                creation_date: None,
+                region: None,
             })
             .collect();
         let mut page = BucketListPage::new(items, tx);
@@ -678,14 +1107,62 @@ mod tests {
         assert_eq!(page.view_indices, vec![0, 1, 2, 3, 4]);
     }
 
+    #[test]
+    fn test_filter_items_with_modes() {
+        let (tx, _rx) = event::new();
+
+        let items = ["foo", "bar", "Baz", "qux", "foobar"]
+            .iter()
+            .map(|name| BucketItem {
+                name: name.to_string(),
+                creation_date: None,
+                region: None,
+            })
+            .collect();
+        let mut page = BucketListPage::new(items, tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('/')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('b')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('.')));
+
+        assert!(page.view_indices.is_empty());
+
+        page.handle_key(KeyEvent::from(KeyCode::Tab)); // regex
+
+        assert_eq!(page.view_indices, vec![1, 4]);
+
+        page.handle_key(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL)); // ignore case
+
+        assert_eq!(page.view_indices, vec![1, 2, 4]);
+
+        page.handle_key(KeyEvent::from(KeyCode::Tab)); // glob
+
+        assert!(page.view_indices.is_empty());
+
+        page.handle_key(KeyEvent::from(KeyCode::Backspace));
+        page.handle_key(KeyEvent::from(KeyCode::Char('*')));
+
+        assert_eq!(page.view_indices, vec![1, 2]);
+
+        page.handle_key(KeyEvent::from(KeyCode::Tab)); // fuzzy
+        page.handle_key(KeyEvent::from(KeyCode::Backspace));
+        page.handle_key(KeyEvent::from(KeyCode::Backspace));
+        page.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('r')));
+
+        assert_eq!(page.view_indices, vec![4]);
+    }
+
     #[test]
     fn test_sort_items() {
-        let (tx, _) = event::new();
+        let (tx, _rx) = event::new();
 
         let items = ["foo", "bar", "baz", "qux", "foobar"]
             .iter()
             .map(|name| BucketItem {
                 name: name.to_string(),
+                creation_date: None,
+                region: None,
             })
             .collect();
         let mut page = BucketListPage::new(items, tx);
@@ -713,12 +1190,14 @@ mod tests {
 
     #[test]
     fn test_filter_and_sort_items() {
-        let (tx, _) = event::new();
+        let (tx, _rx) = event::new();
 
         let items = ["foo", "bar", "baz", "qux", "foobar"]
             .iter()
             .map(|name| BucketItem {
                 name: name.to_string(),
+                creation_date: None,
+                region: None,
             })
             .collect();
         let mut page = BucketListPage::new(items, tx);
@@ -754,6 +1233,13 @@ mod tests {
         assert_eq!(page.view_indices, vec![0, 4]);
     }
 
+    fn parse_datetime(s: &str) -> DateTime<Local> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(30, 10);
         let mut terminal = Terminal::new(backend)?;
@@ -0,0 +1,242 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Stylize},
+    text::Line,
+    widgets::Block,
+    Frame,
+};
+
+use crate::{
+    event::{AppEventType, Sender},
+    key_code, key_code_char,
+    object::PrefixSize,
+    pages::util::{build_helps, build_short_helps},
+    stats::BucketStats,
+    ui::common::{format_datetime, format_object_count, format_ratio_bar, format_size_byte},
+    widget::{ScrollLines, ScrollLinesOptions, ScrollLinesState},
+};
+
+const BAR_WIDTH: usize = 20;
+
+#[derive(Debug)]
+pub struct BucketStatsPage {
+    stats: BucketStats,
+
+    scroll_lines_state: ScrollLinesState,
+    tx: Sender,
+}
+
+impl BucketStatsPage {
+    pub fn new(stats: BucketStats, tx: Sender) -> Self {
+        let lines = build_stats_lines(&stats);
+        let scroll_lines_state =
+            ScrollLinesState::new(lines, ScrollLinesOptions::new(false, false));
+        Self {
+            stats,
+            scroll_lines_state,
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key {
+            key_code!(KeyCode::Esc) => {
+                self.tx.send(AppEventType::Quit);
+            }
+            key_code!(KeyCode::Backspace) => {
+                self.tx.send(AppEventType::CloseCurrentPage);
+            }
+            key_code_char!('j') => {
+                self.scroll_lines_state.scroll_forward();
+            }
+            key_code_char!('k') => {
+                self.scroll_lines_state.scroll_backward();
+            }
+            key_code_char!('f') => {
+                self.scroll_lines_state.scroll_page_forward();
+            }
+            key_code_char!('b') => {
+                self.scroll_lines_state.scroll_page_backward();
+            }
+            key_code_char!('g') => {
+                self.scroll_lines_state.scroll_to_top();
+            }
+            key_code_char!('G') => {
+                self.scroll_lines_state.scroll_to_end();
+            }
+            key_code_char!('r') => {
+                let bucket_name = self.stats.bucket_name.clone();
+                self.tx.send(AppEventType::LoadBucketStats(bucket_name));
+            }
+            key_code_char!('?') => {
+                self.tx.send(AppEventType::OpenHelp);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let title = format!("Stats [{}]", self.stats.bucket_name);
+        let scroll_lines = ScrollLines::default().block(Block::bordered().title(title));
+        f.render_stateful_widget(scroll_lines, area, &mut self.scroll_lines_state);
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = &[
+            (&["Esc", "Ctrl-c"], "Quit app"),
+            (&["j/k"], "Scroll forward/backward"),
+            (&["f/b"], "Scroll page forward/backward"),
+            (&["g/G"], "Scroll to top/end"),
+            (&["r"], "Recalculate stats"),
+            (&["Backspace"], "Close stats"),
+        ];
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = &[
+            (&["Esc"], "Quit", 0),
+            (&["j/k"], "Scroll", 2),
+            (&["r"], "Recalculate", 1),
+            (&["Backspace"], "Close", 1),
+            (&["?"], "Help", 0),
+        ];
+        build_short_helps(helps)
+    }
+
+    pub fn bucket_name(&self) -> &str {
+        &self.stats.bucket_name
+    }
+
+    pub fn set_stats(&mut self, stats: BucketStats) {
+        self.scroll_lines_state.set_lines(build_stats_lines(&stats));
+        self.stats = stats;
+    }
+}
+
+fn build_stats_lines(stats: &BucketStats) -> Vec<Line<'static>> {
+    let total = stats.total;
+    let mut lines = vec![
+        Line::from("Total:".add_modifier(Modifier::BOLD)),
+        Line::from(format!(
+            " {}, {}",
+            format_size_byte(total.size_byte),
+            format_object_count(total.count)
+        )),
+        Line::from(""),
+    ];
+
+    lines.extend(build_size_lines(
+        "Storage classes:",
+        &stats.storage_classes,
+        total,
+    ));
+    lines.extend(build_size_lines("Object age:", &stats.ages, total));
+    lines.extend(build_size_lines(
+        "Top prefixes:",
+        &stats.top_prefixes,
+        total,
+    ));
+
+    lines.push(Line::from("Largest objects:".add_modifier(Modifier::BOLD)));
+    for (key, size_byte) in &stats.largest_objects {
+        lines.push(Line::from(format!(
+            " {:>10}  {}",
+            format_size_byte(*size_byte),
+            key
+        )));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(Line::from(
+        format!("Calculated at {}", format_datetime(&stats.calculated_at)).dark_gray(),
+    ));
+    lines
+}
+
+fn build_size_lines(
+    title: &'static str,
+    sizes: &[(String, PrefixSize)],
+    total: PrefixSize,
+) -> Vec<Line<'static>> {
+    let label_w = sizes.iter().map(|(l, _)| l.len()).max().unwrap_or_default();
+    let count_w = sizes
+        .iter()
+        .map(|(_, s)| format_object_count(s.count).len())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![Line::from(title.add_modifier(Modifier::BOLD))];
+    for (label, size) in sizes {
+        let ratio = if total.size_byte == 0 {
+            0.0
+        } else {
+            size.size_byte as f64 / total.size_byte as f64
+        };
+        lines.push(Line::from(format!(
+            " {:<label_w$}  {:>10}  {:>count_w$}  {}  {:>5.1}%",
+            label,
+            format_size_byte(size.size_byte),
+            format_object_count(size.count),
+            format_ratio_bar(ratio, BAR_WIDTH),
+            ratio * 100.0,
+            label_w = label_w,
+            count_w = count_w,
+        )));
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone};
+
+    fn size(size_byte: usize, count: usize) -> PrefixSize {
+        PrefixSize { size_byte, count }
+    }
+
+    #[test]
+    fn test_build_stats_lines() {
+        let stats = BucketStats {
+            bucket_name: "bucket-1".to_string(),
+            total: size(1000, 3),
+            storage_classes: vec![
+                ("STANDARD".to_string(), size(750, 2)),
+                ("GLACIER".to_string(), size(250, 1)),
+            ],
+            largest_objects: vec![("a/x.bin".to_string(), 500)],
+            ages: vec![("< 1 day".to_string(), size(1000, 3))],
+            top_prefixes: vec![("a/".to_string(), size(500, 1))],
+            calculated_at: Local.with_ymd_and_hms(2024, 1, 2, 13, 4, 5).unwrap(),
+        };
+
+        let actual: Vec<String> = build_stats_lines(&stats)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+
+        let expected = [
+            "Total:",
+            " 1000 B, 3 objects",
+            "",
+            "Storage classes:",
+            " STANDARD       750 B  2 objects  [###############     ]   75.0%",
+            " GLACIER        250 B   1 object  [#####               ]   25.0%",
+            "",
+            "Object age:",
+            " < 1 day      1000 B  3 objects  [####################]  100.0%",
+            "",
+            "Top prefixes:",
+            " a/       500 B  1 object  [##########          ]   50.0%",
+            "",
+            "Largest objects:",
+            "      500 B  a/x.bin",
+            "",
+            "Calculated at 2024-01-02 13:04:05",
+        ];
+        assert_eq!(actual, expected);
+    }
+}
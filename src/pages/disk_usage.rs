@@ -0,0 +1,381 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, ListItem, Padding, Paragraph},
+    Frame,
+};
+
+use crate::{
+    disk_usage::UsageNode,
+    event::{AppEventType, Sender},
+    key_code, key_code_char,
+    object::{ObjectItem, ObjectKey, PrefixSize},
+    pages::util::{build_helps, build_short_helps},
+    ui::common::{format_object_count, format_ratio_bar, format_size_byte},
+    widget::{ConfirmDialog, ScrollList, ScrollListState},
+};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
+const SUMMARY_TEXT_COLOR: Color = Color::DarkGray;
+
+const BAR_WIDTH: usize = 10;
+
+#[derive(Debug)]
+pub struct DiskUsagePage {
+    root: UsageNode,
+    // the key of the scanned prefix
+    key: ObjectKey,
+    // the path of the current directory from the root
+    path: Vec<String>,
+
+    view_state: ViewState,
+
+    list_state: ScrollListState,
+    tx: Sender,
+}
+
+#[derive(Debug)]
+enum ViewState {
+    Default,
+    DeleteConfirmDialog,
+}
+
+impl DiskUsagePage {
+    pub fn new(root: UsageNode, key: ObjectKey, tx: Sender) -> Self {
+        let items_len = root.children.len();
+        Self {
+            root,
+            key,
+            path: Vec::new(),
+            view_state: ViewState::Default,
+            list_state: ScrollListState::new(items_len),
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match self.view_state {
+            ViewState::Default => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.tx.send(AppEventType::Quit);
+                }
+                key_code!(KeyCode::Enter) if self.non_empty() => {
+                    self.move_down();
+                }
+                key_code!(KeyCode::Backspace) => {
+                    self.move_up();
+                }
+                key_code_char!('j') if self.non_empty() => {
+                    self.list_state.select_next();
+                }
+                key_code_char!('k') if self.non_empty() => {
+                    self.list_state.select_prev();
+                }
+                key_code_char!('g') if self.non_empty() => {
+                    self.list_state.select_first();
+                }
+                key_code_char!('G') if self.non_empty() => {
+                    self.list_state.select_last();
+                }
+                key_code_char!('f') if self.non_empty() => {
+                    self.list_state.select_next_page();
+                }
+                key_code_char!('b') if self.non_empty() => {
+                    self.list_state.select_prev_page();
+                }
+                key_code_char!('D') if self.non_empty() => {
+                    self.view_state = ViewState::DeleteConfirmDialog;
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::DeleteConfirmDialog => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('n') => {
+                    self.view_state = ViewState::Default;
+                }
+                key_code_char!('y') => {
+                    self.apply_delete();
+                }
+                _ => {}
+            },
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+
+        let current = self.current_node();
+        let summary = format!(
+            "{}  Total: {}, {}",
+            self.current_key().uri(false),
+            format_size_byte(current.size.size_byte),
+            format_object_count(current.size.count),
+        );
+        let summary = Paragraph::new(summary.fg(SUMMARY_TEXT_COLOR))
+            .block(Block::default().padding(Padding::horizontal(1)));
+        f.render_widget(summary, chunks[0]);
+
+        let list_area = chunks[1];
+        self.list_state
+            .fit_height(list_area.height as usize - 2 /* border */);
+        let offset = self.list_state.offset;
+        let selected = self.list_state.selected;
+
+        let current = self.root.get(&self.path).unwrap();
+        let show_item_count = (list_area.height as usize) - 2 /* border */;
+        let list_items = current
+            .children
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(show_item_count)
+            .map(|(i, node)| build_list_item(node, current.size.size_byte, i == selected))
+            .collect();
+
+        let list = ScrollList::new(list_items);
+        f.render_stateful_widget(list, list_area, &mut self.list_state);
+
+        if let ViewState::DeleteConfirmDialog = self.view_state {
+            let confirm_dialog = ConfirmDialog::new(self.delete_confirm_message());
+            f.render_widget(confirm_dialog, area);
+        }
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = match self.view_state {
+            ViewState::Default => &[
+                (&["Esc", "Ctrl-c"], "Quit app"),
+                (&["j/k"], "Select item"),
+                (&["g/G"], "Go to top/bottom"),
+                (&["f"], "Scroll page forward"),
+                (&["b"], "Scroll page backward"),
+                (&["Enter"], "Open folder"),
+                (&["Backspace"], "Go back to prev folder or close"),
+                (&["D"], "Delete selected item"),
+            ],
+            ViewState::DeleteConfirmDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "n"], "Cancel"),
+                (&["y"], "Delete item"),
+            ],
+        };
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = match self.view_state {
+            ViewState::Default => &[
+                (&["Esc"], "Quit", 0),
+                (&["j/k"], "Select", 3),
+                (&["Enter"], "Open", 1),
+                (&["Backspace"], "Go back", 2),
+                (&["D"], "Delete", 4),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::DeleteConfirmDialog => &[(&["Esc"], "Cancel", 2), (&["y"], "Delete", 1)],
+        };
+        build_short_helps(helps)
+    }
+
+    pub fn key(&self) -> &ObjectKey {
+        &self.key
+    }
+
+    // the sizes of all prefixes in the scanned tree
+    pub fn prefix_sizes(&self) -> Vec<(ObjectKey, PrefixSize)> {
+        self.root.prefix_sizes(&self.key)
+    }
+
+    // called after the object or the objects under the prefix `key` are deleted
+    pub fn remove_item(&mut self, key: &ObjectKey, is_dir: bool) {
+        if !key.starts_with(&self.key) {
+            return;
+        }
+        let path = &key.object_path[self.key.object_path.len()..];
+        if self.root.remove(path, is_dir).is_none() {
+            return;
+        }
+        // go up if the current directory has been removed
+        while self.root.get(&self.path).is_none() {
+            self.path.pop();
+        }
+        let selected = self.list_state.selected;
+        self.list_state = ScrollListState::new(self.current_node().children.len());
+        if self.non_empty() {
+            self.list_state
+                .select_index(selected.min(self.current_node().children.len() - 1));
+        }
+    }
+
+    fn move_down(&mut self) {
+        let node = self.selected_node();
+        if node.is_dir {
+            self.path.push(node.name.clone());
+            self.list_state = ScrollListState::new(self.current_node().children.len());
+        }
+    }
+
+    fn move_up(&mut self) {
+        let Some(name) = self.path.pop() else {
+            self.tx.send(AppEventType::CloseCurrentPage);
+            return;
+        };
+        let current = self.current_node();
+        let index = current
+            .children
+            .iter()
+            .position(|c| c.is_dir && c.name == name);
+        self.list_state = ScrollListState::new(current.children.len());
+        if let Some(i) = index {
+            self.list_state.select_index(i);
+        }
+    }
+
+    fn delete_confirm_message(&self) -> String {
+        let node = self.selected_node();
+        if node.is_dir {
+            format!("Delete {}/ and all objects under it?", node.name)
+        } else {
+            format!("Delete {}?", node.name)
+        }
+    }
+
+    fn apply_delete(&mut self) {
+        self.view_state = ViewState::Default;
+        let node = self.selected_node();
+        let item = if node.is_dir {
+            ObjectItem::Dir {
+                name: node.name.clone(),
+            }
+        } else {
+            ObjectItem::File {
+                name: node.name.clone(),
+                size_byte: node.size.size_byte,
+                last_modified: Default::default(),
+                e_tag: None,
+                storage_class: None,
+            }
+        };
+        let key = self.current_key();
+        self.tx.send(AppEventType::DiskUsageDeleteItem(key, item));
+    }
+
+    fn current_key(&self) -> ObjectKey {
+        let mut key = self.key.clone();
+        key.object_path.extend(self.path.iter().cloned());
+        key
+    }
+
+    fn current_node(&self) -> &UsageNode {
+        self.root.get(&self.path).unwrap()
+    }
+
+    fn selected_node(&self) -> &UsageNode {
+        &self.current_node().children[self.list_state.selected]
+    }
+
+    fn non_empty(&self) -> bool {
+        !self.current_node().children.is_empty()
+    }
+}
+
+fn build_list_item(node: &UsageNode, total_byte: usize, selected: bool) -> ListItem<'_> {
+    let ratio = if total_byte == 0 {
+        0.0
+    } else {
+        node.size.size_byte as f64 / total_byte as f64
+    };
+    let bar = format_ratio_bar(ratio, BAR_WIDTH);
+    let size = format!("{:>10}", format_size_byte(node.size.size_byte));
+    let percent = format!("{:>5.1}%", ratio * 100.0);
+
+    let mut spans = vec![
+        " ".into(),
+        size.into(),
+        "  ".into(),
+        bar.into(),
+        "  ".into(),
+        percent.into(),
+        "  ".into(),
+    ];
+    if node.is_dir {
+        spans.extend([node.name.as_str().bold(), "/".bold()]);
+    } else {
+        spans.push(node.name.as_str().into());
+    }
+    spans.push(" ".into());
+
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(Line::from(spans)).style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event, set_cells};
+
+    use super::*;
+    use chrono::Local;
+    use ratatui::{backend::TestBackend, buffer::Buffer, style::Modifier, Terminal};
+
+    #[test]
+    fn test_render() -> std::io::Result<()> {
+        let (tx, _rx) = event::new();
+        let backend = TestBackend::new(60, 7);
+        let mut terminal = Terminal::new(backend)?;
+
+        let file = |name: &str, size_byte| ObjectItem::File {
+            name: name.to_string(),
+            size_byte,
+            last_modified: Local::now(),
+            e_tag: None,
+            storage_class: None,
+        };
+        let items = vec![
+            file("dir1/file1", 3000),
+            file("dir1/file2", 1000),
+            file("file3", 1000),
+        ];
+        let root = UsageNode::build(&items);
+        let key = ObjectKey {
+            bucket_name: "bucket-1".to_string(),
+            object_path: vec!["a".to_string()],
+        };
+        let mut page = DiskUsagePage::new(root, key, tx);
+
+        terminal.draw(|f| page.render(f, f.size()))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            " s3://bucket-1/a/  Total: 4.88 KiB, 3 objects               ",
+            "┌─────────────────────────────────────────────────── 1 / 2 ┐",
+            "│    3.91 KiB  [########  ]   80.0%  dir1/                 │",
+            "│      1000 B  [##        ]   20.0%  file3                 │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // summary
+            (1..45, [0]) => fg: Color::DarkGray,
+            // dir items
+            (37..42, [2]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [2]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+}
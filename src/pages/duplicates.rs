@@ -0,0 +1,485 @@
+use std::collections::HashSet;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, ListItem, Padding, Paragraph},
+    Frame,
+};
+
+use crate::{
+    duplicate::DuplicateGroup,
+    event::{AppEventType, Sender},
+    key_code, key_code_char,
+    object::ObjectKey,
+    pages::util::{build_helps, build_short_helps},
+    ui::common::{format_datetime, format_size_byte},
+    widget::{ConfirmDialog, ScrollList, ScrollListState},
+};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
+const MARKED_ITEM_TEXT_COLOR: Color = Color::Yellow;
+const SUMMARY_TEXT_COLOR: Color = Color::DarkGray;
+
+#[derive(Debug)]
+pub struct DuplicatesPage {
+    // the key of the scanned prefix
+    key: ObjectKey,
+    groups: Vec<DuplicateGroup>,
+    // the full keys of the objects to delete
+    marked: HashSet<String>,
+    // the index of the group whose objects are listed
+    opened_group: Option<usize>,
+
+    view_state: ViewState,
+
+    group_list_state: ScrollListState,
+    object_list_state: ScrollListState,
+    tx: Sender,
+}
+
+#[derive(Debug)]
+enum ViewState {
+    Default,
+    DeleteConfirmDialog,
+}
+
+impl DuplicatesPage {
+    pub fn new(key: ObjectKey, groups: Vec<DuplicateGroup>, tx: Sender) -> Self {
+        let groups_len = groups.len();
+        Self {
+            key,
+            groups,
+            marked: HashSet::new(),
+            opened_group: None,
+            view_state: ViewState::Default,
+            group_list_state: ScrollListState::new(groups_len),
+            object_list_state: ScrollListState::new(0),
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match self.view_state {
+            ViewState::Default => match key {
+                key_code!(KeyCode::Esc) => {
+                    if self.marked.is_empty() {
+                        self.tx.send(AppEventType::Quit);
+                    } else {
+                        self.marked.clear();
+                    }
+                }
+                key_code!(KeyCode::Backspace) => {
+                    if self.opened_group.is_some() {
+                        self.opened_group = None;
+                    } else {
+                        self.tx.send(AppEventType::CloseCurrentPage);
+                    }
+                }
+                key_code!(KeyCode::Enter) if self.opened_group.is_none() && self.non_empty() => {
+                    self.open_group();
+                }
+                key_code_char!('j') if self.non_empty() => {
+                    self.current_list_state().select_next();
+                }
+                key_code_char!('k') if self.non_empty() => {
+                    self.current_list_state().select_prev();
+                }
+                key_code_char!('g') if self.non_empty() => {
+                    self.current_list_state().select_first();
+                }
+                key_code_char!('G') if self.non_empty() => {
+                    self.current_list_state().select_last();
+                }
+                key_code_char!('f') if self.non_empty() => {
+                    self.current_list_state().select_next_page();
+                }
+                key_code_char!('b') if self.non_empty() => {
+                    self.current_list_state().select_prev_page();
+                }
+                key_code_char!(' ') if self.opened_group.is_some() => {
+                    self.toggle_mark();
+                }
+                key_code_char!('K') if self.opened_group.is_some() => {
+                    self.keep_selected();
+                }
+                key_code_char!('*') if self.non_empty() => {
+                    self.mark_all_but_oldest();
+                }
+                key_code_char!('D') if !self.marked.is_empty() => {
+                    self.open_delete_confirm_dialog();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::DeleteConfirmDialog => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('n') => {
+                    self.view_state = ViewState::Default;
+                }
+                key_code_char!('y') => {
+                    self.view_state = ViewState::Default;
+                    let keys = self.marked.iter().cloned().collect();
+                    let bucket_name = self.key.bucket_name.clone();
+                    self.tx
+                        .send(AppEventType::DuplicatesDeleteObjects(bucket_name, keys));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+
+        let summary = Paragraph::new(self.summary().fg(SUMMARY_TEXT_COLOR))
+            .block(Block::default().padding(Padding::horizontal(1)));
+        f.render_widget(summary, chunks[0]);
+
+        let list_area = chunks[1];
+        let show_item_count = (list_area.height as usize) - 2 /* border */;
+        let prefix_len = self.key.prefix().len();
+
+        let list_items = match self.opened_group {
+            None => {
+                self.group_list_state.fit_height(show_item_count);
+                let offset = self.group_list_state.offset;
+                let selected = self.group_list_state.selected;
+                self.groups
+                    .iter()
+                    .enumerate()
+                    .skip(offset)
+                    .take(show_item_count)
+                    .map(|(i, group)| {
+                        let marked = group.objects.iter().any(|o| self.marked.contains(&o.key));
+                        build_group_item(group, prefix_len, i == selected, marked)
+                    })
+                    .collect()
+            }
+            Some(group_index) => {
+                self.object_list_state.fit_height(show_item_count);
+                let offset = self.object_list_state.offset;
+                let selected = self.object_list_state.selected;
+                self.groups[group_index]
+                    .objects
+                    .iter()
+                    .enumerate()
+                    .skip(offset)
+                    .take(show_item_count)
+                    .map(|(i, obj)| {
+                        let marked = self.marked.contains(&obj.key);
+                        let name = &obj.key[prefix_len..];
+                        let line = format!(" {}  {}", format_datetime(&obj.last_modified), name);
+                        build_item(line, i == selected, marked)
+                    })
+                    .collect()
+            }
+        };
+
+        let list = ScrollList::new(list_items);
+        let list_state = match self.opened_group {
+            None => &mut self.group_list_state,
+            Some(_) => &mut self.object_list_state,
+        };
+        f.render_stateful_widget(list, list_area, list_state);
+
+        if let ViewState::DeleteConfirmDialog = self.view_state {
+            let message = format!("Delete {} marked objects?", self.marked.len());
+            let confirm_dialog = ConfirmDialog::new(message);
+            f.render_widget(confirm_dialog, area);
+        }
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = match (&self.view_state, self.opened_group) {
+            (ViewState::Default, None) => &[
+                (&["Esc", "Ctrl-c"], "Quit app / Clear marks"),
+                (&["j/k"], "Select group"),
+                (&["g/G"], "Go to top/bottom"),
+                (&["f"], "Scroll page forward"),
+                (&["b"], "Scroll page backward"),
+                (&["Enter"], "Show objects in group"),
+                (&["*"], "Mark all copies except the oldest"),
+                (&["D"], "Delete marked objects"),
+                (&["Backspace"], "Close duplicates"),
+            ],
+            (ViewState::Default, Some(_)) => &[
+                (&["Esc", "Ctrl-c"], "Quit app / Clear marks"),
+                (&["j/k"], "Select object"),
+                (&["g/G"], "Go to top/bottom"),
+                (&["f"], "Scroll page forward"),
+                (&["b"], "Scroll page backward"),
+                (&["Space"], "Mark/unmark object"),
+                (&["K"], "Keep selected object and mark the others"),
+                (&["*"], "Mark all copies except the oldest"),
+                (&["D"], "Delete marked objects"),
+                (&["Backspace"], "Go back to groups"),
+            ],
+            (ViewState::DeleteConfirmDialog, _) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "n"], "Cancel"),
+                (&["y"], "Delete marked objects"),
+            ],
+        };
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = match (&self.view_state, self.opened_group) {
+            (ViewState::Default, None) => &[
+                (&["Esc"], "Quit", 0),
+                (&["j/k"], "Select", 3),
+                (&["Enter"], "Open", 1),
+                (&["*"], "Mark copies", 2),
+                (&["D"], "Delete", 2),
+                (&["?"], "Help", 0),
+            ],
+            (ViewState::Default, Some(_)) => &[
+                (&["Esc"], "Quit", 0),
+                (&["j/k"], "Select", 3),
+                (&["Space"], "Mark", 1),
+                (&["K"], "Keep", 1),
+                (&["D"], "Delete", 2),
+                (&["Backspace"], "Go back", 2),
+                (&["?"], "Help", 0),
+            ],
+            (ViewState::DeleteConfirmDialog, _) => {
+                &[(&["Esc"], "Cancel", 2), (&["y"], "Delete", 1)]
+            }
+        };
+        build_short_helps(helps)
+    }
+
+    pub fn key(&self) -> &ObjectKey {
+        &self.key
+    }
+
+    // called after the objects are deleted
+    pub fn remove_objects(&mut self, keys: &[String]) {
+        for group in &mut self.groups {
+            group.objects.retain(|o| !keys.contains(&o.key));
+        }
+        self.groups.retain(|g| g.objects.len() > 1);
+        self.marked.retain(|k| !keys.contains(k));
+
+        self.opened_group = None;
+        let selected = self.group_list_state.selected;
+        self.group_list_state = ScrollListState::new(self.groups.len());
+        if !self.groups.is_empty() {
+            self.group_list_state
+                .select_index(selected.min(self.groups.len() - 1));
+        }
+    }
+
+    fn summary(&self) -> String {
+        match self.opened_group {
+            None => {
+                let wasted: usize = self.groups.iter().map(|g| g.wasted_bytes()).sum();
+                format!(
+                    "{}  {} groups, {} freeable, {} marked",
+                    self.key.uri(false),
+                    self.groups.len(),
+                    format_size_byte(wasted),
+                    self.marked.len(),
+                )
+            }
+            Some(i) => {
+                let group = &self.groups[i];
+                let multipart = if group.is_multipart() {
+                    " (multipart)"
+                } else {
+                    ""
+                };
+                format!(
+                    "ETag: {}{}, {} each",
+                    group.e_tag,
+                    multipart,
+                    format_size_byte(group.size_byte),
+                )
+            }
+        }
+    }
+
+    fn open_group(&mut self) {
+        let i = self.group_list_state.selected;
+        self.opened_group = Some(i);
+        self.object_list_state = ScrollListState::new(self.groups[i].objects.len());
+    }
+
+    fn toggle_mark(&mut self) {
+        let key = self.selected_object_key();
+        if !self.marked.remove(&key) {
+            self.marked.insert(key);
+        }
+        self.object_list_state.select_next();
+    }
+
+    fn keep_selected(&mut self) {
+        let Some(i) = self.opened_group else {
+            return;
+        };
+        let keep = self.selected_object_key();
+        for obj in &self.groups[i].objects {
+            if obj.key == keep {
+                self.marked.remove(&obj.key);
+            } else {
+                self.marked.insert(obj.key.clone());
+            }
+        }
+    }
+
+    fn mark_all_but_oldest(&mut self) {
+        let groups = match self.opened_group {
+            Some(i) => &self.groups[i..=i],
+            None => &self.groups[..],
+        };
+        for group in groups {
+            self.marked.remove(&group.objects[0].key);
+            for obj in &group.objects[1..] {
+                self.marked.insert(obj.key.clone());
+            }
+        }
+    }
+
+    fn open_delete_confirm_dialog(&mut self) {
+        // never delete all copies by mistake
+        let all_marked = self
+            .groups
+            .iter()
+            .any(|g| g.objects.iter().all(|o| self.marked.contains(&o.key)));
+        if all_marked {
+            let msg = "Keep at least one copy in each group".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        self.view_state = ViewState::DeleteConfirmDialog;
+    }
+
+    fn selected_object_key(&self) -> String {
+        let group = &self.groups[self.opened_group.unwrap()];
+        group.objects[self.object_list_state.selected].key.clone()
+    }
+
+    fn current_list_state(&mut self) -> &mut ScrollListState {
+        match self.opened_group {
+            None => &mut self.group_list_state,
+            Some(_) => &mut self.object_list_state,
+        }
+    }
+
+    fn non_empty(&self) -> bool {
+        !self.groups.is_empty()
+    }
+}
+
+fn build_group_item(
+    group: &DuplicateGroup,
+    prefix_len: usize,
+    selected: bool,
+    marked: bool,
+) -> ListItem<'_> {
+    let line = format!(
+        " {:>10}  {} copies  {}",
+        format_size_byte(group.size_byte),
+        group.objects.len(),
+        &group.objects[0].key[prefix_len..],
+    );
+    build_item(line, selected, marked)
+}
+
+fn build_item(line: String, selected: bool, marked: bool) -> ListItem<'static> {
+    let line = if marked {
+        // replace the leading space with the mark
+        format!("*{}", &line[1..])
+    } else {
+        line
+    };
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else if marked {
+        Style::default().fg(MARKED_ITEM_TEXT_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(Line::from(line)).style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event, object::ObjectSummary, set_cells};
+
+    use super::*;
+    use chrono::{Local, TimeZone};
+    use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+
+    fn group(size_byte: usize, e_tag: &str, keys: &[&str]) -> DuplicateGroup {
+        let objects = keys
+            .iter()
+            .map(|key| ObjectSummary {
+                key: key.to_string(),
+                size_byte,
+                last_modified: Local.with_ymd_and_hms(2024, 1, 2, 13, 4, 5).unwrap(),
+                e_tag: e_tag.to_string(),
+                storage_class: "STANDARD".to_string(),
+            })
+            .collect();
+        DuplicateGroup {
+            size_byte,
+            e_tag: e_tag.to_string(),
+            objects,
+        }
+    }
+
+    #[test]
+    fn test_render() -> std::io::Result<()> {
+        let (tx, _rx) = event::new();
+        let backend = TestBackend::new(60, 7);
+        let mut terminal = Terminal::new(backend)?;
+
+        let key = ObjectKey {
+            bucket_name: "bucket-1".to_string(),
+            object_path: vec!["p".to_string()],
+        };
+        let groups = vec![
+            group(2048, "aaa-2", &["p/a/1.bin", "p/b/1.bin", "p/c/1.bin"]),
+            group(100, "bbb", &["p/x.txt", "p/y.txt"]),
+        ];
+        let mut page = DuplicatesPage::new(key, groups, tx);
+        terminal.draw(|f| page.render(f, f.size()))?;
+
+        // mark the copies of the second group
+        page.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        page.handle_key(KeyEvent::from(KeyCode::Enter));
+        page.handle_key(KeyEvent::from(KeyCode::Char('*')));
+        page.handle_key(KeyEvent::from(KeyCode::Backspace));
+
+        terminal.draw(|f| page.render(f, f.size()))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            " s3://bucket-1/p/  2 groups, 4.10 KiB freeable, 1 marked    ",
+            "┌─────────────────────────────────────────────────── 2 / 2 ┐",
+            "│       2 KiB  3 copies  a/1.bin                           │",
+            "│ *     100 B  2 copies  x.txt                             │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // summary
+            (1..56, [0]) => fg: Color::DarkGray,
+            // marked group
+            (2..58, [3]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+}
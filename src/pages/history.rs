@@ -0,0 +1,180 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::ListItem,
+    Frame,
+};
+
+use crate::{
+    event::{AppEventType, Sender},
+    history::VisitedLocation,
+    key_code, key_code_char,
+    pages::util::{build_helps, build_short_helps},
+    widget::{ScrollList, ScrollListState},
+};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
+
+#[derive(Debug)]
+pub struct HistoryPage {
+    locations: Vec<VisitedLocation>,
+
+    list_state: ScrollListState,
+    tx: Sender,
+}
+
+impl HistoryPage {
+    pub fn new(locations: Vec<VisitedLocation>, tx: Sender) -> Self {
+        let items_len = locations.len();
+        Self {
+            locations,
+            list_state: ScrollListState::new(items_len),
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key {
+            key_code!(KeyCode::Esc) => {
+                self.tx.send(AppEventType::Quit);
+            }
+            key_code!(KeyCode::Backspace) | key_code_char!('r', Ctrl) => {
+                self.tx.send(AppEventType::CloseCurrentPage);
+            }
+            key_code!(KeyCode::Enter) if self.non_empty() => {
+                let location = &self.locations[self.list_state.selected];
+                self.tx
+                    .send(AppEventType::HistoryMoveTo(location.location()));
+            }
+            key_code_char!('j') if self.non_empty() => {
+                self.list_state.select_next();
+            }
+            key_code_char!('k') if self.non_empty() => {
+                self.list_state.select_prev();
+            }
+            key_code_char!('g') if self.non_empty() => {
+                self.list_state.select_first();
+            }
+            key_code_char!('G') if self.non_empty() => {
+                self.list_state.select_last();
+            }
+            key_code_char!('?') => {
+                self.tx.send(AppEventType::OpenHelp);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        self.list_state
+            .fit_height(area.height as usize - 2 /* border */);
+        let offset = self.list_state.offset;
+        let selected = self.list_state.selected;
+
+        let show_item_count = (area.height as usize) - 2 /* border */;
+        let list_items = self
+            .locations
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(show_item_count)
+            .map(|(i, location)| build_list_item(location, i == selected))
+            .collect();
+
+        let list = ScrollList::new(list_items);
+        f.render_stateful_widget(list, area, &mut self.list_state);
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = &[
+            (&["Esc", "Ctrl-c"], "Quit app"),
+            (&["j/k"], "Select item"),
+            (&["g/G"], "Go to top/bottom"),
+            (&["Enter"], "Go to selected location"),
+            (&["Backspace", "Ctrl-r"], "Close recent locations"),
+        ];
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = &[
+            (&["Esc"], "Quit", 0),
+            (&["j/k"], "Select", 3),
+            (&["Enter"], "Go", 1),
+            (&["Backspace"], "Close", 2),
+            (&["?"], "Help", 0),
+        ];
+        build_short_helps(helps)
+    }
+
+    fn non_empty(&self) -> bool {
+        !self.locations.is_empty()
+    }
+}
+
+fn build_list_item(location: &VisitedLocation, selected: bool) -> ListItem<'_> {
+    let line = Line::from(vec![" ".into(), location.uri().into(), " ".into()]);
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event, object::ObjectKey, set_cells};
+
+    use super::*;
+    use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+
+    #[test]
+    fn test_render() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let backend = TestBackend::new(34, 5);
+        let mut terminal = Terminal::new(backend)?;
+
+        let locations = vec![
+            VisitedLocation::new(
+                ObjectKey {
+                    bucket_name: "bucket-1".to_string(),
+                    object_path: vec!["a".to_string(), "b.txt".to_string()],
+                },
+                true,
+            ),
+            VisitedLocation::new(
+                ObjectKey {
+                    bucket_name: "bucket-1".to_string(),
+                    object_path: vec!["a".to_string()],
+                },
+                false,
+            ),
+        ];
+        let mut page = HistoryPage::new(locations, tx);
+
+        terminal.draw(|f| page.render(f, f.size()))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────────── 1 / 2 ┐",
+            "│  s3://bucket-1/a/b.txt         │",
+            "│  s3://bucket-1/a/              │",
+            "│                                │",
+            "└────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..32, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+}
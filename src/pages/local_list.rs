@@ -0,0 +1,254 @@
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{layout::Rect, Frame};
+
+use crate::{
+    config::ListConfig,
+    event::{AppEventType, Sender},
+    file::read_local_dir,
+    filter::ListFilter,
+    key_code, key_code_char,
+    object::ObjectItem,
+    pages::{
+        object_list::{build_list_item, ColumnLayout},
+        util::{build_helps, build_short_helps},
+    },
+    widget::{ScrollList, ScrollListState},
+};
+
+#[derive(Debug)]
+pub struct LocalListPage {
+    path: PathBuf,
+    items: Vec<ObjectItem>,
+
+    list_state: ScrollListState,
+    tx: Sender,
+}
+
+impl LocalListPage {
+    pub fn new(path: PathBuf, items: Vec<ObjectItem>, tx: Sender) -> Self {
+        let items_len = items.len();
+        Self {
+            path,
+            items,
+            list_state: ScrollListState::new(items_len),
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key {
+            key_code!(KeyCode::Esc) => {
+                self.tx.send(AppEventType::Quit);
+            }
+            key_code!(KeyCode::Enter) if self.non_empty() => {
+                self.move_down();
+            }
+            key_code!(KeyCode::Backspace) => {
+                self.move_up();
+            }
+            key_code_char!('j') if self.non_empty() => {
+                self.list_state.select_next();
+            }
+            key_code_char!('k') if self.non_empty() => {
+                self.list_state.select_prev();
+            }
+            key_code_char!('g') if self.non_empty() => {
+                self.list_state.select_first();
+            }
+            key_code_char!('G') if self.non_empty() => {
+                self.list_state.select_last();
+            }
+            key_code_char!('f') if self.non_empty() => {
+                self.list_state.select_next_page();
+            }
+            key_code_char!('b') if self.non_empty() => {
+                self.list_state.select_prev_page();
+            }
+            key_code_char!('?') => {
+                self.tx.send(AppEventType::OpenHelp);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        self.list_state
+            .fit_height(area.height as usize - 2 /* border */);
+        let offset = self.list_state.offset;
+        let selected = self.list_state.selected;
+
+        let show_item_count = (area.height as usize) - 2 /* border */;
+        let layout = ColumnLayout::new(&ListConfig::default().columns(), area.width);
+        let list_items = self
+            .items
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(show_item_count)
+            .map(|(i, item)| {
+                build_list_item(
+                    item,
+                    i == selected,
+                    false,
+                    None,
+                    None,
+                    &ListFilter::default(),
+                    &layout,
+                )
+            })
+            .collect();
+
+        let list = ScrollList::new(list_items);
+        f.render_stateful_widget(list, area, &mut self.list_state);
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = &[
+            (&["Esc", "Ctrl-c"], "Quit app"),
+            (&["j/k"], "Select item"),
+            (&["g/G"], "Go to top/bottom"),
+            (&["f"], "Scroll page forward"),
+            (&["b"], "Scroll page backward"),
+            (&["Enter"], "Open directory"),
+            (&["Backspace"], "Go back to parent directory"),
+            (&["Ctrl-t"], "Open new tab"),
+            (&["Ctrl-d"], "Toggle dual-pane mode"),
+            (&["Ctrl-l"], "Toggle local directory"),
+            (&["Tab"], "Switch pane"),
+            (&["F5/F6/F7"], "Copy/Move/Sync to other pane"),
+        ];
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = &[
+            (&["Esc"], "Quit", 0),
+            (&["j/k"], "Select", 3),
+            (&["Enter"], "Open", 1),
+            (&["Backspace"], "Go back", 2),
+            (&["?"], "Help", 0),
+        ];
+        build_short_helps(helps)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // the selected item, as the local list has no marks
+    pub fn target_items(&self) -> Vec<ObjectItem> {
+        self.items
+            .get(self.list_state.selected)
+            .cloned()
+            .into_iter()
+            .collect()
+    }
+
+    // re-read the directory, keeping the selected item if it still exists
+    pub fn reload(&mut self) {
+        let selected = self.target_items().pop();
+        if self.read_dir(self.path.clone()) {
+            if let Some(item) = selected {
+                self.select_item(item.name());
+            }
+        }
+    }
+
+    fn move_down(&mut self) {
+        if let Some(ObjectItem::Dir { name }) = self.target_items().pop() {
+            self.read_dir(self.path.join(name));
+        }
+    }
+
+    fn move_up(&mut self) {
+        let Some(parent) = self.path.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        let name = self
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string());
+        if self.read_dir(parent) {
+            if let Some(name) = name {
+                self.select_item(&name);
+            }
+        }
+    }
+
+    fn read_dir(&mut self, path: PathBuf) -> bool {
+        match read_local_dir(&path) {
+            Ok(items) => {
+                self.list_state = ScrollListState::new(items.len());
+                self.items = items;
+                self.path = path;
+                true
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+                false
+            }
+        }
+    }
+
+    fn select_item(&mut self, name: &str) {
+        if let Some(i) = self.items.iter().position(|item| item.name() == name) {
+            self.list_state.select_index(i);
+        }
+    }
+
+    fn non_empty(&self) -> bool {
+        !self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event, set_cells};
+
+    use super::*;
+    use ratatui::{
+        backend::TestBackend,
+        buffer::Buffer,
+        style::{Color, Modifier},
+        Terminal,
+    };
+
+    #[test]
+    fn test_render() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let backend = TestBackend::new(40, 6);
+        let mut terminal = Terminal::new(backend)?;
+
+        let dir = tempfile::tempdir()?;
+        std::fs::create_dir(dir.path().join("dir1"))?;
+        std::fs::write(dir.path().join("file1"), [0; 1034])?;
+        std::fs::write(dir.path().join("file2"), [])?;
+        let items = read_local_dir(dir.path()).unwrap();
+        let mut page = LocalListPage::new(dir.path().to_path_buf(), items, tx);
+
+        terminal.draw(|f| page.render(f, f.size()))?;
+
+        // the date is not shown in a narrow list
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────── 1 / 3 ┐",
+            "│  dir1/                               │",
+            "│  file1                     1.01 KiB  │",
+            "│  file2                          0 B  │",
+            "│                                      │",
+            "└──────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..38, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+}
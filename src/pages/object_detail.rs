@@ -79,6 +79,17 @@ impl ObjectDetailPage {
         }
     }
 
+    pub fn file_detail(&self) -> &FileDetail {
+        &self.file_detail
+    }
+
+    pub fn update_object(&mut self, file_detail: FileDetail, file_versions: Vec<FileVersion>) {
+        self.detail_tab_state = DetailTabState::new(&file_detail);
+        self.version_tab_state = VersionTabState::new(&file_versions);
+        self.file_detail = file_detail;
+        self.file_versions = file_versions;
+    }
+
     pub fn handle_key(&mut self, key: KeyEvent) {
         match self.view_state {
             ViewState::Default => match key {
@@ -117,6 +128,15 @@ impl ObjectDetailPage {
                         self.version_tab_state.select_last();
                     }
                 }
+                key_code_char!('H') => {
+                    self.tx.send(AppEventType::HistoryBack);
+                }
+                key_code_char!('L') => {
+                    self.tx.send(AppEventType::HistoryForward);
+                }
+                key_code_char!('r', Ctrl) => {
+                    self.tx.send(AppEventType::OpenHistory);
+                }
                 key_code_char!('s') => {
                     self.download();
                 }
@@ -126,12 +146,24 @@ impl ObjectDetailPage {
                 key_code_char!('p') => {
                     self.preview();
                 }
+                key_code_char!('e') => {
+                    self.edit();
+                }
+                key_code_char!('o') => {
+                    self.open();
+                }
                 key_code_char!('r') => {
                     self.open_copy_detail_dialog();
                 }
                 key_code_char!('x') => {
                     self.open_management_console();
                 }
+                key_code_char!('m') => {
+                    self.tx.send(AppEventType::AddBookmark);
+                }
+                key_code_char!('\'') => {
+                    self.tx.send(AppEventType::OpenBookmarks);
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -177,6 +209,8 @@ impl ObjectDetailPage {
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
         let chunks = Layout::horizontal(Constraint::from_percentages([50, 50])).split(area);
 
+        self.list_state
+            .fit_height(chunks[0].height as usize - 2 /* border */);
         let offset = self.list_state.offset;
         let selected = self.list_state.selected;
 
@@ -221,6 +255,10 @@ impl ObjectDetailPage {
         }
     }
 
+    pub fn is_default_view(&self) -> bool {
+        matches!(self.view_state, ViewState::Default)
+    }
+
     pub fn helps(&self) -> Vec<String> {
         let helps: &[(&[&str], &str)] = match self.view_state {
             ViewState::Default => match self.tab {
@@ -233,7 +271,20 @@ impl ObjectDetailPage {
                     (&["s"], "Download object"),
                     (&["S"], "Download object as"),
                     (&["p"], "Preview object"),
+                    (&["e"], "Edit object in editor"),
+                    (&["o"], "Open object with command"),
                     (&["x"], "Open management console in browser"),
+                    (&["m"], "Bookmark object"),
+                    (&["'"], "Open bookmarks"),
+                    (&["H/L"], "Go back/forward in history"),
+                    (&["Ctrl-r"], "Open recent locations"),
+                    (&["Ctrl-t"], "Open new tab"),
+                    (&["Ctrl-w"], "Close current tab"),
+                    (&["gt/gT", "1-9"], "Switch tab"),
+                    (&["Ctrl-d"], "Toggle dual-pane mode"),
+                    (&["Ctrl-l"], "Toggle local directory"),
+                    (&["Tab"], "Switch pane"),
+                    (&["F5/F6/F7"], "Copy/Move/Sync to other pane"),
                 ],
                 Tab::Version => &[
                     (&["Esc", "Ctrl-c"], "Quit app"),
@@ -245,7 +296,20 @@ impl ObjectDetailPage {
                     (&["s"], "Download object"),
                     (&["S"], "Download object as"),
                     (&["p"], "Preview object"),
+                    (&["e"], "Edit latest object in editor"),
+                    (&["o"], "Open object with command"),
                     (&["x"], "Open management console in browser"),
+                    (&["m"], "Bookmark object"),
+                    (&["'"], "Open bookmarks"),
+                    (&["H/L"], "Go back/forward in history"),
+                    (&["Ctrl-r"], "Open recent locations"),
+                    (&["Ctrl-t"], "Open new tab"),
+                    (&["Ctrl-w"], "Close current tab"),
+                    (&["gt/gT", "1-9"], "Switch tab"),
+                    (&["Ctrl-d"], "Toggle dual-pane mode"),
+                    (&["Ctrl-l"], "Toggle local directory"),
+                    (&["Tab"], "Switch pane"),
+                    (&["F5/F6/F7"], "Copy/Move/Sync to other pane"),
                 ],
             },
             ViewState::SaveDialog(_) => &[
@@ -352,6 +416,19 @@ impl ObjectDetailPage {
             .send(AppEventType::OpenPreview(file_detail, version_id));
     }
 
+    fn edit(&self) {
+        // always edit the latest version
+        let file_detail = self.file_detail.clone();
+        self.tx.send(AppEventType::EditObject(file_detail));
+    }
+
+    fn open(&self) {
+        let file_detail = self.file_detail.clone();
+        let version_id = self.current_selected_version_id();
+        self.tx
+            .send(AppEventType::OpenObject(file_detail, version_id));
+    }
+
     fn open_management_console(&self) {
         let file_name = self.file_detail.name.clone();
         self.tx
@@ -374,7 +451,7 @@ fn build_list_items_from_object_items(
     offset: usize,
     selected: usize,
     area: Rect,
-) -> Vec<ListItem> {
+) -> Vec<ListItem<'_>> {
     let show_item_count = (area.height as usize) - 2 /* border */;
     current_items
         .iter()
@@ -391,7 +468,7 @@ fn build_list_item_from_object_item(
     offset: usize,
     selected: usize,
     area: Rect,
-) -> ListItem {
+) -> ListItem<'_> {
     let content = match item {
         ObjectItem::Dir { name, .. } => {
             let content = format_dir_item(name, area.width);
@@ -947,16 +1024,22 @@ mod tests {
                 name: "file1".to_string(),
                 size_byte: 1024 + 10,
                 last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
             },
             ObjectItem::File {
                 name: "file2".to_string(),
                 size_byte: 1024 * 999,
                 last_modified: parse_datetime("2023-12-31 09:00:00"),
+                e_tag: None,
+                storage_class: None,
             },
             ObjectItem::File {
                 name: "file3".to_string(),
                 size_byte: 1024,
                 last_modified: parse_datetime("2024-01-03 12:59:59"),
+                e_tag: None,
+                storage_class: None,
             },
         ];
         let file_detail = FileDetail {
@@ -0,0 +1,201 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::Block,
+    Frame,
+};
+use similar::TextDiff;
+
+use crate::{
+    event::{AppEventType, Sender},
+    key_code, key_code_char,
+    object::{FileDetail, RawObject},
+    pages::util::{build_helps, build_short_helps},
+    util::to_preview_string,
+    widget::{ScrollLines, ScrollLinesOptions, ScrollLinesState},
+};
+
+const DIFF_INSERT_COLOR: Color = Color::Green;
+const DIFF_DELETE_COLOR: Color = Color::Red;
+const DIFF_HUNK_COLOR: Color = Color::Cyan;
+const DIFF_CONTEXT_RADIUS: usize = 3;
+
+#[derive(Debug)]
+pub struct ObjectDiffPage {
+    file_detail: FileDetail,
+    e_tag: String,
+    edited: RawObject,
+
+    scroll_lines_state: ScrollLinesState,
+
+    tx: Sender,
+}
+
+impl ObjectDiffPage {
+    pub fn new(
+        file_detail: FileDetail,
+        e_tag: String,
+        original: RawObject,
+        edited: RawObject,
+        tx: Sender,
+    ) -> Self {
+        let lines = build_diff_lines(&file_detail.name, &original, &edited);
+        let scroll_lines_state = ScrollLinesState::new(lines, ScrollLinesOptions::new(false, true));
+        Self {
+            file_detail,
+            e_tag,
+            edited,
+            scroll_lines_state,
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key {
+            key_code!(KeyCode::Esc) => {
+                self.tx.send(AppEventType::Quit);
+            }
+            key_code!(KeyCode::Backspace) | key_code_char!('n') => {
+                self.tx.send(AppEventType::CloseCurrentPage);
+            }
+            key_code_char!('y') => {
+                self.upload();
+            }
+            key_code_char!('j') => {
+                self.scroll_lines_state.scroll_forward();
+            }
+            key_code_char!('k') => {
+                self.scroll_lines_state.scroll_backward();
+            }
+            key_code_char!('f') => {
+                self.scroll_lines_state.scroll_page_forward();
+            }
+            key_code_char!('b') => {
+                self.scroll_lines_state.scroll_page_backward();
+            }
+            key_code_char!('g') => {
+                self.scroll_lines_state.scroll_to_top();
+            }
+            key_code_char!('G') => {
+                self.scroll_lines_state.scroll_to_end();
+            }
+            key_code_char!('h') => {
+                self.scroll_lines_state.scroll_left();
+            }
+            key_code_char!('l') => {
+                self.scroll_lines_state.scroll_right();
+            }
+            key_code_char!('w') => {
+                self.scroll_lines_state.toggle_wrap();
+            }
+            key_code_char!('?') => {
+                self.tx.send(AppEventType::OpenHelp);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let title = format!("Diff [{}] Upload changes? (y/n)", self.file_detail.name);
+        let scroll_lines = ScrollLines::default().block(Block::bordered().title(title));
+        f.render_stateful_widget(scroll_lines, area, &mut self.scroll_lines_state);
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = &[
+            (&["Esc", "Ctrl-c"], "Quit app"),
+            (&["y"], "Upload edited object"),
+            (&["n", "Backspace"], "Discard changes"),
+            (&["j/k"], "Scroll forward/backward"),
+            (&["f/b"], "Scroll page forward/backward"),
+            (&["g/G"], "Scroll to top/end"),
+            (&["h/l"], "Scroll left/right"),
+            (&["w"], "Toggle wrap"),
+        ];
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = &[
+            (&["Esc"], "Quit", 0),
+            (&["y"], "Upload", 1),
+            (&["n"], "Discard", 1),
+            (&["j/k"], "Scroll", 2),
+            (&["?"], "Help", 0),
+        ];
+        build_short_helps(helps)
+    }
+}
+
+impl ObjectDiffPage {
+    fn upload(&self) {
+        let file_detail = self.file_detail.clone();
+        let e_tag = self.e_tag.clone();
+        let edited = self.edited.clone();
+        self.tx
+            .send(AppEventType::UploadEditedObject(file_detail, e_tag, edited));
+    }
+}
+
+fn build_diff_lines(name: &str, original: &RawObject, edited: &RawObject) -> Vec<Line<'static>> {
+    let original = to_preview_string(&original.bytes);
+    let edited = to_preview_string(&edited.bytes);
+
+    let diff = TextDiff::from_lines(&original, &edited);
+    let unified = diff
+        .unified_diff()
+        .context_radius(DIFF_CONTEXT_RADIUS)
+        .header(&format!("a/{}", name), &format!("b/{}", name))
+        .to_string();
+
+    unified
+        .lines()
+        .map(|line| {
+            let line = line.to_string();
+            if line.starts_with("+++") || line.starts_with("---") {
+                Line::from(line.bold())
+            } else if line.starts_with('+') {
+                Line::from(line.fg(DIFF_INSERT_COLOR))
+            } else if line.starts_with('-') {
+                Line::from(line.fg(DIFF_DELETE_COLOR))
+            } else if line.starts_with("@@") {
+                Line::from(line.fg(DIFF_HUNK_COLOR))
+            } else {
+                Line::raw(line)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(s: &str) -> RawObject {
+        RawObject {
+            bytes: s.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_build_diff_lines() {
+        let original = object("{\n  \"a\": 1,\n  \"b\": 2\n}\n");
+        let edited = object("{\n  \"a\": 1,\n  \"b\": 3\n}\n");
+
+        let actual = build_diff_lines("config.json", &original, &edited);
+
+        let expected = vec![
+            Line::from("--- a/config.json".bold()),
+            Line::from("+++ b/config.json".bold()),
+            Line::from("@@ -1,4 +1,4 @@".fg(DIFF_HUNK_COLOR)),
+            Line::raw(" {"),
+            Line::raw("   \"a\": 1,"),
+            Line::from("-  \"b\": 2".fg(DIFF_DELETE_COLOR)),
+            Line::from("+  \"b\": 3".fg(DIFF_INSERT_COLOR)),
+            Line::raw(" }"),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,42 +1,76 @@
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 
-use chrono::{DateTime, Local};
+use chrono::Local;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Rect,
     style::{Color, Style, Stylize},
-    text::Line,
+    text::{Line, Span},
     widgets::ListItem,
     Frame,
 };
 
 use crate::{
+    config::{ListColumn, ListConfig},
     event::{AppEventType, Sender},
+    file::paste_from_clipboard,
+    filter::{split_matches, FilterOptions, ListFilter, ObjectFilter},
     key_code, key_code_char,
-    object::ObjectItem,
+    object::{ObjectItem, PrefixSize},
     pages::util::{build_helps, build_short_helps},
-    ui::common::{format_datetime, format_size_byte},
-    util::split_str,
+    sort::{extension, natural_cmp, ObjectListSort},
+    ui::common::{format_datetime, format_object_count, format_size_byte},
     widget::{
-        InputDialog, InputDialogState, ObjectListSortDialog, ObjectListSortDialogState,
-        ObjectListSortType, ScrollList, ScrollListState,
+        ConfirmDialog, FinderDialog, FinderDialogState, InputDialog, InputDialogState,
+        ObjectListSortDialog, ObjectListSortDialogState, ObjectListSortType, ScrollList,
+        ScrollListState, StorageClassDialog, StorageClassDialogState,
     },
 };
 
 const SELECTED_COLOR: Color = Color::Cyan;
 const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
 const HIGHLIGHTED_ITEM_TEXT_COLOR: Color = Color::Red;
+const MARKED_ITEM_TEXT_COLOR: Color = Color::Yellow;
+
+const MIN_NAME_WIDTH: usize = 10;
+const DATE_WIDTH: usize = 19;
+const SIZE_WIDTH: usize = 10;
+const STORAGE_CLASS_WIDTH: usize = 19;
+const E_TAG_WIDTH: usize = 36;
+const CONTENT_TYPE_WIDTH: usize = 24;
 
 #[derive(Debug)]
 pub struct ObjectListPage {
     object_items: Vec<ObjectItem>,
     view_indices: Vec<usize>,
+    // kept while the recursive items are listed
+    hierarchical_items: Option<Vec<ObjectItem>>,
+    // indices of object_items, kept across filtering and sorting
+    marked: HashSet<usize>,
+    // view index where the visual mode started
+    visual_anchor: Option<usize>,
+    // by the name of the directory, None while calculating
+    prefix_sizes: HashMap<String, Option<PrefixSize>>,
+    // by the name of the file, None while loading
+    content_types: HashMap<String, Option<String>>,
+    // the names of the shown files whose content types are not requested to the app yet
+    content_type_requests: Vec<String>,
+    columns: Vec<ListColumn>,
 
     view_state: ViewState,
 
     list_state: ScrollListState,
     filter_input_state: InputDialogState,
+    filter_options: FilterOptions,
+    filter: ObjectFilter,
+    go_to_input_state: InputDialogState,
     sort_dialog_state: ObjectListSortDialogState,
+    finder_state: FinderDialogState,
+    copy_input_state: InputDialogState,
+    storage_class_dialog_state: StorageClassDialogState,
     tx: Sender,
 }
 
@@ -45,6 +79,11 @@ enum ViewState {
     Default,
     FilterDialog,
     SortDialog,
+    GoToDialog,
+    FinderDialog,
+    DeleteConfirmDialog,
+    CopyDialog,
+    StorageClassDialog,
 }
 
 impl ObjectListPage {
@@ -54,10 +93,23 @@ impl ObjectListPage {
         Self {
             object_items,
             view_indices,
+            hierarchical_items: None,
+            marked: HashSet::new(),
+            visual_anchor: None,
+            prefix_sizes: HashMap::new(),
+            content_types: HashMap::new(),
+            content_type_requests: Vec::new(),
+            columns: ListConfig::default().columns(),
             view_state: ViewState::Default,
             list_state: ScrollListState::new(items_len),
             filter_input_state: InputDialogState::default(),
+            filter_options: FilterOptions::default(),
+            filter: ObjectFilter::default(),
+            go_to_input_state: InputDialogState::default(),
             sort_dialog_state: ObjectListSortDialogState::default(),
+            finder_state: FinderDialogState::default(),
+            copy_input_state: InputDialogState::default(),
+            storage_class_dialog_state: StorageClassDialogState::default(),
             tx,
         }
     }
@@ -66,7 +118,11 @@ impl ObjectListPage {
         match self.view_state {
             ViewState::Default => match key {
                 key_code!(KeyCode::Esc) => {
-                    if self.filter_input_state.input().is_empty() {
+                    if self.visual_anchor.is_some() {
+                        self.visual_anchor = None;
+                    } else if !self.marked.is_empty() {
+                        self.marked.clear();
+                    } else if self.filter_input_state.input().is_empty() {
                         self.tx.send(AppEventType::Quit);
                     } else {
                         self.reset_filter();
@@ -75,6 +131,27 @@ impl ObjectListPage {
                 key_code!(KeyCode::Enter) if self.non_empty() => {
                     self.tx.send(AppEventType::ObjectListMoveDown);
                 }
+                key_code_char!(' ') if self.non_empty() => {
+                    self.toggle_mark();
+                }
+                key_code_char!('v') if self.non_empty() => {
+                    self.toggle_visual_mode();
+                }
+                key_code_char!('*') if self.non_empty() => {
+                    self.toggle_mark_all();
+                }
+                key_code_char!('s') if self.non_empty() => {
+                    self.download_targets();
+                }
+                key_code_char!('D') if self.non_empty() => {
+                    self.open_delete_confirm_dialog();
+                }
+                key_code_char!('c') if self.non_empty() => {
+                    self.open_copy_dialog();
+                }
+                key_code_char!('t') if self.non_empty() => {
+                    self.open_storage_class_dialog();
+                }
                 key_code!(KeyCode::Backspace) => {
                     self.tx.send(AppEventType::ObjectListMoveUp);
                 }
@@ -90,6 +167,9 @@ impl ObjectListPage {
                 key_code_char!('G') if self.non_empty() => {
                     self.select_last();
                 }
+                key_code_char!('f', Ctrl) => {
+                    self.open_prefix_search_dialog();
+                }
                 key_code_char!('f') if self.non_empty() => {
                     self.select_next_page();
                 }
@@ -108,6 +188,42 @@ impl ObjectListPage {
                 key_code_char!('o') => {
                     self.open_sort_dialog();
                 }
+                key_code_char!(':') => {
+                    self.open_go_to_dialog();
+                }
+                key_code_char!('m') => {
+                    self.tx.send(AppEventType::AddBookmark);
+                }
+                key_code_char!('\'') => {
+                    self.tx.send(AppEventType::OpenBookmarks);
+                }
+                key_code_char!('H') => {
+                    self.tx.send(AppEventType::HistoryBack);
+                }
+                key_code_char!('L') => {
+                    self.tx.send(AppEventType::HistoryForward);
+                }
+                key_code_char!('r', Ctrl) => {
+                    self.tx.send(AppEventType::OpenHistory);
+                }
+                key_code_char!('R') => {
+                    self.toggle_recursive();
+                }
+                key_code_char!('z') if self.non_empty() => {
+                    self.calculate_selected_prefix_size();
+                }
+                key_code_char!('Z') if self.non_empty() => {
+                    self.calculate_all_prefix_sizes();
+                }
+                key_code_char!('U') => {
+                    self.tx.send(AppEventType::OpenDiskUsage);
+                }
+                key_code_char!('P') => {
+                    self.tx.send(AppEventType::OpenDuplicates);
+                }
+                key_code_char!('p', Ctrl) => {
+                    self.open_finder_dialog();
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -120,6 +236,14 @@ impl ObjectListPage {
                 key_code!(KeyCode::Enter) => {
                     self.apply_filter();
                 }
+                key_code!(KeyCode::Tab) => {
+                    self.filter_options.mode = self.filter_options.mode.next();
+                    self.filter_view_indices();
+                }
+                key_code_char!('t', Ctrl) => {
+                    self.filter_options.ignore_case = !self.filter_options.ignore_case;
+                    self.filter_view_indices();
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -138,6 +262,9 @@ impl ObjectListPage {
                 key_code_char!('k') => {
                     self.select_prev_sort_item();
                 }
+                key_code_char!('d') => {
+                    self.toggle_sort_dirs_first();
+                }
                 key_code!(KeyCode::Enter) => {
                     self.apply_sort();
                 }
@@ -146,27 +273,112 @@ impl ObjectListPage {
                 }
                 _ => {}
             },
+            ViewState::GoToDialog => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_go_to_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.apply_go_to();
+                }
+                key_code_char!('v', Ctrl) => {
+                    self.paste_to_go_to_dialog();
+                }
+                _ => {
+                    self.go_to_input_state.handle_key_event(key);
+                }
+            },
+            ViewState::FinderDialog => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_finder_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.apply_finder();
+                }
+                key_code!(KeyCode::Down) | key_code_char!('n', Ctrl) => {
+                    self.finder_state.select_next();
+                }
+                key_code!(KeyCode::Up) | key_code_char!('p', Ctrl) => {
+                    self.finder_state.select_prev();
+                }
+                _ => {
+                    self.finder_state.handle_key_event(key);
+                    if let Some(query) = self.finder_state.prefix_search_query() {
+                        self.finder_state.start_prefix_loading(&query);
+                        self.tx.send(AppEventType::SearchFinderItems(query));
+                    }
+                }
+            },
+            ViewState::DeleteConfirmDialog => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('n') => {
+                    self.view_state = ViewState::Default;
+                }
+                key_code_char!('y') => {
+                    self.apply_delete();
+                }
+                _ => {}
+            },
+            ViewState::CopyDialog => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_copy_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.apply_copy();
+                }
+                key_code_char!('v', Ctrl) => {
+                    self.paste_to_copy_dialog();
+                }
+                _ => {
+                    self.copy_input_state.handle_key_event(key);
+                }
+            },
+            ViewState::StorageClassDialog => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_storage_class_dialog();
+                }
+                key_code_char!('j') => {
+                    self.storage_class_dialog_state.select_next();
+                }
+                key_code_char!('k') => {
+                    self.storage_class_dialog_state.select_prev();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.apply_storage_class();
+                }
+                _ => {}
+            },
         }
     }
 
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
-        let offset = self.list_state.offset;
-        let selected = self.list_state.selected;
+        self.list_state
+            .fit_height(area.height as usize - 2 /* border */);
 
+        let show_item_count = area.height as usize - 2 /* border */;
+        let layout = ColumnLayout::new(&self.columns, area.width);
+        if layout.contains(ListColumn::ContentType) {
+            self.request_content_types(show_item_count);
+        }
+
+        let marked = self.marked_indices();
         let list_items = build_list_items(
             &self.object_items,
             &self.view_indices,
-            self.filter_input_state.input(),
-            offset,
-            selected,
-            area,
+            &marked,
+            &self.prefix_sizes,
+            &self.content_types,
+            self.filter.name_filter(),
+            &layout,
+            self.list_state,
+            show_item_count,
         );
 
         let list = ScrollList::new(list_items);
         f.render_stateful_widget(list, area, &mut self.list_state);
 
         if let ViewState::FilterDialog = self.view_state {
-            let filter_dialog = InputDialog::default().title("Filter").max_width(30);
+            let filter_dialog = InputDialog::default()
+                .title(self.filter_options.dialog_title())
+                .max_width(40);
             f.render_stateful_widget(filter_dialog, area, &mut self.filter_input_state);
 
             let (cursor_x, cursor_y) = self.filter_input_state.cursor();
@@ -177,6 +389,49 @@ impl ObjectListPage {
             let sort_dialog = ObjectListSortDialog::new(self.sort_dialog_state);
             f.render_widget(sort_dialog, area);
         }
+
+        if let ViewState::GoToDialog = self.view_state {
+            let go_to_dialog = InputDialog::default().title("Go to");
+            f.render_stateful_widget(go_to_dialog, area, &mut self.go_to_input_state);
+
+            let (cursor_x, cursor_y) = self.go_to_input_state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
+
+        if let ViewState::FinderDialog = self.view_state {
+            let title = if self.finder_state.is_prefix_search() {
+                "Search (key prefix)"
+            } else {
+                "Find"
+            };
+            let finder_dialog = FinderDialog::default().title(title);
+            f.render_stateful_widget(finder_dialog, area, &mut self.finder_state);
+
+            let (cursor_x, cursor_y) = self.finder_state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
+
+        if let ViewState::DeleteConfirmDialog = self.view_state {
+            let confirm_dialog = ConfirmDialog::new(self.delete_confirm_message());
+            f.render_widget(confirm_dialog, area);
+        }
+
+        if let ViewState::CopyDialog = self.view_state {
+            let copy_dialog = InputDialog::default().title("Copy to");
+            f.render_stateful_widget(copy_dialog, area, &mut self.copy_input_state);
+
+            let (cursor_x, cursor_y) = self.copy_input_state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
+
+        if let ViewState::StorageClassDialog = self.view_state {
+            let storage_class_dialog = StorageClassDialog::new(self.storage_class_dialog_state);
+            f.render_widget(storage_class_dialog, area);
+        }
+    }
+
+    pub fn is_default_view(&self) -> bool {
+        matches!(self.view_state, ViewState::Default)
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -194,6 +449,32 @@ impl ObjectListPage {
                         (&["~"], "Go back to bucket list"),
                         (&["/"], "Filter object list"),
                         (&["o"], "Sort object list"),
+                        (&[":"], "Go to path or S3 URI"),
+                        (&["m"], "Bookmark current prefix"),
+                        (&["'"], "Open bookmarks"),
+                        (&["H/L"], "Go back/forward in history"),
+                        (&["Ctrl-r"], "Open recent locations"),
+                        (&["Ctrl-t"], "Open new tab"),
+                        (&["Ctrl-w"], "Close current tab"),
+                        (&["gt/gT", "1-9"], "Switch tab"),
+                        (&["Ctrl-d"], "Toggle dual-pane mode"),
+                        (&["Ctrl-l"], "Toggle local directory"),
+                        (&["Tab"], "Switch pane"),
+                        (&["F5/F6/F7"], "Copy/Move/Sync to other pane"),
+                        (&["R"], "Toggle recursive listing"),
+                        (&["z"], "Calculate size of selected folder"),
+                        (&["Z"], "Calculate sizes of all listed folders"),
+                        (&["U"], "Open disk usage explorer"),
+                        (&["P"], "Find duplicate objects"),
+                        (&["Ctrl-p"], "Find object under current prefix"),
+                        (&["Ctrl-f"], "Search objects by key prefix"),
+                        (&["Space"], "Mark/unmark item"),
+                        (&["v"], "Toggle visual mode to mark a range"),
+                        (&["*"], "Mark/unmark all listed items"),
+                        (&["s"], "Download marked items"),
+                        (&["D"], "Delete marked items"),
+                        (&["c"], "Copy marked items"),
+                        (&["t"], "Change storage class of marked items"),
                         (&["x"], "Open management console in browser"),
                     ]
                 } else {
@@ -209,6 +490,32 @@ impl ObjectListPage {
                         (&["~"], "Go back to bucket list"),
                         (&["/"], "Filter object list"),
                         (&["o"], "Sort object list"),
+                        (&[":"], "Go to path or S3 URI"),
+                        (&["m"], "Bookmark current prefix"),
+                        (&["'"], "Open bookmarks"),
+                        (&["H/L"], "Go back/forward in history"),
+                        (&["Ctrl-r"], "Open recent locations"),
+                        (&["Ctrl-t"], "Open new tab"),
+                        (&["Ctrl-w"], "Close current tab"),
+                        (&["gt/gT", "1-9"], "Switch tab"),
+                        (&["Ctrl-d"], "Toggle dual-pane mode"),
+                        (&["Ctrl-l"], "Toggle local directory"),
+                        (&["Tab"], "Switch pane"),
+                        (&["F5/F6/F7"], "Copy/Move/Sync to other pane"),
+                        (&["R"], "Toggle recursive listing"),
+                        (&["z"], "Calculate size of selected folder"),
+                        (&["Z"], "Calculate sizes of all listed folders"),
+                        (&["U"], "Open disk usage explorer"),
+                        (&["P"], "Find duplicate objects"),
+                        (&["Ctrl-p"], "Find object under current prefix"),
+                        (&["Ctrl-f"], "Search objects by key prefix"),
+                        (&["Space"], "Mark/unmark item"),
+                        (&["v"], "Toggle visual mode to mark a range"),
+                        (&["*"], "Mark/unmark all listed items"),
+                        (&["s"], "Download marked items"),
+                        (&["D"], "Delete marked items"),
+                        (&["c"], "Copy marked items"),
+                        (&["t"], "Change storage class of marked items"),
                         (&["x"], "Open management console in browser"),
                     ]
                 }
@@ -217,13 +524,45 @@ impl ObjectListPage {
                 (&["Ctrl-c"], "Quit app"),
                 (&["Esc"], "Close filter dialog"),
                 (&["Enter"], "Apply filter"),
+                (&["Tab"], "Switch mode (substring/regex/glob/fuzzy)"),
+                (&["Ctrl-t"], "Toggle case sensitivity"),
             ],
             ViewState::SortDialog => &[
                 (&["Ctrl-c"], "Quit app"),
                 (&["Esc"], "Close sort dialog"),
                 (&["j/k"], "Select item"),
+                (&["d"], "Toggle directories first"),
                 (&["Enter"], "Apply sort"),
             ],
+            ViewState::GoToDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close go to dialog"),
+                (&["Ctrl-v"], "Paste from clipboard"),
+                (&["Enter"], "Go to path or S3 URI"),
+            ],
+            ViewState::FinderDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close find dialog"),
+                (&["Down/Up", "Ctrl-n/p"], "Select item"),
+                (&["Enter"], "Go to selected object"),
+            ],
+            ViewState::DeleteConfirmDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "n"], "Cancel"),
+                (&["y"], "Delete items"),
+            ],
+            ViewState::CopyDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close copy dialog"),
+                (&["Ctrl-v"], "Paste from clipboard"),
+                (&["Enter"], "Copy items to path or S3 URI"),
+            ],
+            ViewState::StorageClassDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close storage class dialog"),
+                (&["j/k"], "Select item"),
+                (&["Enter"], "Change storage class"),
+            ],
         };
         build_helps(helps)
     }
@@ -240,6 +579,8 @@ impl ObjectListPage {
                         (&["Backspace"], "Go back", 2),
                         (&["/"], "Filter", 4),
                         (&["o"], "Sort", 5),
+                        (&["R"], "Recursive", 7),
+                        (&["Space"], "Mark", 8),
                         (&["?"], "Help", 0),
                     ]
                 } else {
@@ -251,6 +592,8 @@ impl ObjectListPage {
                         (&["Backspace"], "Go back", 2),
                         (&["/"], "Filter", 4),
                         (&["o"], "Sort", 5),
+                        (&["R"], "Recursive", 7),
+                        (&["Space"], "Mark", 8),
                         (&["?"], "Help", 0),
                     ]
                 }
@@ -266,6 +609,27 @@ impl ObjectListPage {
                 (&["Enter"], "Sort", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::GoToDialog => &[
+                (&["Esc"], "Close", 2),
+                (&["Ctrl-v"], "Paste", 3),
+                (&["Enter"], "Go", 1),
+            ],
+            ViewState::FinderDialog => &[
+                (&["Esc"], "Close", 2),
+                (&["Ctrl-n/p"], "Select", 3),
+                (&["Enter"], "Go", 1),
+            ],
+            ViewState::DeleteConfirmDialog => &[(&["Esc"], "Cancel", 2), (&["y"], "Delete", 1)],
+            ViewState::CopyDialog => &[
+                (&["Esc"], "Close", 2),
+                (&["Ctrl-v"], "Paste", 3),
+                (&["Enter"], "Copy", 1),
+            ],
+            ViewState::StorageClassDialog => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Select", 3),
+                (&["Enter"], "Change", 1),
+            ],
         };
         build_short_helps(helps)
     }
@@ -314,236 +678,775 @@ impl ObjectListPage {
         self.sort_dialog_state.reset();
 
         self.sort_view_indices();
+        self.tx.send(AppEventType::ObjectListSaveSort(self.sort()));
     }
 
-    fn apply_filter(&mut self) {
-        self.view_state = ViewState::Default;
-
-        self.filter_view_indices();
-    }
-
-    fn reset_filter(&mut self) {
-        self.filter_input_state.clear_input();
+    fn open_finder_dialog(&mut self) {
+        self.view_state = ViewState::FinderDialog;
+        self.finder_state.start_loading();
 
-        self.filter_view_indices();
+        self.tx.send(AppEventType::LoadFinderItems);
     }
 
-    fn filter_view_indices(&mut self) {
-        let filter = self.filter_input_state.input();
-        self.view_indices = self
-            .object_items
-            .iter()
-            .enumerate()
-            .filter(|(_, item)| item.name().contains(filter))
-            .map(|(idx, _)| idx)
-            .collect();
-        // reset list state
-        self.list_state = ScrollListState::new(self.view_indices.len());
+    fn open_prefix_search_dialog(&mut self) {
+        self.view_state = ViewState::FinderDialog;
+        self.finder_state.start_prefix_search();
 
-        self.sort_view_indices();
+        // stop loading for the previous finder
+        self.tx.send(AppEventType::SearchFinderItems(String::new()));
     }
 
-    fn apply_sort(&mut self) {
+    fn close_finder_dialog(&mut self) {
         self.view_state = ViewState::Default;
+        self.finder_state = FinderDialogState::default();
+    }
 
-        self.sort_view_indices();
+    fn apply_finder(&mut self) {
+        if let Some(key) = self.finder_state.selected_key() {
+            let key = key.to_string();
+            self.close_finder_dialog();
+            self.tx.send(AppEventType::ObjectListMoveToObject(key));
+        }
     }
 
-    fn select_next_sort_item(&mut self) {
-        self.sort_dialog_state.select_next();
+    pub fn add_finder_items(&mut self, items: Vec<ObjectItem>, done: bool, truncated: bool) {
+        if !matches!(self.view_state, ViewState::FinderDialog) {
+            return;
+        }
+        let keys = items.iter().map(|item| item.name().to_string()).collect();
+        self.finder_state.add_keys(keys);
+        if done {
+            self.finder_state.finish_loading(truncated);
+        }
+    }
 
-        self.sort_view_indices();
+    pub fn select_dir(&mut self, name: &str) -> bool {
+        self.select_item_by(|item| matches!(item, ObjectItem::Dir { name: n, .. } if n == name))
     }
 
-    fn select_prev_sort_item(&mut self) {
-        self.sort_dialog_state.select_prev();
+    pub fn select_file(&mut self, name: &str) -> bool {
+        self.select_item_by(|item| matches!(item, ObjectItem::File { name: n, .. } if n == name))
+    }
 
-        self.sort_view_indices();
+    fn select_item_by(&mut self, f: impl Fn(&ObjectItem) -> bool) -> bool {
+        let pos = self
+            .view_indices
+            .iter()
+            .position(|&i| f(&self.object_items[i]));
+        if let Some(pos) = pos {
+            self.list_state.select_index(pos);
+        }
+        pos.is_some()
     }
 
-    fn sort_view_indices(&mut self) {
-        let items = &self.object_items;
-        let selected = self.sort_dialog_state.selected();
+    fn toggle_recursive(&mut self) {
+        if let Some(items) = self.hierarchical_items.take() {
+            self.object_items = items;
+            self.marked.clear();
+            self.filter_view_indices();
+        } else {
+            self.tx.send(AppEventType::LoadObjectsRecursive);
+        }
+    }
 
-        #[allow(clippy::type_complexity)]
-        let sort_func: Box<dyn FnMut(&usize, &usize) -> Ordering> = match selected {
-            ObjectListSortType::Default => Box::new(|a, b| a.cmp(b)),
-            ObjectListSortType::NameAsc => Box::new(|a, b| items[*a].name().cmp(items[*b].name())),
-            ObjectListSortType::NameDesc => Box::new(|a, b| items[*b].name().cmp(items[*a].name())),
-            ObjectListSortType::LastModifiedAsc => {
-                Box::new(|a, b| items[*a].last_modified().cmp(&items[*b].last_modified()))
-            }
-            ObjectListSortType::LastModifiedDesc => {
-                Box::new(|a, b| items[*b].last_modified().cmp(&items[*a].last_modified()))
-            }
-            ObjectListSortType::SizeAsc => {
-                Box::new(|a, b| items[*a].size_byte().cmp(&items[*b].size_byte()))
-            }
-            ObjectListSortType::SizeDesc => {
-                Box::new(|a, b| items[*b].size_byte().cmp(&items[*a].size_byte()))
-            }
-        };
+    pub fn set_recursive_items(&mut self, items: Vec<ObjectItem>) {
+        let items = std::mem::replace(&mut self.object_items, items);
+        self.hierarchical_items = Some(items);
+        self.marked.clear();
+        self.filter_view_indices();
+    }
 
-        self.view_indices.sort_by(sort_func);
+    // replace the items with the reloaded ones, keeping the selected item if it still exists
+    pub fn set_items(&mut self, items: Vec<ObjectItem>) {
+        let selected = self
+            .non_empty()
+            .then(|| self.current_selected_item().name().to_string());
+        self.object_items = items;
+        self.hierarchical_items = None;
+        self.prefix_sizes.clear();
+        self.content_types.clear();
+        self.content_type_requests.clear();
+        self.clear_marks();
+        self.filter_view_indices();
+        if let Some(name) = selected {
+            self.select_item_by(|item| item.name() == name);
+        }
     }
 
-    pub fn current_selected_item(&self) -> &ObjectItem {
-        let i = self
-            .view_indices
-            .get(self.list_state.selected)
-            .unwrap_or_else(|| {
-                panic!(
-                    "selected view index {} is out of range {}",
-                    self.list_state.selected,
-                    self.view_indices.len()
-                )
-            });
-        self.object_items.get(*i).unwrap_or_else(|| {
-            panic!(
-                "selected index {} is out of range {}",
-                i,
-                self.object_items.len()
-            )
-        })
+    fn calculate_selected_prefix_size(&mut self) {
+        if let ObjectItem::Dir { name } = self.current_selected_item() {
+            let names = vec![name.clone()];
+            self.calculate_prefix_sizes(names);
+        }
     }
 
-    pub fn object_list(&self) -> Vec<ObjectItem> {
-        self.view_indices
+    // calculate only the directories not calculated yet, like ncdu
+    fn calculate_all_prefix_sizes(&mut self) {
+        let names: Vec<String> = self
+            .view_indices
             .iter()
-            .map(|&original_idx| &self.object_items[original_idx])
+            .filter_map(|&i| match &self.object_items[i] {
+                ObjectItem::Dir { name } => Some(name),
+                ObjectItem::File { .. } => None,
+            })
+            .filter(|name| !self.prefix_sizes.contains_key(*name))
             .cloned()
-            .collect()
+            .collect();
+        if !names.is_empty() {
+            self.calculate_prefix_sizes(names);
+        }
     }
 
-    pub fn list_state(&self) -> ScrollListState {
-        self.list_state
+    fn calculate_prefix_sizes(&mut self, names: Vec<String>) {
+        for name in &names {
+            self.prefix_sizes.insert(name.clone(), None);
+        }
+        self.tx
+            .send(AppEventType::ObjectListCalculatePrefixSizes(names));
     }
 
-    fn non_empty(&self) -> bool {
-        !self.view_indices.is_empty()
+    pub fn set_prefix_sizes(&mut self, sizes: HashMap<String, PrefixSize>) {
+        self.prefix_sizes = sizes
+            .into_iter()
+            .map(|(name, size)| (name, Some(size)))
+            .collect();
     }
-}
 
-fn build_list_items<'a>(
-    current_items: &'a [ObjectItem],
-    view_indices: &'a [usize],
-    filter: &'a str,
-    offset: usize,
-    selected: usize,
-    area: Rect,
-) -> Vec<ListItem<'a>> {
-    let show_item_count = (area.height as usize) - 2 /* border */;
-    view_indices
-        .iter()
-        .map(|&original_idx| &current_items[original_idx])
-        .skip(offset)
-        .take(show_item_count)
-        .enumerate()
-        .map(|(idx, item)| build_list_item(item, idx + offset == selected, filter, area))
-        .collect()
-}
+    pub fn set_prefix_size(&mut self, name: String, size: PrefixSize) {
+        self.prefix_sizes.insert(name, Some(size));
+    }
 
-fn build_list_item<'a>(
-    item: &'a ObjectItem,
-    selected: bool,
-    filter: &'a str,
-    area: Rect,
-) -> ListItem<'a> {
-    let line = match item {
-        ObjectItem::Dir { name, .. } => build_object_dir_line(name, filter),
-        ObjectItem::File {
-            name,
-            size_byte,
-            last_modified,
-            ..
-        } => build_object_file_line(name, *size_byte, last_modified, filter, area.width),
-    };
+    // the listing does not include the content types, so they are loaded only for the shown files
+    fn request_content_types(&mut self, show_item_count: usize) {
+        let names: Vec<String> = self
+            .view_indices
+            .iter()
+            .skip(self.list_state.offset)
+            .take(show_item_count)
+            .filter_map(|&i| match &self.object_items[i] {
+                ObjectItem::File { name, .. } => Some(name),
+                ObjectItem::Dir { .. } => None,
+            })
+            .filter(|name| !self.content_types.contains_key(*name))
+            .cloned()
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+        for name in &names {
+            self.content_types.insert(name.clone(), None);
+        }
+        self.content_type_requests.extend(names);
+        self.tx.send(AppEventType::ObjectListLoadContentTypes);
+    }
 
-    let style = if selected {
-        Style::default()
-            .bg(SELECTED_COLOR)
-            .fg(SELECTED_ITEM_TEXT_COLOR)
-    } else {
-        Style::default()
-    };
-    ListItem::new(line).style(style)
-}
+    pub fn take_content_type_requests(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.content_type_requests)
+    }
 
-fn build_object_dir_line<'a>(name: &'a str, filter: &'a str) -> Line<'a> {
-    if filter.is_empty() {
-        Line::from(vec![" ".into(), name.bold(), "/".bold(), " ".into()])
-    } else {
-        let (before, highlighted, after) = split_str(name, filter).unwrap();
-        Line::from(vec![
-            " ".into(),
-            before.bold(),
-            highlighted.fg(HIGHLIGHTED_ITEM_TEXT_COLOR).bold(),
-            after.bold(),
-            "/".bold(),
-            " ".into(),
-        ])
+    pub fn set_content_types(&mut self, content_types: HashMap<String, String>) {
+        self.content_types = content_types
+            .into_iter()
+            .map(|(name, content_type)| (name, Some(content_type)))
+            .collect();
     }
-}
 
-fn build_object_file_line<'a>(
-    name: &'a str,
-    size_byte: usize,
-    last_modified: &'a DateTime<Local>,
-    filter: &'a str,
-    width: u16,
-) -> Line<'a> {
-    let size = format_size_byte(size_byte);
-    let date = format_datetime(last_modified);
-    let date_w: usize = 19;
-    let size_w: usize = 10;
-    let name_w: usize = (width as usize) - date_w - size_w - 10 /* spaces */ - 4 /* border + pad */;
-
-    let name = format!("{:<name_w$}", name, name_w = name_w);
-    let date = format!("{:<date_w$}", date, date_w = date_w);
-    let size = format!("{:>size_w$}", size, size_w = size_w);
-
-    if filter.is_empty() {
-        Line::from(vec![
-            " ".into(),
-            name.into(),
-            "    ".into(),
-            date.into(),
-            "    ".into(),
-            size.into(),
-            " ".into(),
-        ])
-    } else {
-        let (before, highlighted, after) = split_str(&name, filter).unwrap();
-        Line::from(vec![
-            " ".into(),
-            before.into(),
-            highlighted.fg(HIGHLIGHTED_ITEM_TEXT_COLOR),
-            after.into(),
-            "    ".into(),
-            date.into(),
-            "    ".into(),
-            size.into(),
-            " ".into(),
-        ])
+    pub fn set_content_type(&mut self, name: String, content_type: String) {
+        self.content_types.insert(name, Some(content_type));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{event, set_cells};
+    pub fn set_columns(&mut self, columns: Vec<ListColumn>) {
+        self.columns = columns;
+    }
 
-    use super::*;
-    use chrono::NaiveDateTime;
-    use ratatui::{backend::TestBackend, buffer::Buffer, style::Modifier, Terminal};
+    // drop the calculations which will not be completed
+    pub fn cancel_prefix_size_calculations(&mut self) {
+        self.prefix_sizes.retain(|_, size| size.is_some());
+    }
 
-    #[test]
-    fn test_render_without_scroll() -> std::io::Result<()> {
-        let (tx, _) = event::new();
-        let mut terminal = setup_terminal()?;
+    fn toggle_mark(&mut self) {
+        let i = self.view_indices[self.list_state.selected];
+        if !self.marked.remove(&i) {
+            self.marked.insert(i);
+        }
+        self.select_next();
+    }
 
-        terminal.draw(|f| {
-            let items = vec![
-                ObjectItem::Dir {
+    fn toggle_visual_mode(&mut self) {
+        if self.visual_anchor.is_some() {
+            let indices = self.visual_indices().to_vec();
+            self.marked.extend(indices);
+            self.visual_anchor = None;
+        } else {
+            self.visual_anchor = Some(self.list_state.selected);
+        }
+    }
+
+    fn toggle_mark_all(&mut self) {
+        self.visual_anchor = None;
+        if self.view_indices.iter().all(|i| self.marked.contains(i)) {
+            for i in &self.view_indices {
+                self.marked.remove(i);
+            }
+        } else {
+            self.marked.extend(self.view_indices.iter());
+        }
+    }
+
+    fn visual_indices(&self) -> &[usize] {
+        match self.visual_anchor {
+            Some(anchor) => {
+                let selected = self.list_state.selected;
+                &self.view_indices[anchor.min(selected)..=anchor.max(selected)]
+            }
+            None => &[],
+        }
+    }
+
+    // marked items including the current visual range
+    fn marked_indices(&self) -> HashSet<usize> {
+        let mut indices = self.marked.clone();
+        indices.extend(self.visual_indices());
+        indices
+    }
+
+    pub fn marked_summary(&self) -> Option<(usize, usize)> {
+        let indices = self.marked_indices();
+        if indices.is_empty() {
+            return None;
+        }
+        let size_byte = indices
+            .iter()
+            .map(|&i| self.object_items[i].size_byte().unwrap_or_default())
+            .sum();
+        Some((indices.len(), size_byte))
+    }
+
+    // the marked items, or the selected item if nothing is marked
+    pub fn target_items(&self) -> Vec<ObjectItem> {
+        let indices = self.marked_indices();
+        if indices.is_empty() {
+            return vec![self.current_selected_item().clone()];
+        }
+        self.object_items
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| indices.contains(i))
+            .map(|(_, item)| item.clone())
+            .collect()
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.visual_anchor = None;
+    }
+
+    fn download_targets(&mut self) {
+        let items = self.target_items();
+        self.clear_marks();
+        self.tx.send(AppEventType::ObjectListDownloadObjects(items));
+    }
+
+    fn open_delete_confirm_dialog(&mut self) {
+        self.view_state = ViewState::DeleteConfirmDialog;
+    }
+
+    fn delete_confirm_message(&self) -> String {
+        match self.target_items().as_slice() {
+            [ObjectItem::Dir { name }] => format!("Delete {}/ and all objects under it?", name),
+            [item] => format!("Delete {}?", item.name()),
+            items => format!("Delete {} items?", items.len()),
+        }
+    }
+
+    fn apply_delete(&mut self) {
+        self.view_state = ViewState::Default;
+        let items = self.target_items();
+        self.clear_marks();
+        self.tx.send(AppEventType::ObjectListDeleteObjects(items));
+    }
+
+    fn open_copy_dialog(&mut self) {
+        self.view_state = ViewState::CopyDialog;
+    }
+
+    fn close_copy_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+        self.copy_input_state.clear_input();
+    }
+
+    fn apply_copy(&mut self) {
+        let input = self.copy_input_state.input().to_string();
+        self.close_copy_dialog();
+        if !input.is_empty() {
+            let items = self.target_items();
+            self.clear_marks();
+            self.tx
+                .send(AppEventType::ObjectListCopyObjects(items, input));
+        }
+    }
+
+    fn paste_to_copy_dialog(&mut self) {
+        match paste_from_clipboard() {
+            Ok(s) => self.copy_input_state.insert_str(&s),
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+    }
+
+    fn open_storage_class_dialog(&mut self) {
+        self.view_state = ViewState::StorageClassDialog;
+    }
+
+    fn close_storage_class_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+        self.storage_class_dialog_state.reset();
+    }
+
+    fn apply_storage_class(&mut self) {
+        let storage_class = self.storage_class_dialog_state.selected().str().to_string();
+        self.close_storage_class_dialog();
+        let items = self.target_items();
+        self.clear_marks();
+        self.tx.send(AppEventType::ObjectListChangeStorageClass(
+            items,
+            storage_class,
+        ));
+    }
+
+    fn open_go_to_dialog(&mut self) {
+        self.view_state = ViewState::GoToDialog;
+    }
+
+    fn close_go_to_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+        self.go_to_input_state.clear_input();
+    }
+
+    fn apply_go_to(&mut self) {
+        let input = self.go_to_input_state.input().to_string();
+        self.close_go_to_dialog();
+        if !input.is_empty() {
+            self.tx.send(AppEventType::MoveToInput(input));
+        }
+    }
+
+    fn paste_to_go_to_dialog(&mut self) {
+        match paste_from_clipboard() {
+            Ok(s) => self.go_to_input_state.insert_str(&s),
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+    }
+
+    fn apply_filter(&mut self) {
+        self.view_state = ViewState::Default;
+
+        self.filter_view_indices();
+    }
+
+    fn reset_filter(&mut self) {
+        self.filter_input_state.clear_input();
+
+        self.filter_view_indices();
+    }
+
+    fn filter_view_indices(&mut self) {
+        let filter = ObjectFilter::new(
+            self.filter_input_state.input(),
+            self.filter_options,
+            Local::now(),
+        );
+        self.view_indices = self
+            .object_items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| filter.matches(item))
+            .map(|(idx, _)| idx)
+            .collect();
+        self.filter = filter;
+        // reset list state
+        self.list_state = ScrollListState::new(self.view_indices.len());
+
+        self.sort_view_indices();
+    }
+
+    fn apply_sort(&mut self) {
+        self.view_state = ViewState::Default;
+
+        self.sort_view_indices();
+        self.tx.send(AppEventType::ObjectListSaveSort(self.sort()));
+    }
+
+    fn toggle_sort_dirs_first(&mut self) {
+        self.sort_dialog_state.toggle_dirs_first();
+
+        self.sort_view_indices();
+    }
+
+    fn sort(&self) -> ObjectListSort {
+        ObjectListSort {
+            sort_type: self.sort_dialog_state.selected(),
+            dirs_first: self.sort_dialog_state.dirs_first(),
+        }
+    }
+
+    pub fn set_sort(&mut self, sort: ObjectListSort) {
+        self.sort_dialog_state = ObjectListSortDialogState::new(sort.sort_type, sort.dirs_first);
+
+        self.sort_view_indices();
+    }
+
+    fn select_next_sort_item(&mut self) {
+        self.sort_dialog_state.select_next();
+
+        self.sort_view_indices();
+    }
+
+    fn select_prev_sort_item(&mut self) {
+        self.sort_dialog_state.select_prev();
+
+        self.sort_view_indices();
+    }
+
+    fn sort_view_indices(&mut self) {
+        // the range is made of view indices
+        self.visual_anchor = None;
+
+        let items = &self.object_items;
+        let selected = self.sort_dialog_state.selected();
+
+        #[allow(clippy::type_complexity)]
+        let mut sort_func: Box<dyn FnMut(&usize, &usize) -> Ordering> = match selected {
+            ObjectListSortType::Default => Box::new(|a, b| a.cmp(b)),
+            ObjectListSortType::NameAsc => Box::new(|a, b| items[*a].name().cmp(items[*b].name())),
+            ObjectListSortType::NameDesc => Box::new(|a, b| items[*b].name().cmp(items[*a].name())),
+            ObjectListSortType::LastModifiedAsc => {
+                Box::new(|a, b| items[*a].last_modified().cmp(&items[*b].last_modified()))
+            }
+            ObjectListSortType::LastModifiedDesc => {
+                Box::new(|a, b| items[*b].last_modified().cmp(&items[*a].last_modified()))
+            }
+            ObjectListSortType::SizeAsc => {
+                Box::new(|a, b| items[*a].size_byte().cmp(&items[*b].size_byte()))
+            }
+            ObjectListSortType::SizeDesc => {
+                Box::new(|a, b| items[*b].size_byte().cmp(&items[*a].size_byte()))
+            }
+            ObjectListSortType::ExtensionAsc => Box::new(|a, b| {
+                let (a, b) = (items[*a].name(), items[*b].name());
+                extension(a).cmp(extension(b)).then_with(|| a.cmp(b))
+            }),
+            ObjectListSortType::ExtensionDesc => Box::new(|a, b| {
+                let (a, b) = (items[*a].name(), items[*b].name());
+                extension(b).cmp(extension(a)).then_with(|| b.cmp(a))
+            }),
+            ObjectListSortType::NaturalAsc => {
+                Box::new(|a, b| natural_cmp(items[*a].name(), items[*b].name()))
+            }
+            ObjectListSortType::NaturalDesc => {
+                Box::new(|a, b| natural_cmp(items[*b].name(), items[*a].name()))
+            }
+        };
+
+        if self.sort_dialog_state.dirs_first() {
+            let is_file = |i: &usize| matches!(items[*i], ObjectItem::File { .. });
+            self.view_indices
+                .sort_by(|a, b| is_file(a).cmp(&is_file(b)).then_with(|| sort_func(a, b)));
+        } else {
+            self.view_indices.sort_by(sort_func);
+        }
+    }
+
+    pub fn current_selected_item(&self) -> &ObjectItem {
+        let i = self
+            .view_indices
+            .get(self.list_state.selected)
+            .unwrap_or_else(|| {
+                panic!(
+                    "selected view index {} is out of range {}",
+                    self.list_state.selected,
+                    self.view_indices.len()
+                )
+            });
+        self.object_items.get(*i).unwrap_or_else(|| {
+            panic!(
+                "selected index {} is out of range {}",
+                i,
+                self.object_items.len()
+            )
+        })
+    }
+
+    pub fn object_list(&self) -> Vec<ObjectItem> {
+        self.view_indices
+            .iter()
+            .map(|&original_idx| &self.object_items[original_idx])
+            .cloned()
+            .collect()
+    }
+
+    pub fn list_state(&self) -> ScrollListState {
+        self.list_state
+    }
+
+    pub fn non_empty(&self) -> bool {
+        !self.view_indices.is_empty()
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_list_items<'a>(
+    current_items: &'a [ObjectItem],
+    view_indices: &'a [usize],
+    marked: &HashSet<usize>,
+    prefix_sizes: &HashMap<String, Option<PrefixSize>>,
+    content_types: &'a HashMap<String, Option<String>>,
+    filter: &ListFilter,
+    layout: &ColumnLayout,
+    list_state: ScrollListState,
+    show_item_count: usize,
+) -> Vec<ListItem<'a>> {
+    view_indices
+        .iter()
+        .map(|&original_idx| (&current_items[original_idx], marked.contains(&original_idx)))
+        .skip(list_state.offset)
+        .take(show_item_count)
+        .enumerate()
+        .map(|(idx, (item, marked))| {
+            let selected = idx + list_state.offset == list_state.selected;
+            let prefix_size = prefix_sizes.get(item.name()).copied();
+            let content_type = content_types.get(item.name());
+            build_list_item(
+                item,
+                selected,
+                marked,
+                prefix_size,
+                content_type,
+                filter,
+                layout,
+            )
+        })
+        .collect()
+}
+
+// the columns which fit in the width of the list, with the width of each column
+#[derive(Debug)]
+pub struct ColumnLayout {
+    columns: Vec<(ListColumn, usize)>,
+}
+
+impl ColumnLayout {
+    pub fn new(columns: &[ListColumn], width: u16) -> ColumnLayout {
+        let rest_w = (width as usize).saturating_sub(2 /* spaces */ + 4 /* border + pad */);
+        let others_w = |columns: &[ListColumn]| -> usize {
+            columns
+                .iter()
+                .filter_map(|c| column_width(*c))
+                .map(|w| w + 4 /* spaces */)
+                .sum()
+        };
+
+        // the columns are omitted if the list is too narrow, e.g. in the dual-pane mode
+        let mut columns = columns.to_vec();
+        while rest_w < MIN_NAME_WIDTH + others_w(&columns) {
+            let omitted = columns
+                .iter()
+                .enumerate()
+                .filter_map(|(i, c)| column_omit_priority(*c).map(|p| (i, p)))
+                .max_by_key(|(_, p)| *p);
+            match omitted {
+                Some((i, _)) => columns.remove(i),
+                None => break,
+            };
+        }
+
+        let name_w = rest_w.saturating_sub(others_w(&columns));
+        let columns = columns
+            .into_iter()
+            .map(|c| (c, column_width(c).unwrap_or(name_w)))
+            .collect();
+        ColumnLayout { columns }
+    }
+
+    fn contains(&self, column: ListColumn) -> bool {
+        self.columns.iter().any(|(c, _)| *c == column)
+    }
+}
+
+// None for the name, which takes the rest of the width
+fn column_width(column: ListColumn) -> Option<usize> {
+    match column {
+        ListColumn::Name => None,
+        ListColumn::Size => Some(SIZE_WIDTH),
+        ListColumn::LastModified => Some(DATE_WIDTH),
+        ListColumn::StorageClass => Some(STORAGE_CLASS_WIDTH),
+        ListColumn::ETag => Some(E_TAG_WIDTH),
+        ListColumn::ContentType => Some(CONTENT_TYPE_WIDTH),
+    }
+}
+
+// the column with the higher priority is omitted first, the name and the size are always shown
+fn column_omit_priority(column: ListColumn) -> Option<usize> {
+    match column {
+        ListColumn::Name | ListColumn::Size => None,
+        ListColumn::LastModified => Some(1),
+        ListColumn::StorageClass => Some(2),
+        ListColumn::ETag => Some(3),
+        ListColumn::ContentType => Some(4),
+    }
+}
+
+// `prefix_size` is Some(None) while calculating the size of the directory,
+// and `content_type` is Some(None) while loading the content type of the file
+pub fn build_list_item<'a>(
+    item: &'a ObjectItem,
+    selected: bool,
+    marked: bool,
+    prefix_size: Option<Option<PrefixSize>>,
+    content_type: Option<&'a Option<String>>,
+    filter: &ListFilter,
+    layout: &ColumnLayout,
+) -> ListItem<'a> {
+    let mut spans = vec![" ".into()];
+    for (i, (column, width)) in layout.columns.iter().enumerate() {
+        if i > 0 {
+            spans.push("    ".into());
+        }
+        match column {
+            ListColumn::Name => {
+                let count = prefix_size.flatten().map(|size| size.count);
+                spans.extend(build_name_cell(item, count, filter, *width))
+            }
+            column => {
+                let cell = build_cell(item, *column, prefix_size, content_type);
+                spans.push(align_cell(cell, *column, *width).into());
+            }
+        }
+    }
+    spans.push(" ".into());
+    if marked {
+        // replace the leading space with the mark
+        spans[0] = "*".into();
+    }
+    let line = Line::from(spans);
+
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else if marked {
+        Style::default().fg(MARKED_ITEM_TEXT_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+// the directories show the object count under them at the end of the name if calculated,
+// so that it is shown regardless of the other columns
+fn build_name_cell<'a>(
+    item: &'a ObjectItem,
+    count: Option<usize>,
+    filter: &ListFilter,
+    width: usize,
+) -> Vec<Span<'a>> {
+    let name = item.name();
+    let mut spans = build_name_spans(name, filter);
+    let mut name_w = name.chars().count();
+    if let ObjectItem::Dir { .. } = item {
+        spans = spans.into_iter().map(|s| s.bold()).collect();
+        spans.push("/".bold());
+        name_w += 1;
+    }
+    let count = count.map(format_object_count).filter(|count| {
+        name_w + 1 /* space */ + count.chars().count() <= width
+    });
+    match count {
+        Some(count) => {
+            let pad = width - name_w - count.chars().count();
+            spans.push(" ".repeat(pad).into());
+            spans.push(count.into());
+        }
+        None => spans.push(" ".repeat(width.saturating_sub(name_w)).into()),
+    }
+    spans
+}
+
+// the directories show the total size under them if calculated
+fn build_cell(
+    item: &ObjectItem,
+    column: ListColumn,
+    prefix_size: Option<Option<PrefixSize>>,
+    content_type: Option<&Option<String>>,
+) -> String {
+    match (item, column) {
+        // the name is built with the highlighted spans
+        (_, ListColumn::Name) => String::new(),
+        (ObjectItem::Dir { .. }, ListColumn::Size) => match prefix_size {
+            Some(Some(prefix_size)) => format_size_byte(prefix_size.size_byte),
+            Some(None) => "...".to_string(),
+            None => String::new(),
+        },
+        (ObjectItem::Dir { .. }, _) => String::new(),
+        (ObjectItem::File { size_byte, .. }, ListColumn::Size) => format_size_byte(*size_byte),
+        (ObjectItem::File { last_modified, .. }, ListColumn::LastModified) => {
+            format_datetime(last_modified)
+        }
+        (ObjectItem::File { storage_class, .. }, ListColumn::StorageClass) => {
+            storage_class.clone().unwrap_or_default()
+        }
+        (ObjectItem::File { e_tag, .. }, ListColumn::ETag) => e_tag.clone().unwrap_or_default(),
+        (ObjectItem::File { .. }, ListColumn::ContentType) => match content_type {
+            Some(Some(content_type)) => content_type.clone(),
+            Some(None) => "...".to_string(),
+            None => String::new(),
+        },
+    }
+}
+
+fn align_cell(cell: String, column: ListColumn, width: usize) -> String {
+    let cell = if cell.chars().count() > width {
+        let mut s: String = cell.chars().take(width.saturating_sub(1)).collect();
+        s.push('…');
+        s
+    } else {
+        cell
+    };
+    match column {
+        ListColumn::Size => format!("{:>width$}", cell, width = width),
+        _ => format!("{:<width$}", cell, width = width),
+    }
+}
+
+fn build_name_spans<'a>(name: &'a str, filter: &ListFilter) -> Vec<Span<'a>> {
+    let ranges = filter.find(name).unwrap_or_default();
+    split_matches(name, &ranges)
+        .into_iter()
+        .map(|(s, matched)| {
+            if matched {
+                s.fg(HIGHLIGHTED_ITEM_TEXT_COLOR)
+            } else {
+                s.into()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event, set_cells};
+
+    use super::*;
+    use chrono::{DateTime, NaiveDateTime};
+    use ratatui::{backend::TestBackend, buffer::Buffer, style::Modifier, Terminal};
+
+    #[test]
+    fn test_render_without_scroll() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let items = vec![
+                ObjectItem::Dir {
                     name: "dir1".to_string(),
                 },
                 ObjectItem::Dir {
@@ -553,11 +1456,15 @@ mod tests {
                     name: "file1".to_string(),
                     size_byte: 1024 + 10,
                     last_modified: parse_datetime("2024-01-02 13:01:02"),
+                    e_tag: None,
+                    storage_class: None,
                 },
                 ObjectItem::File {
                     name: "file2".to_string(),
                     size_byte: 1024 * 999,
                     last_modified: parse_datetime("2023-12-31 09:00:00"),
+                    e_tag: None,
+                    storage_class: None,
                 },
             ];
             let mut page = ObjectListPage::new(items, tx);
@@ -601,6 +1508,8 @@ mod tests {
                     name: format!("file{}", i + 1),
                     size_byte: 1024,
                     last_modified: parse_datetime("2024-01-02 13:01:02"),
+                    e_tag: None,
+                    storage_class: None,
                 })
                 .collect();
             let mut page = ObjectListPage::new(items, tx);
@@ -631,6 +1540,70 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_toggle_recursive_items() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
+            },
+        ];
+        let recursive_items = vec![
+            ObjectItem::File {
+                name: "dir1/a/file2".to_string(),
+                size_byte: 2048,
+                last_modified: parse_datetime("2024-01-03 10:00:00"),
+                e_tag: None,
+                storage_class: None,
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
+            },
+        ];
+        let mut page = ObjectListPage::new(items, tx);
+        page.set_recursive_items(recursive_items);
+
+        let area = Rect::new(0, 0, 60, 10);
+        terminal.draw(|f| page.render(f, area))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 2 ┐",
+            "│  dir1/a/file2         2024-01-03 10:00:00         2 KiB  │",
+            "│  file1                2024-01-02 13:01:02         1 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('R')));
+        assert!(matches!(page.current_selected_item(), ObjectItem::Dir { name } if name == "dir1"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_sort_items() {
         let (tx, _) = event::new();
@@ -640,17 +1613,23 @@ mod tests {
                 name: "file".into(),
                 size_byte: 1024,
                 last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
             },
             ObjectItem::Dir { name: "dir".into() },
             ObjectItem::File {
                 name: "xyz".into(),
                 size_byte: 1024 * 1024,
                 last_modified: parse_datetime("2023-12-31 23:59:59"),
+                e_tag: None,
+                storage_class: None,
             },
             ObjectItem::File {
                 name: "abc".into(),
                 size_byte: 0,
                 last_modified: parse_datetime("-2000-01-01 00:00:00"),
+                e_tag: None,
+                storage_class: None,
             },
         ];
         let mut page = ObjectListPage::new(items, tx);
@@ -681,6 +1660,401 @@ mod tests {
         assert_eq!(page.view_indices, vec![3, 1, 4, 0, 2]);
     }
 
+    #[test]
+    fn test_sort_items_by_extension_and_natural_order() {
+        let (tx, _) = event::new();
+        let file = |name: &str| ObjectItem::File {
+            name: name.into(),
+            size_byte: 0,
+            last_modified: parse_datetime("2024-01-02 13:01:02"),
+            e_tag: None,
+            storage_class: None,
+        };
+        let items = vec![
+            file("part-10.csv"),
+            ObjectItem::Dir {
+                name: "part-3".into(),
+            },
+            file("part-2.json"),
+            file("part-1.csv"),
+        ];
+        let mut page = ObjectListPage::new(items, tx);
+
+        page.set_sort(ObjectListSort {
+            sort_type: ObjectListSortType::ExtensionAsc,
+            dirs_first: false,
+        });
+        assert_eq!(page.view_indices, vec![1, 3, 0, 2]);
+
+        page.set_sort(ObjectListSort {
+            sort_type: ObjectListSortType::NaturalAsc,
+            dirs_first: false,
+        });
+        assert_eq!(page.view_indices, vec![3, 2, 1, 0]);
+
+        page.set_sort(ObjectListSort {
+            sort_type: ObjectListSortType::NaturalDesc,
+            dirs_first: false,
+        });
+        assert_eq!(page.view_indices, vec![0, 1, 2, 3]);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('d'))); // toggle dirs first
+
+        assert_eq!(page.view_indices, vec![1, 0, 2, 3]);
+    }
+
+    #[test]
+    fn test_mark_items() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
+            },
+            ObjectItem::File {
+                name: "file2".to_string(),
+                size_byte: 2048,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
+            },
+            ObjectItem::File {
+                name: "file3".to_string(),
+                size_byte: 4096,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
+            },
+        ];
+        let mut page = ObjectListPage::new(items, tx);
+        let area = Rect::new(0, 0, 60, 10);
+        terminal.draw(|f| page.render(f, area))?;
+
+        page.handle_key(KeyEvent::from(KeyCode::Char(' '))); // mark dir1
+        page.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('v')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('j'))); // file2..file3 in visual mode
+        assert_eq!(page.marked_summary(), Some((3, 2048 + 4096)));
+
+        page.handle_key(KeyEvent::from(KeyCode::Esc)); // cancel visual mode
+        assert_eq!(page.marked_summary(), Some((1, 0)));
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('k')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('k')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('v')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('v'))); // mark file1..file2
+        assert_eq!(page.marked_summary(), Some((3, 1024 + 2048)));
+
+        terminal.draw(|f| page.render(f, area))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 3 / 4 ┐",
+            "│ *dir1/                                                   │",
+            "│ *file1                2024-01-02 13:01:02         1 KiB  │",
+            "│ *file2                2024-01-02 13:01:02         2 KiB  │",
+            "│  file3                2024-01-02 13:01:02         4 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // marked items
+            (2..58, [1, 2]) => fg: Color::Yellow,
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [3]) => bg: Color::Cyan, fg: Color::Black,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('*'))); // mark all
+        assert_eq!(page.marked_summary(), Some((4, 1024 + 2048 + 4096)));
+        page.handle_key(KeyEvent::from(KeyCode::Char('*'))); // unmark all
+        assert_eq!(page.marked_summary(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_columns() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(130, 6))?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024 + 10,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: Some("d41d8cd98f00b204e9800998ecf8427e".to_string()),
+                storage_class: Some("STANDARD".to_string()),
+            },
+            ObjectItem::File {
+                name: "file2".to_string(),
+                size_byte: 1024 * 999,
+                last_modified: parse_datetime("2023-12-31 09:00:00"),
+                e_tag: Some("9b2cf535f27731c974343645a3985328-12".to_string()),
+                storage_class: Some("INTELLIGENT_TIERING".to_string()),
+            },
+        ];
+        let mut page = ObjectListPage::new(items, tx);
+        page.set_columns(vec![
+            ListColumn::Name,
+            ListColumn::Size,
+            ListColumn::StorageClass,
+            ListColumn::ETag,
+        ]);
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 130, 6);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────────────────────────────────────────────────────────────────────────────────────────────────────────── 1 / 3 ┐",
+            "│  dir1/                                                                                                                         │",
+            "│  file1                                                1.01 KiB    STANDARD               d41d8cd98f00b204e9800998ecf8427e      │",
+            "│  file2                                                 999 KiB    INTELLIGENT_TIERING    9b2cf535f27731c974343645a3985328-12   │",
+            "│                                                                                                                                │",
+            "└────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..128, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        // the columns are omitted in order if the list is too narrow
+        let mut terminal = Terminal::new(TestBackend::new(70, 6))?;
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 70, 6);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────────────────────────────────────────────── 1 / 3 ┐",
+            "│  dir1/                                                             │",
+            "│  file1                            1.01 KiB    STANDARD             │",
+            "│  file2                             999 KiB    INTELLIGENT_TIERING  │",
+            "│                                                                    │",
+            "└────────────────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..68, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_prefix_sizes() -> std::io::Result<()> {
+        let (tx, _rx) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::Dir {
+                name: "dir2".to_string(),
+            },
+            ObjectItem::Dir {
+                name: "dir3".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024 + 10,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
+            },
+        ];
+        let mut page = ObjectListPage::new(items, tx);
+        let size = |size_byte, count| PrefixSize { size_byte, count };
+        page.set_prefix_sizes(HashMap::from([("dir1".to_string(), size(1024 * 999, 3))]));
+        page.handle_key(KeyEvent::from(KeyCode::Char('Z'))); // calculate dir2 and dir3
+        page.set_prefix_size("dir2".to_string(), size(10, 1));
+
+        let area = Rect::new(0, 0, 60, 10);
+        terminal.draw(|f| page.render(f, area))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 4 ┐",
+            "│  dir1/   3 objects                              999 KiB  │",
+            "│  dir2/    1 object                                 10 B  │",
+            "│  dir3/                                              ...  │",
+            "│  file1                2024-01-02 13:01:02      1.01 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir items
+            (3..8, [1, 2, 3]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        // the object count is shown without the last modified column
+        page.set_columns(vec![ListColumn::Name, ListColumn::Size]);
+        terminal.draw(|f| page.render(f, area))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 4 ┐",
+            "│  dir1/                          3 objects       999 KiB  │",
+            "│  dir2/                           1 object          10 B  │",
+            "│  dir3/                                              ...  │",
+            "│  file1                                         1.01 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir items
+            (3..8, [1, 2, 3]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_content_types() -> std::io::Result<()> {
+        let (tx, rx) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1.txt".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
+            },
+            ObjectItem::File {
+                name: "file2.png".to_string(),
+                size_byte: 2048,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                e_tag: None,
+                storage_class: None,
+            },
+        ];
+        let mut page = ObjectListPage::new(items, tx);
+        page.set_columns(vec![
+            ListColumn::Name,
+            ListColumn::ContentType,
+            ListColumn::Size,
+        ]);
+        page.set_content_types(HashMap::from([(
+            "file1.txt".to_string(),
+            "text/plain".to_string(),
+        )]));
+
+        let area = Rect::new(0, 0, 60, 10);
+        terminal.draw(|f| page.render(f, area))?;
+
+        // only the files not loaded yet are requested, and only once
+        recv_content_types_request(&rx);
+        assert_eq!(page.take_content_type_requests(), ["file2.png"]);
+        terminal.draw(|f| page.render(f, area))?;
+        assert!(page.take_content_type_requests().is_empty());
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 3 ┐",
+            "│  dir1/                                                   │",
+            "│  file1.txt       text/plain                       1 KiB  │",
+            "│  file2.png       ...                              2 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        page.set_content_type("file2.png".to_string(), "image/png".to_string());
+        terminal.draw(|f| page.render(f, area))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 3 ┐",
+            "│  dir1/                                                   │",
+            "│  file1.txt       text/plain                       1 KiB  │",
+            "│  file2.png       image/png                        2 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    fn recv_content_types_request(rx: &event::Receiver) {
+        loop {
+            // skip other events (e.g. errors from reading terminal events)
+            if let AppEventType::ObjectListLoadContentTypes = rx.recv() {
+                return;
+            }
+        }
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(60, 10);
         let mut terminal = Terminal::new(backend)?;
@@ -1,15 +1,22 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{layout::Rect, Frame};
+use regex::{Regex, RegexBuilder};
 
 use crate::{
     config::PreviewConfig,
     event::{AppEventType, Sender},
+    format::ContainerFormat,
     key_code, key_code_char,
     object::{FileDetail, RawObject},
     pages::util::{build_helps, build_short_helps},
     widget::{InputDialog, InputDialogState, TextPreview, TextPreviewState},
 };
 
+// unique across all the preview pages, so that the follow results are delivered to the page which requested them
+static NEXT_FOLLOW_ID: AtomicUsize = AtomicUsize::new(1);
+
 #[derive(Debug)]
 pub struct ObjectPreviewPage {
     state: TextPreviewState,
@@ -19,8 +26,20 @@ pub struct ObjectPreviewPage {
     object: RawObject,
     path: String,
 
+    preview_type: PreviewType,
+    preview_config: PreviewConfig,
+
     view_state: ViewState,
 
+    search_input_state: InputDialogState,
+    search_mode: SearchMode,
+    searching: bool,
+
+    following: bool,
+    follow_id: usize,
+    // ETag of the followed content, none until the object is read again in follow mode
+    follow_e_tag: Option<String>,
+
     tx: Sender,
 }
 
@@ -29,6 +48,54 @@ enum ViewState {
     #[default]
     Default,
     SaveDialog(InputDialogState),
+    SearchDialog,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum SearchMode {
+    #[default]
+    IgnoreCase,
+    Regex,
+}
+
+impl SearchMode {
+    fn toggle(self) -> SearchMode {
+        match self {
+            SearchMode::IgnoreCase => SearchMode::Regex,
+            SearchMode::Regex => SearchMode::IgnoreCase,
+        }
+    }
+
+    fn dialog_title(&self) -> &'static str {
+        match self {
+            SearchMode::IgnoreCase => "Search (ignore case)",
+            SearchMode::Regex => "Search (regex)",
+        }
+    }
+
+    fn build_regex(&self, query: &str) -> Result<Regex, regex::Error> {
+        match self {
+            SearchMode::IgnoreCase => RegexBuilder::new(&regex::escape(query))
+                .case_insensitive(true)
+                .build(),
+            SearchMode::Regex => Regex::new(query),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PreviewType {
+    Text,
+    Container(ContainerFormat),
+}
+
+impl PreviewType {
+    fn detect(file_detail: &FileDetail, object: &RawObject) -> PreviewType {
+        match ContainerFormat::detect(&file_detail.name, &object.bytes) {
+            Some(format) => PreviewType::Container(format),
+            None => PreviewType::Text,
+        }
+    }
 }
 
 impl ObjectPreviewPage {
@@ -40,7 +107,9 @@ impl ObjectPreviewPage {
         preview_config: PreviewConfig,
         tx: Sender,
     ) -> Self {
-        let (state, msg) = TextPreviewState::new(&file_detail, &object, preview_config.highlight);
+        let preview_type = PreviewType::detect(&file_detail, &object);
+        let (state, msg) =
+            build_preview_state(&file_detail, &object, preview_type, &preview_config);
         if let Some(msg) = msg {
             tx.send(AppEventType::NotifyWarn(msg));
         }
@@ -51,7 +120,15 @@ impl ObjectPreviewPage {
             file_detail,
             file_version_id,
             path,
+            preview_type,
+            preview_config,
             view_state: ViewState::Default,
+            search_input_state: InputDialogState::default(),
+            search_mode: SearchMode::default(),
+            searching: false,
+            following: false,
+            follow_id: 0,
+            follow_e_tag: None,
             tx,
         }
     }
@@ -60,7 +137,11 @@ impl ObjectPreviewPage {
         match self.view_state {
             ViewState::Default => match key {
                 key_code!(KeyCode::Esc) => {
-                    self.tx.send(AppEventType::Quit);
+                    if self.searching {
+                        self.clear_search();
+                    } else {
+                        self.tx.send(AppEventType::Quit);
+                    }
                 }
                 key_code!(KeyCode::Backspace) => {
                     self.tx.send(AppEventType::CloseCurrentPage);
@@ -92,9 +173,32 @@ impl ObjectPreviewPage {
                 key_code_char!('w') => {
                     self.state.scroll_lines_state.toggle_wrap();
                 }
-                key_code_char!('n') => {
+                key_code_char!('#') => {
                     self.state.scroll_lines_state.toggle_number();
                 }
+                key_code_char!('/') => {
+                    self.open_search_dialog();
+                }
+                key_code_char!('n') => {
+                    // `n` keeps toggling the number unless a search is active
+                    if self.searching {
+                        self.state.scroll_lines_state.select_next_match();
+                    } else {
+                        self.state.scroll_lines_state.toggle_number();
+                    }
+                }
+                key_code_char!('N') => {
+                    self.state.scroll_lines_state.select_prev_match();
+                }
+                key_code_char!('F') => {
+                    self.toggle_follow();
+                }
+                key_code_char!('e') => {
+                    self.edit();
+                }
+                key_code_char!('o') => {
+                    self.open();
+                }
                 key_code_char!('s') => {
                     self.download();
                 }
@@ -121,6 +225,21 @@ impl ObjectPreviewPage {
                     state.handle_key_event(key);
                 }
             },
+            ViewState::SearchDialog => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_search_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.apply_search();
+                }
+                key_code!(KeyCode::Tab) => {
+                    self.search_mode = self.search_mode.toggle();
+                }
+                // all the characters including `?` are typed into the input
+                _ => {
+                    self.search_input_state.handle_key_event(key);
+                }
+            },
         }
     }
 
@@ -128,7 +247,8 @@ impl ObjectPreviewPage {
         let preview = TextPreview::new(
             self.file_detail.name.as_str(),
             self.file_version_id.as_deref(),
-        );
+        )
+        .following(self.following);
         f.render_stateful_widget(preview, area, &mut self.state);
 
         if let ViewState::SaveDialog(state) = &mut self.view_state {
@@ -138,27 +258,70 @@ impl ObjectPreviewPage {
             let (cursor_x, cursor_y) = state.cursor();
             f.set_cursor(cursor_x, cursor_y);
         }
+
+        if let ViewState::SearchDialog = self.view_state {
+            let search_dialog = InputDialog::default()
+                .title(self.search_mode.dialog_title())
+                .max_width(40);
+            f.render_stateful_widget(search_dialog, area, &mut self.search_input_state);
+
+            let (cursor_x, cursor_y) = self.search_input_state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
     }
 
     pub fn helps(&self) -> Vec<String> {
         let helps: &[(&[&str], &str)] = match self.view_state {
-            ViewState::Default => &[
-                (&["Esc", "Ctrl-c"], "Quit app"),
-                (&["j/k"], "Scroll forward/backward"),
-                (&["f/b"], "Scroll page forward/backward"),
-                (&["g/G"], "Scroll to top/end"),
-                (&["h/l"], "Scroll left/right"),
-                (&["w"], "Toggle wrap"),
-                (&["n"], "Toggle number"),
-                (&["Backspace"], "Close preview"),
-                (&["s"], "Download object"),
-                (&["S"], "Download object as"),
-            ],
+            ViewState::Default => {
+                if self.searching {
+                    &[
+                        (&["Ctrl-c"], "Quit app"),
+                        (&["Esc"], "Clear search"),
+                        (&["j/k"], "Scroll forward/backward"),
+                        (&["f/b"], "Scroll page forward/backward"),
+                        (&["g/G"], "Scroll to top/end"),
+                        (&["h/l"], "Scroll left/right"),
+                        (&["w"], "Toggle wrap"),
+                        (&["#"], "Toggle number"),
+                        (&["/"], "Search text"),
+                        (&["n/N"], "Go to next/previous match"),
+                        (&["F"], "Toggle follow mode"),
+                        (&["e"], "Edit object in editor"),
+                        (&["o"], "Open object with command"),
+                        (&["Backspace"], "Close preview"),
+                        (&["s"], "Download object"),
+                        (&["S"], "Download object as"),
+                    ]
+                } else {
+                    &[
+                        (&["Esc", "Ctrl-c"], "Quit app"),
+                        (&["j/k"], "Scroll forward/backward"),
+                        (&["f/b"], "Scroll page forward/backward"),
+                        (&["g/G"], "Scroll to top/end"),
+                        (&["h/l"], "Scroll left/right"),
+                        (&["w"], "Toggle wrap"),
+                        (&["n", "#"], "Toggle number"),
+                        (&["/"], "Search text"),
+                        (&["F"], "Toggle follow mode"),
+                        (&["e"], "Edit object in editor"),
+                        (&["o"], "Open object with command"),
+                        (&["Backspace"], "Close preview"),
+                        (&["s"], "Download object"),
+                        (&["S"], "Download object as"),
+                    ]
+                }
+            }
             ViewState::SaveDialog(_) => &[
                 (&["Ctrl-c"], "Quit app"),
                 (&["Esc"], "Close save dialog"),
                 (&["Enter"], "Download object"),
             ],
+            ViewState::SearchDialog => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close search dialog"),
+                (&["Enter"], "Search text"),
+                (&["Tab"], "Toggle ignore case/regex"),
+            ],
         };
 
         build_helps(helps)
@@ -166,19 +329,39 @@ impl ObjectPreviewPage {
 
     pub fn short_helps(&self) -> Vec<(String, usize)> {
         let helps: &[(&[&str], &str, usize)] = match self.view_state {
-            ViewState::Default => &[
-                (&["Esc"], "Quit", 0),
-                (&["j/k"], "Scroll", 2),
-                (&["g/G"], "Top/End", 4),
-                (&["s/S"], "Download", 3),
-                (&["Backspace"], "Close", 2),
-                (&["?"], "Help", 0),
-            ],
+            ViewState::Default => {
+                if self.searching {
+                    &[
+                        (&["Esc"], "Clear search", 0),
+                        (&["j/k"], "Scroll", 2),
+                        (&["n/N"], "Next/Prev", 1),
+                        (&["g/G"], "Top/End", 4),
+                        (&["s/S"], "Download", 3),
+                        (&["Backspace"], "Close", 2),
+                        (&["?"], "Help", 0),
+                    ]
+                } else {
+                    &[
+                        (&["Esc"], "Quit", 0),
+                        (&["j/k"], "Scroll", 2),
+                        (&["g/G"], "Top/End", 4),
+                        (&["/"], "Search", 5),
+                        (&["s/S"], "Download", 3),
+                        (&["Backspace"], "Close", 2),
+                        (&["?"], "Help", 0),
+                    ]
+                }
+            }
             ViewState::SaveDialog(_) => &[
                 (&["Esc"], "Close", 2),
                 (&["Enter"], "Download", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::SearchDialog => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Search", 1),
+                (&["Tab"], "Mode", 3),
+            ],
         };
 
         build_short_helps(helps)
@@ -194,6 +377,163 @@ impl ObjectPreviewPage {
         self.view_state = ViewState::Default;
     }
 
+    fn open_search_dialog(&mut self) {
+        self.view_state = ViewState::SearchDialog;
+    }
+
+    fn close_search_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+        self.clear_search();
+    }
+
+    fn apply_search(&mut self) {
+        self.view_state = ViewState::Default;
+
+        let query = self.search_input_state.input();
+        if query.is_empty() {
+            self.clear_search();
+            return;
+        }
+
+        match self.search_mode.build_regex(query) {
+            Ok(re) => {
+                self.state.scroll_lines_state.search(&re);
+                self.searching = true;
+            }
+            Err(_) => {
+                let msg = format!("Invalid search pattern: {}", query);
+                self.tx.send(AppEventType::NotifyWarn(msg));
+                self.clear_search();
+            }
+        }
+    }
+
+    fn clear_search(&mut self) {
+        self.search_input_state.clear_input();
+        self.state.scroll_lines_state.clear_search();
+        self.searching = false;
+    }
+
+    fn toggle_follow(&mut self) {
+        if self.following {
+            self.stop_follow();
+            return;
+        }
+        if self.file_version_id.is_some() {
+            let msg = "Follow mode is not available for a specific version".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        if let PreviewType::Container(format) = self.preview_type {
+            let msg = format!("Follow mode is not available for {} files", format.str());
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+
+        // results of the previous follow requests are ignored
+        self.follow_id = NEXT_FOLLOW_ID.fetch_add(1, Ordering::Relaxed);
+        self.following = true;
+        self.follow_e_tag = None;
+        self.state.scroll_lines_state.scroll_to_end();
+        self.request_follow();
+    }
+
+    pub fn stop_follow(&mut self) {
+        self.following = false;
+    }
+
+    pub fn is_following(&self, follow_id: usize) -> bool {
+        self.following && self.follow_id == follow_id
+    }
+
+    pub fn follow_object(
+        &mut self,
+        obj: RawObject,
+        e_tag: Option<String>,
+        follow_id: usize,
+        reset: bool,
+    ) {
+        if !self.is_following(follow_id) {
+            return;
+        }
+        self.follow_e_tag = e_tag;
+
+        let highlight = self.preview_config.highlight;
+        if reset {
+            self.object = obj;
+            self.state
+                .set_text(&self.file_detail, &self.object.bytes, highlight);
+        } else if !obj.bytes.is_empty() {
+            // the last line may be incomplete, so render it again with the new bytes
+            let bytes = &self.object.bytes;
+            let line_start = bytes.iter().rposition(|b| *b == b'\n').map_or(0, |i| i + 1);
+            let replace_last = line_start < bytes.len() || bytes.is_empty();
+
+            self.object.bytes.extend(obj.bytes);
+            let new_bytes = &self.object.bytes[line_start..];
+            self.state
+                .append_text(&self.file_detail, new_bytes, highlight, replace_last);
+        }
+        self.file_detail.size_byte = self.object.bytes.len();
+
+        self.state.scroll_lines_state.scroll_to_end();
+        self.request_follow();
+    }
+
+    fn edit(&self) {
+        if self.file_version_id.is_some() {
+            let msg = "Editing is not available for a specific version".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let file_detail = self.file_detail.clone();
+        self.tx.send(AppEventType::EditObject(file_detail));
+    }
+
+    fn open(&self) {
+        let file_detail = self.file_detail.clone();
+        let version_id = self.file_version_id.clone();
+        self.tx
+            .send(AppEventType::OpenObject(file_detail, version_id));
+    }
+
+    pub fn update_object(&mut self, file_detail: FileDetail, object: RawObject) {
+        if self.file_version_id.is_some() {
+            // the preview of a specific version is not changed
+            return;
+        }
+        self.preview_type = PreviewType::detect(&file_detail, &object);
+        match self.preview_type {
+            PreviewType::Text => {
+                let highlight = self.preview_config.highlight;
+                self.state.set_text(&file_detail, &object.bytes, highlight);
+            }
+            PreviewType::Container(_) => {
+                let (state, _) = build_preview_state(
+                    &file_detail,
+                    &object,
+                    self.preview_type,
+                    &self.preview_config,
+                );
+                self.state = state;
+            }
+        }
+        self.file_detail = file_detail;
+        self.object = object;
+    }
+
+    fn request_follow(&self) {
+        let file_detail = self.file_detail.clone();
+        let offset = self.object.bytes.len();
+        let e_tag = self.follow_e_tag.clone();
+        self.tx.send(AppEventType::PreviewFollowObject(
+            file_detail,
+            self.follow_id,
+            offset,
+            e_tag,
+        ));
+    }
+
     fn download(&self) {
         // object has been already downloaded, so send completion event to save file
         let obj = self.object.clone();
@@ -217,6 +557,26 @@ impl ObjectPreviewPage {
     }
 }
 
+fn build_preview_state(
+    file_detail: &FileDetail,
+    object: &RawObject,
+    preview_type: PreviewType,
+    preview_config: &PreviewConfig,
+) -> (TextPreviewState, Option<String>) {
+    match preview_type {
+        PreviewType::Text => TextPreviewState::new(file_detail, object, preview_config.highlight),
+        PreviewType::Container(format) => match format.read(&object.bytes) {
+            Ok(content) => (TextPreviewState::from_container(&content), None),
+            Err(e) => {
+                // If the file cannot be decoded, display it as text
+                let (state, _) = TextPreviewState::new(file_detail, object, false);
+                let msg = format!("Failed to decode {} file: {}", format.str(), e.msg);
+                (state, Some(msg))
+            }
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{event, set_cells};
@@ -360,6 +720,211 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_with_search() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let file_detail = file_detail();
+        let preview = [
+            "Hello, world!",
+            "This is a test file.",
+            "This FILE is used for testing.",
+            "Thank you!",
+        ];
+        let object = object(&preview);
+        let file_path = "file.txt".to_string();
+        let preview_config = PreviewConfig::default();
+        let mut page =
+            ObjectPreviewPage::new(file_detail, None, object, file_path, preview_config, tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('/')));
+        for c in "file".chars() {
+            page.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        page.handle_key(KeyEvent::from(KeyCode::Enter));
+        page.handle_key(KeyEvent::from(KeyCode::Char('n')));
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 30, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.txt] (2/2)────┐",
+            "│ 3 This FILE is used for    │",
+            "│   testing.                 │",
+            "│ 4 Thank you!               │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2], [1, 3]) => fg: Color::DarkGray,
+            (9..13, [1]) => bg: Color::LightRed, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('n')));
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 30, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.txt] (1/2)────┐",
+            "│ 2 This is a test file.     │",
+            "│ 3 This FILE is used for    │",
+            "│   testing.                 │",
+            "│ 4 Thank you!               │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2], [1, 2, 4]) => fg: Color::DarkGray,
+            (19..23, [1]) => bg: Color::LightRed, fg: Color::Black,
+            (9..13, [2]) => bg: Color::Yellow, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_with_question_mark() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let file_detail = file_detail();
+        let preview = ["What?", "Why?"];
+        let object = object(&preview);
+        let file_path = "file.txt".to_string();
+        let preview_config = PreviewConfig::default();
+        let mut page =
+            ObjectPreviewPage::new(file_detail, None, object, file_path, preview_config, tx);
+
+        // `n` toggles the number off without the search
+        page.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('/')));
+        // `?` is typed into the input instead of opening the help
+        page.handle_key(KeyEvent::from(KeyCode::Char('?')));
+        page.handle_key(KeyEvent::from(KeyCode::Enter));
+        page.handle_key(KeyEvent::from(KeyCode::Char('n')));
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 30, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.txt] (2/2)────┐",
+            "│ Why?                       │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (5..6, [1]) => bg: Color::LightRed, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_follow() -> std::io::Result<()> {
+        let (tx, rx) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let file_detail = file_detail();
+        let preview = ["line 1", "line 2"];
+        let object = object(&preview);
+        let file_path = "file.txt".to_string();
+        let preview_config = PreviewConfig::default();
+        let mut page =
+            ObjectPreviewPage::new(file_detail, None, object, file_path, preview_config, tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('F')));
+        let (follow_id, offset, e_tag) = recv_follow_request(&rx);
+        assert_eq!((offset, e_tag), (13, None));
+        assert!(page.is_following(follow_id));
+
+        // the first read in follow mode loads the object again with its ETag
+        let e_tag = Some("\"abc\"".to_string());
+        let obj = RawObject {
+            bytes: b"line 1\nline 2".to_vec(),
+        };
+        page.follow_object(obj, e_tag.clone(), follow_id, true);
+        assert_eq!(recv_follow_request(&rx), (follow_id, 13, e_tag.clone()));
+
+        let obj = RawObject {
+            bytes: b" appended\nline 3\n".to_vec(),
+        };
+        page.follow_object(obj, e_tag.clone(), follow_id, false);
+        assert_eq!(recv_follow_request(&rx), (follow_id, 30, e_tag));
+
+        // ignore the result of the stale request
+        let obj = RawObject {
+            bytes: b"stale".to_vec(),
+        };
+        page.follow_object(obj, None, follow_id - 1, false);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('g')));
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 30, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.txt] (followin┐",
+            "│ 1 line 1                   │",
+            "│ 2 line 2 appended          │",
+            "│ 3 line 3                   │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2], [1, 2, 3]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    fn recv_follow_request(rx: &event::Receiver) -> (usize, usize, Option<String>) {
+        loop {
+            // skip other events (e.g. errors from reading terminal events)
+            if let AppEventType::PreviewFollowObject(_, follow_id, offset, e_tag) = rx.recv() {
+                return (follow_id, offset, e_tag);
+            }
+        }
+    }
+
     fn parse_datetime(s: &str) -> DateTime<Local> {
         NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
             .unwrap()
@@ -1,12 +1,21 @@
+use std::path::PathBuf;
+
 use crate::{
+    bookmark::Bookmark,
     config::PreviewConfig,
+    disk_usage::UsageNode,
+    duplicate::DuplicateGroup,
     event::Sender,
-    object::{BucketItem, FileDetail, FileVersion, ObjectItem, RawObject},
+    history::VisitedLocation,
+    object::{BucketItem, FileDetail, FileVersion, ObjectItem, ObjectKey, RawObject},
     pages::{
-        bucket_list::BucketListPage, help::HelpPage, initializing::InitializingPage,
-        object_detail::ObjectDetailPage, object_list::ObjectListPage,
+        bookmarks::BookmarksPage, bucket_list::BucketListPage, bucket_stats::BucketStatsPage,
+        disk_usage::DiskUsagePage, duplicates::DuplicatesPage, help::HelpPage,
+        history::HistoryPage, initializing::InitializingPage, local_list::LocalListPage,
+        object_detail::ObjectDetailPage, object_diff::ObjectDiffPage, object_list::ObjectListPage,
         object_preview::ObjectPreviewPage,
     },
+    stats::BucketStats,
     widget::ScrollListState,
 };
 
@@ -14,9 +23,16 @@ use crate::{
 pub enum Page {
     Initializing(Box<InitializingPage>),
     BucketList(Box<BucketListPage>),
+    BucketStats(Box<BucketStatsPage>),
     ObjectList(Box<ObjectListPage>),
     ObjectDetail(Box<ObjectDetailPage>),
     ObjectPreview(Box<ObjectPreviewPage>),
+    ObjectDiff(Box<ObjectDiffPage>),
+    Bookmarks(Box<BookmarksPage>),
+    History(Box<HistoryPage>),
+    LocalList(Box<LocalListPage>),
+    DiskUsage(Box<DiskUsagePage>),
+    Duplicates(Box<DuplicatesPage>),
     Help(Box<HelpPage>),
 }
 
@@ -29,6 +45,10 @@ impl Page {
         Self::BucketList(Box::new(BucketListPage::new(bucket_items, tx)))
     }
 
+    pub fn of_bucket_stats(stats: BucketStats, tx: Sender) -> Self {
+        Self::BucketStats(Box::new(BucketStatsPage::new(stats, tx)))
+    }
+
     pub fn of_object_list(object_items: Vec<ObjectItem>, tx: Sender) -> Self {
         Self::ObjectList(Box::new(ObjectListPage::new(object_items, tx)))
     }
@@ -67,10 +87,57 @@ impl Page {
         )))
     }
 
+    pub fn of_object_diff(
+        file_detail: FileDetail,
+        e_tag: String,
+        original: RawObject,
+        edited: RawObject,
+        tx: Sender,
+    ) -> Self {
+        Self::ObjectDiff(Box::new(ObjectDiffPage::new(
+            file_detail,
+            e_tag,
+            original,
+            edited,
+            tx,
+        )))
+    }
+
+    pub fn of_bookmarks(bookmarks: Vec<Bookmark>, tx: Sender) -> Self {
+        Self::Bookmarks(Box::new(BookmarksPage::new(bookmarks, tx)))
+    }
+
+    pub fn of_history(locations: Vec<VisitedLocation>, tx: Sender) -> Self {
+        Self::History(Box::new(HistoryPage::new(locations, tx)))
+    }
+
+    pub fn of_local_list(path: PathBuf, items: Vec<ObjectItem>, tx: Sender) -> Self {
+        Self::LocalList(Box::new(LocalListPage::new(path, items, tx)))
+    }
+
+    pub fn of_disk_usage(root: UsageNode, key: ObjectKey, tx: Sender) -> Self {
+        Self::DiskUsage(Box::new(DiskUsagePage::new(root, key, tx)))
+    }
+
+    pub fn of_duplicates(key: ObjectKey, groups: Vec<DuplicateGroup>, tx: Sender) -> Self {
+        Self::Duplicates(Box::new(DuplicatesPage::new(key, groups, tx)))
+    }
+
     pub fn of_help(helps: Vec<String>, tx: Sender) -> Self {
         Self::Help(Box::new(HelpPage::new(helps, tx)))
     }
 
+    // tab and pane keys are available only while browsing without any dialog
+    pub fn accepts_global_keys(&self) -> bool {
+        match self {
+            Page::BucketList(page) => page.is_default_view(),
+            Page::ObjectList(page) => page.is_default_view(),
+            Page::ObjectDetail(page) => page.is_default_view(),
+            Page::LocalList(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn as_bucket_list(&self) -> &BucketListPage {
         match self {
             Self::BucketList(page) => page,
@@ -85,6 +152,20 @@ impl Page {
         }
     }
 
+    pub fn as_mut_bucket_list(&mut self) -> &mut BucketListPage {
+        match self {
+            Self::BucketList(page) => &mut *page,
+            page => panic!("Page is not BucketList: {:?}", page),
+        }
+    }
+
+    pub fn as_mut_object_list(&mut self) -> &mut ObjectListPage {
+        match self {
+            Self::ObjectList(page) => &mut *page,
+            page => panic!("Page is not ObjectList: {:?}", page),
+        }
+    }
+
     pub fn as_mut_object_detail(&mut self) -> &mut ObjectDetailPage {
         match self {
             Self::ObjectDetail(page) => &mut *page,
@@ -140,7 +221,15 @@ impl PageStack {
         self.stack.first().unwrap()
     }
 
-    pub fn iter(&self) -> std::slice::Iter<Page> {
+    pub fn head_mut(&mut self) -> &mut Page {
+        self.stack.first_mut().unwrap()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Page> {
         self.stack.iter()
     }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Page> {
+        self.stack.iter_mut()
+    }
 }
@@ -1,21 +1,49 @@
-use crossterm::event::KeyCode;
+use crossterm::{
+    event::{KeyCode, KeyEvent},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
 use ratatui::{backend::Backend, Terminal};
-use std::io::Result;
+use std::{
+    io::{stdout, Result},
+    process::Command,
+};
 
 use crate::{
     app::{App, Notification},
+    error,
     event::{AppEventType, Receiver},
+    external::run_command,
     key_code, key_code_char,
     pages::page::Page,
     ui,
 };
 
+fn handle_page_key(app: &mut App, key: KeyEvent) {
+    match app.page_stack.current_page_mut() {
+        Page::Initializing(page) => page.handle_key(key),
+        Page::BucketList(page) => page.handle_key(key),
+        Page::BucketStats(page) => page.handle_key(key),
+        Page::ObjectList(page) => page.handle_key(key),
+        Page::ObjectDetail(page) => page.handle_key(key),
+        Page::ObjectPreview(page) => page.handle_key(key),
+        Page::ObjectDiff(page) => page.handle_key(key),
+        Page::Bookmarks(page) => page.handle_key(key),
+        Page::History(page) => page.handle_key(key),
+        Page::LocalList(page) => page.handle_key(key),
+        Page::DiskUsage(page) => page.handle_key(key),
+        Page::Duplicates(page) => page.handle_key(key),
+        Page::Help(page) => page.handle_key(key),
+    }
+}
+
 pub async fn run<B: Backend>(
     app: &mut App,
     terminal: &mut Terminal<B>,
     rx: Receiver,
 ) -> Result<()> {
     loop {
+        app.update_history();
         terminal.draw(|f| ui::render(f, app))?;
 
         let event = rx.recv();
@@ -58,30 +86,53 @@ pub async fn run<B: Backend>(
                     continue;
                 }
 
-                match app.page_stack.current_page_mut() {
-                    Page::Initializing(page) => page.handle_key(key),
-                    Page::BucketList(page) => page.handle_key(key),
-                    Page::ObjectList(page) => page.handle_key(key),
-                    Page::ObjectDetail(page) => page.handle_key(key),
-                    Page::ObjectPreview(page) => page.handle_key(key),
-                    Page::Help(page) => page.handle_key(key),
+                if app.handle_pane_key(key) {
+                    continue;
+                }
+
+                for key in app.handle_tab_key(key) {
+                    handle_page_key(app, key);
                 }
             }
             AppEventType::Resize(width, height) => {
                 app.resize(width, height);
             }
-            AppEventType::Initialize(client, bucket) => {
-                app.initialize(client, bucket);
+            AppEventType::Initialize(client, bucket, location) => {
+                app.initialize(client, bucket, location);
             }
             AppEventType::CompleteInitialize(result) => {
                 app.complete_initialize(result);
             }
+            AppEventType::BucketListLoadRegions(names) => {
+                app.load_bucket_regions(names);
+            }
+            AppEventType::CompleteLoadBucketRegions(result) => {
+                app.complete_load_bucket_regions(result);
+            }
             AppEventType::LoadObjects => {
                 app.load_objects();
             }
             AppEventType::CompleteLoadObjects(result) => {
                 app.complete_load_objects(result);
             }
+            AppEventType::LoadObjectsRecursive => {
+                app.load_objects_recursive();
+            }
+            AppEventType::CompleteLoadObjectsRecursive(result) => {
+                app.complete_load_objects_recursive(result);
+            }
+            AppEventType::LoadFinderItems => {
+                app.load_finder_items();
+            }
+            AppEventType::SearchFinderItems(query) => {
+                app.search_finder_items(query);
+            }
+            AppEventType::CompleteLoadFinderItems(result) => {
+                app.complete_load_finder_items(result);
+            }
+            AppEventType::CompleteMoveTo(result) => {
+                app.complete_move_to(result);
+            }
             AppEventType::LoadObject => {
                 app.load_object();
             }
@@ -103,6 +154,47 @@ pub async fn run<B: Backend>(
             AppEventType::CompletePreviewObject(result) => {
                 app.complete_preview_object(result);
             }
+            AppEventType::PreviewFollowObject(file_detail, follow_id, offset, e_tag) => {
+                app.preview_follow_object(file_detail, follow_id, offset, e_tag);
+            }
+            AppEventType::CompletePreviewFollowObject(follow_id, result) => {
+                app.complete_preview_follow_object(follow_id, result);
+            }
+            AppEventType::EditObject(file_detail) => {
+                app.edit_object(file_detail);
+            }
+            AppEventType::CompleteEditObject(result) => {
+                app.complete_edit_object(result);
+            }
+            AppEventType::OpenEditor(editing) => {
+                let result = suspend_and_run(terminal, &rx, editing.editor_command())?;
+                app.complete_open_editor(*editing, result);
+            }
+            AppEventType::UploadEditedObject(file_detail, e_tag, obj) => {
+                app.upload_edited_object(file_detail, e_tag, obj);
+            }
+            AppEventType::CompleteUploadEditedObject(result) => {
+                app.complete_upload_edited_object(result);
+            }
+            AppEventType::OpenObject(file_detail, version_id) => {
+                app.open_object(file_detail, version_id);
+            }
+            AppEventType::CompleteOpenObject(result) => {
+                app.complete_open_object(result);
+            }
+            AppEventType::OpenWithCommand(opening) => {
+                let result = suspend_and_run(terminal, &rx, opening.command())?;
+                app.complete_open_with_command(*opening, result);
+            }
+            AppEventType::OpenBucketStats => {
+                app.open_bucket_stats();
+            }
+            AppEventType::LoadBucketStats(bucket_name) => {
+                app.load_bucket_stats(bucket_name);
+            }
+            AppEventType::CompleteLoadBucketStats(result) => {
+                app.complete_load_bucket_stats(result);
+            }
             AppEventType::BucketListMoveDown => {
                 app.bucket_list_move_down();
             }
@@ -112,6 +204,96 @@ pub async fn run<B: Backend>(
             AppEventType::ObjectListMoveUp => {
                 app.object_list_move_up();
             }
+            AppEventType::ObjectListMoveToObject(key) => {
+                app.object_list_move_to_object(key);
+            }
+            AppEventType::ObjectListSaveSort(sort) => {
+                app.object_list_save_sort(sort);
+            }
+            AppEventType::ObjectListDownloadObjects(items) => {
+                app.object_list_download_objects(items);
+            }
+            AppEventType::ObjectListDeleteObjects(items) => {
+                app.object_list_delete_objects(items);
+            }
+            AppEventType::ObjectListCopyObjects(items, input) => {
+                app.object_list_copy_objects(items, input);
+            }
+            AppEventType::ObjectListChangeStorageClass(items, storage_class) => {
+                app.object_list_change_storage_class(items, storage_class);
+            }
+            AppEventType::CompleteBulkObjects(result) => {
+                app.complete_bulk_objects(result);
+            }
+            AppEventType::CompleteTransfer(result) => {
+                app.complete_transfer(result);
+            }
+            AppEventType::ObjectListCalculatePrefixSizes(names) => {
+                app.object_list_calculate_prefix_sizes(names);
+            }
+            AppEventType::CompleteCalculatePrefixSize(result) => {
+                app.complete_calculate_prefix_size(result);
+            }
+            AppEventType::ObjectListLoadContentTypes => {
+                app.object_list_load_content_types();
+            }
+            AppEventType::CompleteLoadContentTypes(result) => {
+                app.complete_load_content_types(result);
+            }
+            AppEventType::OpenDiskUsage => {
+                app.open_disk_usage();
+            }
+            AppEventType::CompleteOpenDiskUsage(result) => {
+                app.complete_open_disk_usage(result);
+            }
+            AppEventType::DiskUsageDeleteItem(key, item) => {
+                app.disk_usage_delete_item(key, item);
+            }
+            AppEventType::CompleteDiskUsageDelete(result) => {
+                app.complete_disk_usage_delete(result);
+            }
+            AppEventType::OpenDuplicates => {
+                app.open_duplicates();
+            }
+            AppEventType::CompleteOpenDuplicates(result) => {
+                app.complete_open_duplicates(result);
+            }
+            AppEventType::DuplicatesDeleteObjects(bucket_name, keys) => {
+                app.duplicates_delete_objects(bucket_name, keys);
+            }
+            AppEventType::CompleteDuplicatesDelete(result) => {
+                app.complete_duplicates_delete(result);
+            }
+            AppEventType::MoveToInput(input) => {
+                app.move_to_input(input);
+            }
+            AppEventType::AddBookmark => {
+                app.add_bookmark();
+            }
+            AppEventType::OpenBookmarks => {
+                app.open_bookmarks();
+            }
+            AppEventType::BookmarksMoveTo(location) => {
+                app.bookmarks_move_to(location);
+            }
+            AppEventType::RenameBookmark(index, label) => {
+                app.rename_bookmark(index, label);
+            }
+            AppEventType::DeleteBookmark(index) => {
+                app.delete_bookmark(index);
+            }
+            AppEventType::HistoryBack => {
+                app.history_back();
+            }
+            AppEventType::HistoryForward => {
+                app.history_forward();
+            }
+            AppEventType::OpenHistory => {
+                app.open_history();
+            }
+            AppEventType::HistoryMoveTo(location) => {
+                app.history_move_to(location);
+            }
             AppEventType::BackToBucketList => {
                 app.back_to_bucket_list();
             }
@@ -163,3 +345,25 @@ pub async fn run<B: Backend>(
         }
     }
 }
+
+// Run the command with the terminal restored, like when the app exits.
+fn suspend_and_run<B: Backend>(
+    terminal: &mut Terminal<B>,
+    rx: &Receiver,
+    cmd: Command,
+) -> Result<error::Result<()>> {
+    rx.pause_terminal_events();
+    execute!(stdout(), LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+
+    let result = run_command(cmd);
+
+    enable_raw_mode()?;
+    execute!(stdout(), EnterAlternateScreen)?;
+    rx.resume_terminal_events();
+
+    // redraw everything because the screen may have been overwritten
+    terminal.clear()?;
+
+    Ok(result)
+}
@@ -0,0 +1,159 @@
+use std::{cmp::Ordering, collections::HashMap, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{AppError, Result},
+    file::save_binary,
+    widget::ObjectListSortType,
+};
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectListSort {
+    pub sort_type: ObjectListSortType,
+    pub dirs_first: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct SortSettings {
+    // by bucket name
+    sorts: HashMap<String, ObjectListSort>,
+    // None if the file could not be loaded, to avoid overwriting it
+    path: Option<String>,
+}
+
+impl SortSettings {
+    pub fn load(path: &str) -> Result<SortSettings> {
+        let sorts = if Path::new(path).exists() {
+            let bytes = std::fs::read(path)
+                .map_err(|e| AppError::new("Failed to read sort settings", e))?;
+            serde_json::from_slice(&bytes)
+                .map_err(|e| AppError::new("Failed to parse sort settings", e))?
+        } else {
+            HashMap::new()
+        };
+        Ok(SortSettings {
+            sorts,
+            path: Some(path.to_string()),
+        })
+    }
+
+    pub fn get(&self, bucket_name: &str) -> Option<ObjectListSort> {
+        self.sorts.get(bucket_name).copied()
+    }
+
+    pub fn set(&mut self, bucket_name: String, sort: ObjectListSort) -> Result<()> {
+        if self.sorts.get(&bucket_name) == Some(&sort) {
+            return Ok(());
+        }
+        if sort == ObjectListSort::default() {
+            self.sorts.remove(&bucket_name);
+        } else {
+            self.sorts.insert(bucket_name, sort);
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let bytes = serde_json::to_vec_pretty(&self.sorts)
+            .map_err(|e| AppError::new("Failed to serialize sort settings", e))?;
+        save_binary(path, &bytes)
+    }
+}
+
+// compares the digit runs by their numeric values, so that `part-2` comes before `part-10`
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chunks = chunks(a);
+    let mut b_chunks = chunks(b);
+    loop {
+        match (a_chunks.next(), b_chunks.next()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let ord = match (is_digits(x), is_digits(y)) {
+                    (true, true) => {
+                        let x = x.trim_start_matches('0');
+                        let y = y.trim_start_matches('0');
+                        x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+                    }
+                    _ => x.cmp(y),
+                };
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+        }
+    }
+}
+
+// splits `s` into the runs of digits and non-digits
+fn chunks(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let digit = first.is_ascii_digit();
+        let end = rest
+            .find(|c: char| c.is_ascii_digit() != digit)
+            .unwrap_or(rest.len());
+        let (chunk, r) = rest.split_at(end);
+        rest = r;
+        Some(chunk)
+    })
+}
+
+fn is_digits(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_digit())
+}
+
+// the empty string if the name has no extension
+pub fn extension(name: &str) -> &str {
+    name.rsplit_once('.')
+        .map(|(_, ext)| ext)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp() {
+        let mut names = vec![
+            "part-10", "part-2", "part-1", "part-02", "a", "part-1b", "part-", "b10c", "b9z",
+        ];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            ["a", "b9z", "b10c", "part-", "part-1", "part-1b", "part-02", "part-2", "part-10"]
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_sort_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sort.json");
+        let path = path.to_string_lossy();
+
+        let mut settings = SortSettings::load(&path).unwrap();
+        assert_eq!(settings.get("bucket-1"), None);
+
+        let sort = ObjectListSort {
+            sort_type: ObjectListSortType::NaturalDesc,
+            dirs_first: true,
+        };
+        settings.set("bucket-1".to_string(), sort).unwrap();
+
+        let mut settings = SortSettings::load(&path).unwrap();
+        assert_eq!(settings.get("bucket-1"), Some(sort));
+
+        settings
+            .set("bucket-1".to_string(), ObjectListSort::default())
+            .unwrap();
+        let settings = SortSettings::load(&path).unwrap();
+        assert_eq!(settings.get("bucket-1"), None);
+    }
+}
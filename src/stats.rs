@@ -0,0 +1,272 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    path::Path,
+};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{AppError, Result},
+    file::save_binary,
+    object::{ObjectSummary, PrefixSize},
+};
+
+const LARGEST_OBJECTS_COUNT: usize = 10;
+const TOP_PREFIXES_COUNT: usize = 10;
+
+// the labels and the upper bounds (in days) of the age ranges
+const AGE_RANGES: [(&str, Option<i64>); 6] = [
+    ("< 1 day", Some(1)),
+    ("< 1 week", Some(7)),
+    ("< 1 month", Some(30)),
+    ("< 3 months", Some(90)),
+    ("< 1 year", Some(365)),
+    (">= 1 year", None),
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BucketStats {
+    pub bucket_name: String,
+    pub total: PrefixSize,
+    // sorted by size, largest first
+    pub storage_classes: Vec<(String, PrefixSize)>,
+    pub largest_objects: Vec<(String, usize)>,
+    pub ages: Vec<(String, PrefixSize)>,
+    // the prefixes directly under the bucket
+    pub top_prefixes: Vec<(String, PrefixSize)>,
+    pub calculated_at: DateTime<Local>,
+}
+
+#[derive(Debug)]
+pub struct BucketStatsBuilder {
+    bucket_name: String,
+    now: DateTime<Local>,
+    total: PrefixSize,
+    storage_classes: HashMap<String, PrefixSize>,
+    // the smallest of the largest objects is on the top
+    largest_objects: BinaryHeap<Reverse<(usize, String)>>,
+    ages: Vec<PrefixSize>,
+    prefixes: HashMap<String, PrefixSize>,
+}
+
+impl BucketStatsBuilder {
+    pub fn new(bucket_name: String, now: DateTime<Local>) -> BucketStatsBuilder {
+        BucketStatsBuilder {
+            bucket_name,
+            now,
+            total: PrefixSize::default(),
+            storage_classes: HashMap::new(),
+            largest_objects: BinaryHeap::new(),
+            ages: vec![PrefixSize::default(); AGE_RANGES.len()],
+            prefixes: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, obj: &ObjectSummary) {
+        let size = PrefixSize {
+            size_byte: obj.size_byte,
+            count: 1,
+        };
+        self.total += size;
+
+        *self
+            .storage_classes
+            .entry(obj.storage_class.clone())
+            .or_default() += size;
+
+        self.largest_objects
+            .push(Reverse((obj.size_byte, obj.key.clone())));
+        if self.largest_objects.len() > LARGEST_OBJECTS_COUNT {
+            self.largest_objects.pop();
+        }
+
+        let days = (self.now - obj.last_modified).num_days();
+        let i = AGE_RANGES
+            .iter()
+            .position(|(_, max)| match max {
+                Some(max) => days < *max,
+                None => true,
+            })
+            .unwrap();
+        self.ages[i] += size;
+
+        if let Some((prefix, _)) = obj.key.split_once('/') {
+            *self.prefixes.entry(format!("{}/", prefix)).or_default() += size;
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.total.count
+    }
+
+    pub fn build(self) -> BucketStats {
+        let storage_classes = sort_by_size(self.storage_classes.into_iter().collect());
+
+        let mut largest_objects: Vec<(String, usize)> = self
+            .largest_objects
+            .into_iter()
+            .map(|Reverse((size_byte, key))| (key, size_byte))
+            .collect();
+        largest_objects.sort_by_key(|(key, size_byte)| (Reverse(*size_byte), key.clone()));
+
+        let ages = AGE_RANGES
+            .iter()
+            .zip(self.ages)
+            .map(|((label, _), size)| (label.to_string(), size))
+            .collect();
+
+        let mut top_prefixes = sort_by_size(self.prefixes.into_iter().collect());
+        top_prefixes.truncate(TOP_PREFIXES_COUNT);
+
+        BucketStats {
+            bucket_name: self.bucket_name,
+            total: self.total,
+            storage_classes,
+            largest_objects,
+            ages,
+            top_prefixes,
+            calculated_at: self.now,
+        }
+    }
+}
+
+fn sort_by_size(mut sizes: Vec<(String, PrefixSize)>) -> Vec<(String, PrefixSize)> {
+    sizes.sort_by_key(|(name, size)| (Reverse(size.size_byte), name.clone()));
+    sizes
+}
+
+#[derive(Debug, Default)]
+pub struct BucketStatsCache {
+    stats: HashMap<String, BucketStats>,
+    // None if the file could not be loaded, to avoid overwriting it
+    path: Option<String>,
+}
+
+impl BucketStatsCache {
+    pub fn load(path: &str) -> Result<BucketStatsCache> {
+        let stats = if Path::new(path).exists() {
+            let bytes =
+                std::fs::read(path).map_err(|e| AppError::new("Failed to read bucket stats", e))?;
+            serde_json::from_slice(&bytes)
+                .map_err(|e| AppError::new("Failed to parse bucket stats", e))?
+        } else {
+            HashMap::new()
+        };
+        Ok(BucketStatsCache {
+            stats,
+            path: Some(path.to_string()),
+        })
+    }
+
+    pub fn get(&self, bucket_name: &str) -> Option<&BucketStats> {
+        self.stats.get(bucket_name)
+    }
+
+    pub fn set(&mut self, stats: BucketStats) -> Result<()> {
+        self.stats.insert(stats.bucket_name.clone(), stats);
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let bytes = serde_json::to_vec_pretty(&self.stats)
+            .map_err(|e| AppError::new("Failed to serialize bucket stats", e))?;
+        save_binary(path, &bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    fn obj(key: &str, size_byte: usize, days: i64, storage_class: &str) -> ObjectSummary {
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        ObjectSummary {
+            key: key.to_string(),
+            size_byte,
+            last_modified: now - Duration::days(days),
+            e_tag: String::new(),
+            storage_class: storage_class.to_string(),
+        }
+    }
+
+    fn size(size_byte: usize, count: usize) -> PrefixSize {
+        PrefixSize { size_byte, count }
+    }
+
+    fn build_stats() -> BucketStats {
+        let now = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut builder = BucketStatsBuilder::new("bucket-1".to_string(), now);
+        for i in 0..12 {
+            builder.add(&obj(&format!("a/{}.txt", i), i, 0, "STANDARD"));
+        }
+        builder.add(&obj("b/x.bin", 100, 10, "GLACIER"));
+        builder.add(&obj("y.bin", 50, 400, "STANDARD"));
+        builder.build()
+    }
+
+    #[test]
+    fn test_build_stats() {
+        let stats = build_stats();
+
+        assert_eq!(stats.total, size(216, 14));
+        assert_eq!(
+            stats.storage_classes,
+            [
+                ("STANDARD".to_string(), size(116, 13)),
+                ("GLACIER".to_string(), size(100, 1)),
+            ]
+        );
+
+        let largest: Vec<&str> = stats
+            .largest_objects
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect();
+        assert_eq!(largest.len(), LARGEST_OBJECTS_COUNT);
+        assert_eq!(largest[..3], ["b/x.bin", "y.bin", "a/11.txt"]);
+
+        let ages: Vec<PrefixSize> = stats.ages.iter().map(|(_, size)| *size).collect();
+        assert_eq!(
+            ages,
+            [
+                size(66, 12),
+                size(0, 0),
+                size(100, 1),
+                size(0, 0),
+                size(0, 0),
+                size(50, 1)
+            ]
+        );
+
+        assert_eq!(
+            stats.top_prefixes,
+            [
+                ("b/".to_string(), size(100, 1)),
+                ("a/".to_string(), size(66, 12)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.json");
+        let path = path.to_string_lossy();
+
+        let mut cache = BucketStatsCache::load(&path).unwrap();
+        assert_eq!(cache.get("bucket-1"), None);
+
+        let stats = build_stats();
+        cache.set(stats.clone()).unwrap();
+
+        let cache = BucketStatsCache::load(&path).unwrap();
+        assert_eq!(cache.get("bucket-1"), Some(&stats));
+    }
+}
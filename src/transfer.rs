@@ -0,0 +1,399 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client::Client,
+    error::{AppError, Result},
+    file::{copy_local_file, join_key_path, list_local_files, remove_empty_dirs, save_binary},
+    object::{ObjectItem, ObjectKey},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaneLocation {
+    // a prefix
+    S3(ObjectKey),
+    Local(PathBuf),
+}
+
+impl PaneLocation {
+    pub fn display(&self) -> String {
+        match self {
+            PaneLocation::S3(key) => key.uri(false),
+            PaneLocation::Local(path) => path.to_string_lossy().to_string(),
+        }
+    }
+
+    // the local paths are compared after resolving `..` and symbolic links
+    pub fn is_same(&self, other: &PaneLocation) -> bool {
+        match (self, other) {
+            (PaneLocation::Local(a), PaneLocation::Local(b)) => {
+                canonicalize_or_self(a) == canonicalize_or_self(b)
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferMode {
+    Copy,
+    Move,
+    // copies only the objects which are missing or differ in size
+    Sync,
+}
+
+impl TransferMode {
+    fn str(&self) -> &'static str {
+        match self {
+            TransferMode::Copy => "Copy",
+            TransferMode::Move => "Move",
+            TransferMode::Sync => "Sync",
+        }
+    }
+
+    fn progressive_str(&self) -> &'static str {
+        match self {
+            TransferMode::Copy => "Copying",
+            TransferMode::Move => "Moving",
+            TransferMode::Sync => "Syncing",
+        }
+    }
+
+    fn past_str(&self) -> &'static str {
+        match self {
+            TransferMode::Copy => "Copied",
+            TransferMode::Move => "Moved",
+            TransferMode::Sync => "Synced",
+        }
+    }
+}
+
+// `items` are the entries directly under `src`
+pub async fn transfer<F>(
+    client: &Client,
+    mode: TransferMode,
+    src: &PaneLocation,
+    items: Vec<ObjectItem>,
+    dest: &PaneLocation,
+    progress: F,
+) -> Result<String>
+where
+    F: Fn(String),
+{
+    let mut targets = list_targets(client, src, items.clone()).await?;
+    let total = targets.len();
+    if mode == TransferMode::Sync {
+        let existing = list_all_files(client, dest).await?;
+        targets.retain(|(name, size_byte)| existing.get(name) != Some(size_byte));
+    }
+
+    for (i, (name, size_byte)) in targets.iter().enumerate() {
+        let msg = format!(
+            "{} {}/{} objects",
+            mode.progressive_str(),
+            i + 1,
+            targets.len()
+        );
+        progress(msg);
+        transfer_file(client, src, dest, name, *size_byte).await?;
+    }
+
+    if mode == TransferMode::Move {
+        delete_items(client, src, &items, &targets).await?;
+    }
+
+    let msg = match mode {
+        TransferMode::Sync => format!(
+            "Synced {} of {} objects to {}",
+            targets.len(),
+            total,
+            dest.display()
+        ),
+        _ => format!(
+            "{} {} objects to {}",
+            mode.past_str(),
+            targets.len(),
+            dest.display()
+        ),
+    };
+    Ok(msg)
+}
+
+// expands the directories into the files under them, names are relative to `src`
+pub async fn list_targets(
+    client: &Client,
+    src: &PaneLocation,
+    items: Vec<ObjectItem>,
+) -> Result<Vec<(String, usize)>> {
+    let mut targets = Vec::new();
+    for item in items {
+        match item {
+            ObjectItem::File {
+                name, size_byte, ..
+            } => targets.push((name, size_byte)),
+            ObjectItem::Dir { name } => {
+                let files = match src {
+                    PaneLocation::S3(key) => {
+                        let dir_prefix = format!("{}{}/", key.prefix(), name);
+                        client
+                            .load_objects_recursive(&key.bucket_name, &dir_prefix)
+                            .await?
+                            .into_iter()
+                            .filter_map(|item| match item {
+                                ObjectItem::File {
+                                    name, size_byte, ..
+                                } => Some((name, size_byte)),
+                                ObjectItem::Dir { .. } => None,
+                            })
+                            .collect()
+                    }
+                    PaneLocation::Local(path) => list_local_files(&path.join(&name))?,
+                };
+                for (file_name, size_byte) in files {
+                    targets.push((format!("{}/{}", name, file_name), size_byte));
+                }
+            }
+        }
+    }
+    Ok(targets)
+}
+
+async fn list_all_files(
+    client: &Client,
+    location: &PaneLocation,
+) -> Result<HashMap<String, usize>> {
+    let files: Vec<(String, usize)> = match location {
+        PaneLocation::S3(key) => client
+            .load_objects_recursive(&key.bucket_name, &key.prefix())
+            .await?
+            .into_iter()
+            .filter_map(|item| match item {
+                ObjectItem::File {
+                    name, size_byte, ..
+                } => Some((name, size_byte)),
+                ObjectItem::Dir { .. } => None,
+            })
+            .collect(),
+        PaneLocation::Local(path) if path.exists() => list_local_files(path)?,
+        PaneLocation::Local(_) => Vec::new(),
+    };
+    Ok(files.into_iter().collect())
+}
+
+async fn transfer_file(
+    client: &Client,
+    src: &PaneLocation,
+    dest: &PaneLocation,
+    name: &str,
+    size_byte: usize,
+) -> Result<()> {
+    match (src, dest) {
+        (PaneLocation::S3(src_key), PaneLocation::S3(dest_key)) => {
+            let src_object_key = format!("{}{}", src_key.prefix(), name);
+            let dest_object_key = format!("{}{}", dest_key.prefix(), name);
+            client
+                .copy_object(
+                    &src_key.bucket_name,
+                    &src_object_key,
+                    &dest_key.bucket_name,
+                    &dest_object_key,
+                    size_byte,
+                    None,
+                )
+                .await
+        }
+        (PaneLocation::S3(src_key), PaneLocation::Local(dest_path)) => {
+            let src_object_key = format!("{}{}", src_key.prefix(), name);
+            let obj = client
+                .download_object(
+                    &src_key.bucket_name,
+                    &src_object_key,
+                    None,
+                    size_byte,
+                    |_| {},
+                )
+                .await?;
+            let path = join_key_path(dest_path, name)?;
+            save_binary(&path.to_string_lossy(), &obj.bytes)
+        }
+        (PaneLocation::Local(src_path), PaneLocation::S3(dest_key)) => {
+            let bytes = std::fs::read(src_path.join(name))
+                .map_err(|e| AppError::new("Failed to read file", e))?;
+            let dest_object_key = format!("{}{}", dest_key.prefix(), name);
+            client
+                .upload_object(&dest_key.bucket_name, &dest_object_key, bytes)
+                .await
+        }
+        (PaneLocation::Local(src_path), PaneLocation::Local(dest_path)) => {
+            let src_file = src_path.join(name);
+            let dest_file = join_key_path(dest_path, name)?;
+            // copying a file onto itself would truncate it
+            if canonicalize_or_self(&src_file) == canonicalize_or_self(&dest_file) {
+                let msg = format!("Source and destination are the same: {}", name);
+                return Err(AppError::msg(msg));
+            }
+            copy_local_file(&src_file, &dest_file)
+        }
+    }
+}
+
+// `targets` are the result of `list_targets` for `items`
+pub async fn delete_items(
+    client: &Client,
+    src: &PaneLocation,
+    items: &[ObjectItem],
+    targets: &[(String, usize)],
+) -> Result<()> {
+    match src {
+        PaneLocation::S3(key) => {
+            let prefix = key.prefix();
+            // also delete the folder objects created by the management console
+            let dir_keys = items.iter().filter_map(|item| match item {
+                ObjectItem::Dir { name } => Some(format!("{}{}/", prefix, name)),
+                ObjectItem::File { .. } => None,
+            });
+            let keys: Vec<String> = targets
+                .iter()
+                .map(|(name, _)| format!("{}{}", prefix, name))
+                .chain(dir_keys)
+                .collect();
+            client.delete_objects(&key.bucket_name, &keys).await
+        }
+        PaneLocation::Local(path) => {
+            // only the transferred files are deleted, the files added in the meantime are kept
+            for (name, _) in targets {
+                std::fs::remove_file(join_key_path(path, name)?)
+                    .map_err(|e| AppError::new("Failed to remove file", e))?;
+            }
+            for item in items {
+                if let ObjectItem::Dir { name } = item {
+                    let dir_path = join_key_path(path, name)?;
+                    if dir_path.exists() {
+                        remove_empty_dirs(&dir_path)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+// returns the name of the directory in `items` which contains `dest`,
+// transferring it would copy the directory into itself
+pub fn dest_inside_items(
+    src: &PaneLocation,
+    items: &[ObjectItem],
+    dest: &PaneLocation,
+) -> Option<String> {
+    let dir_names = items.iter().filter_map(|item| match item {
+        ObjectItem::Dir { name } => Some(name),
+        ObjectItem::File { .. } => None,
+    });
+    match (src, dest) {
+        (PaneLocation::S3(src_key), PaneLocation::S3(dest_key)) => {
+            if src_key.bucket_name != dest_key.bucket_name {
+                return None;
+            }
+            let dest_prefix = dest_key.prefix();
+            dir_names
+                .filter(|name| dest_prefix.starts_with(&format!("{}{}/", src_key.prefix(), name)))
+                .map(String::from)
+                .next()
+        }
+        (PaneLocation::Local(src_path), PaneLocation::Local(dest_path)) => {
+            let dest_path = canonicalize_or_self(dest_path);
+            dir_names
+                .filter(|name| dest_path.starts_with(canonicalize_or_self(&src_path.join(name))))
+                .map(String::from)
+                .next()
+        }
+        _ => None,
+    }
+}
+
+fn canonicalize_or_self(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[derive(Debug)]
+pub struct TransferRequest {
+    pub mode: TransferMode,
+    pub src: PaneLocation,
+    pub items: Vec<ObjectItem>,
+    pub dest: PaneLocation,
+}
+
+impl TransferRequest {
+    pub fn confirm_message(&self) -> String {
+        let mode = self.mode.str();
+        let dest = self.dest.display();
+        match self.items.as_slice() {
+            [ObjectItem::Dir { name }] => format!("{} {}/ to {}?", mode, name, dest),
+            [item] => format!("{} {} to {}?", mode, item.name(), dest),
+            items => format!("{} {} items to {}?", mode, items.len(), dest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s3(bucket: &str, path: &[&str]) -> PaneLocation {
+        PaneLocation::S3(ObjectKey {
+            bucket_name: bucket.to_string(),
+            object_path: path.iter().map(|s| s.to_string()).collect(),
+        })
+    }
+
+    fn dir(name: &str) -> ObjectItem {
+        ObjectItem::Dir {
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_dest_inside_items_s3() {
+        let items = vec![dir("dir")];
+        let src = s3("b", &["a"]);
+
+        let dest = s3("b", &["a", "dir", "sub"]);
+        assert_eq!(dest_inside_items(&src, &items, &dest), Some("dir".into()));
+        let dest = s3("b", &["a", "dir"]);
+        assert_eq!(dest_inside_items(&src, &items, &dest), Some("dir".into()));
+        let dest = s3("b", &["a", "dir2"]);
+        assert_eq!(dest_inside_items(&src, &items, &dest), None);
+        let dest = s3("c", &["a", "dir", "sub"]);
+        assert_eq!(dest_inside_items(&src, &items, &dest), None);
+    }
+
+    #[test]
+    fn test_dest_inside_items_local() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("dir/sub")).unwrap();
+        std::fs::create_dir_all(root.path().join("dir2")).unwrap();
+        let items = vec![dir("dir")];
+        let src = PaneLocation::Local(root.path().to_path_buf());
+
+        let dest = PaneLocation::Local(root.path().join("dir/sub"));
+        assert_eq!(dest_inside_items(&src, &items, &dest), Some("dir".into()));
+        let dest = PaneLocation::Local(root.path().join("dir2/../dir/sub"));
+        assert_eq!(dest_inside_items(&src, &items, &dest), Some("dir".into()));
+        let dest = PaneLocation::Local(root.path().join("dir2"));
+        assert_eq!(dest_inside_items(&src, &items, &dest), None);
+    }
+
+    #[test]
+    fn test_is_same_location() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("dir")).unwrap();
+        std::fs::create_dir_all(root.path().join("dir2")).unwrap();
+        let local = |path: &str| PaneLocation::Local(root.path().join(path));
+
+        assert!(local("dir").is_same(&local("dir2/../dir")));
+        assert!(local("dir").is_same(&local("dir/.")));
+        assert!(!local("dir").is_same(&local("dir2")));
+    }
+}
@@ -23,6 +23,20 @@ pub fn format_size_byte(size_byte: usize) -> String {
     humansize::format_size_i(size_byte, humansize::BINARY)
 }
 
+pub fn format_object_count(count: usize) -> String {
+    if count == 1 {
+        "1 object".to_string()
+    } else {
+        format!("{} objects", count)
+    }
+}
+
+// `[####      ]` for the ratio 0.4 and the width 10
+pub fn format_ratio_bar(ratio: f64, width: usize) -> String {
+    let filled = ((ratio * width as f64).round() as usize).min(width);
+    format!("[{}{}]", "#".repeat(filled), " ".repeat(width - filled))
+}
+
 #[cfg(not(feature = "imggen"))]
 pub fn format_version(version: &str) -> &str {
     version
@@ -1,6 +1,6 @@
 use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
-    style::{Color, Modifier, Stylize},
+    style::{Color, Modifier, Style, Stylize},
     text::Line,
     widgets::{Block, BorderType, Padding, Paragraph},
     Frame,
@@ -8,10 +8,10 @@ use ratatui::{
 
 use crate::{
     app::{App, Notification},
-    pages::page::Page,
+    pages::page::{Page, PageStack},
     ui::common::calc_centered_dialog_rect,
     util,
-    widget::{Dialog, Header},
+    widget::{ConfirmDialog, Dialog, Header},
 };
 
 const SHORT_HELP_COLOR: Color = Color::DarkGray;
@@ -50,12 +50,45 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_content(f: &mut Frame, area: Rect, app: &mut App) {
-    match app.page_stack.current_page_mut() {
+    // the help is shown in full width even in the dual-pane mode
+    if matches!(app.page_stack.current_page(), Page::Help(_)) {
+        render_page(f, area, app.page_stack.current_page_mut());
+        return;
+    }
+
+    let left_focused = app.is_left_pane_focused();
+    match app.panes_mut() {
+        Some((left, right)) => {
+            let chunks = Layout::horizontal(Constraint::from_percentages([50, 50])).split(area);
+            render_page(f, chunks[0], left.current_page_mut());
+            render_page(f, chunks[1], right.current_page_mut());
+
+            let unfocused_area = if left_focused { chunks[1] } else { chunks[0] };
+            let dim = Style::default().add_modifier(Modifier::DIM);
+            f.buffer_mut().set_style(unfocused_area, dim);
+        }
+        None => render_page(f, area, app.page_stack.current_page_mut()),
+    }
+
+    if let Some(msg) = app.pending_transfer_message() {
+        f.render_widget(ConfirmDialog::new(msg), area);
+    }
+}
+
+fn render_page(f: &mut Frame, area: Rect, page: &mut Page) {
+    match page {
         Page::Initializing(page) => page.render(f, area),
         Page::BucketList(page) => page.render(f, area),
+        Page::BucketStats(page) => page.render(f, area),
         Page::ObjectList(page) => page.render(f, area),
         Page::ObjectDetail(page) => page.render(f, area),
         Page::ObjectPreview(page) => page.render(f, area),
+        Page::ObjectDiff(page) => page.render(f, area),
+        Page::Bookmarks(page) => page.render(f, area),
+        Page::History(page) => page.render(f, area),
+        Page::LocalList(page) => page.render(f, area),
+        Page::DiskUsage(page) => page.render(f, area),
+        Page::Duplicates(page) => page.render(f, area),
         Page::Help(page) => page.render(f, area),
     }
 }
@@ -95,31 +128,67 @@ fn render_loading_dialog(f: &mut Frame, app: &App) {
 }
 
 fn build_header(app: &App) -> Header {
-    let mut target_pages: Vec<&Page> = app
-        .page_stack
+    let breadcrumb = build_breadcrumb(&app.page_stack);
+    let mut header = Header::new(breadcrumb);
+
+    let tab_stacks = app.tab_stacks();
+    if tab_stacks.len() > 1 {
+        let labels = tab_stacks
+            .into_iter()
+            .map(|stack| {
+                build_breadcrumb(stack)
+                    .pop()
+                    .unwrap_or_else(|| "Buckets".to_string())
+            })
+            .collect();
+        header = header.tabs(labels, app.current_tab());
+    }
+
+    let marked_summary = match app.page_stack.current_page() {
+        Page::ObjectList(page) => page.marked_summary(),
+        _ => None,
+    };
+    match marked_summary {
+        Some((count, size_byte)) => header.marked(count, size_byte),
+        None => header,
+    }
+}
+
+fn build_breadcrumb(page_stack: &PageStack) -> Vec<String> {
+    if let Page::LocalList(page) = page_stack.current_page() {
+        return vec![page.path().to_string_lossy().to_string()];
+    }
+
+    let mut target_pages: Vec<&Page> = page_stack
         .iter()
         .filter(|page| matches!(page, Page::BucketList(_) | Page::ObjectList(_)))
         .collect();
     target_pages.pop(); // Remove the last item (current page)
 
-    let breadcrumb: Vec<String> = target_pages
+    target_pages
         .iter()
         .map(|page| match page {
             Page::BucketList(page) => page.current_selected_item().name.clone(),
             Page::ObjectList(page) => page.current_selected_item().name().into(),
             _ => unreachable!(),
         })
-        .collect();
-    Header::new(breadcrumb)
+        .collect()
 }
 
-fn build_short_help(app: &App, width: u16) -> Paragraph {
+fn build_short_help(app: &App, width: u16) -> Paragraph<'_> {
     let helps = match app.page_stack.current_page() {
         Page::Initializing(page) => page.short_helps(),
         Page::BucketList(page) => page.short_helps(),
+        Page::BucketStats(page) => page.short_helps(),
         Page::ObjectList(page) => page.short_helps(),
         Page::ObjectDetail(page) => page.short_helps(),
         Page::ObjectPreview(page) => page.short_helps(),
+        Page::ObjectDiff(page) => page.short_helps(),
+        Page::Bookmarks(page) => page.short_helps(),
+        Page::History(page) => page.short_helps(),
+        Page::LocalList(page) => page.short_helps(),
+        Page::DiskUsage(page) => page.short_helps(),
+        Page::Duplicates(page) => page.short_helps(),
         Page::Help(page) => page.short_helps(),
     };
     let pad = Padding::horizontal(2);
@@ -134,28 +203,28 @@ fn build_short_help_string(helps: &[(String, usize)], max_width: usize) -> Strin
     ss.join(delimiter)
 }
 
-fn build_info_status(msg: &str) -> Paragraph {
+fn build_info_status(msg: &str) -> Paragraph<'_> {
     Paragraph::new(msg.fg(INFO_STATUS_COLOR))
         .block(Block::default().padding(Padding::horizontal(2)))
 }
 
-fn build_success_status(msg: &str) -> Paragraph {
+fn build_success_status(msg: &str) -> Paragraph<'_> {
     Paragraph::new(msg.add_modifier(Modifier::BOLD).fg(SUCCESS_STATUS_COLOR))
         .block(Block::default().padding(Padding::horizontal(2)))
 }
 
-fn build_warn_status(msg: &str) -> Paragraph {
+fn build_warn_status(msg: &str) -> Paragraph<'_> {
     Paragraph::new(msg.add_modifier(Modifier::BOLD).fg(WARN_STATUS_COLOR))
         .block(Block::default().padding(Padding::horizontal(2)))
 }
 
-fn build_error_status(err: &str) -> Paragraph {
+fn build_error_status(err: &str) -> Paragraph<'_> {
     let err = format!("ERROR: {}", err);
     Paragraph::new(err.add_modifier(Modifier::BOLD).fg(ERROR_STATUS_COLOR))
         .block(Block::default().padding(Padding::horizontal(2)))
 }
 
-fn build_loading_dialog(msg: &str) -> Paragraph {
+fn build_loading_dialog(msg: &str) -> Paragraph<'_> {
     let text = Line::from(msg.add_modifier(Modifier::BOLD));
     Paragraph::new(text).alignment(Alignment::Center).block(
         Block::bordered()
@@ -1,19 +1,24 @@
 mod bar;
+mod confirm_dialog;
 mod copy_detail_dialog;
 mod dialog;
 mod divider;
+mod finder_dialog;
 mod header;
 mod input_dialog;
 mod scroll;
 mod scroll_lines;
 mod scroll_list;
 mod sort_list_dialog;
+mod storage_class_dialog;
 mod text_preview;
 
 pub use bar::Bar;
+pub use confirm_dialog::ConfirmDialog;
 pub use copy_detail_dialog::{CopyDetailDialog, CopyDetailDialogState};
 pub use dialog::Dialog;
 pub use divider::Divider;
+pub use finder_dialog::{FinderDialog, FinderDialogState};
 pub use header::Header;
 pub use input_dialog::{InputDialog, InputDialogState};
 pub use scroll::ScrollBar;
@@ -23,4 +28,5 @@ pub use sort_list_dialog::{
     BucketListSortDialog, BucketListSortDialogState, BucketListSortType, ObjectListSortDialog,
     ObjectListSortDialogState, ObjectListSortType,
 };
+pub use storage_class_dialog::{StorageClassDialog, StorageClassDialogState};
 pub use text_preview::{TextPreview, TextPreviewState};
@@ -0,0 +1,72 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, BorderType, Padding, Paragraph, Widget, WidgetRef},
+};
+
+use crate::{ui::common::calc_centered_dialog_rect, widget::Dialog};
+
+const KEY_TEXT_COLOR: Color = Color::DarkGray;
+
+pub struct ConfirmDialog {
+    message: String,
+}
+
+impl ConfirmDialog {
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl Widget for ConfirmDialog {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let dialog_width = (area.width - 4).min(self.message.len() as u16 + 4).max(20);
+        let dialog_height = 2 /* message + keys */ + 2 /* border */;
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let lines = vec![
+            Line::from(self.message),
+            Line::from("y: Yes, n: No".fg(KEY_TEXT_COLOR)),
+        ];
+        let title = Title::from("Confirm");
+        let paragraph = Paragraph::new(lines).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(paragraph));
+        dialog.render_ref(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_confirm_dialog() {
+        let dialog = ConfirmDialog::new("Delete 3 items?".to_string());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 6));
+        dialog.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                              ",
+            "     ╭Confirm───────────╮     ",
+            "     │ Delete 3 items?  │     ",
+            "     │ y: Yes, n: No    │     ",
+            "     ╰──────────────────╯     ",
+            "                              ",
+        ]);
+        set_cells! { expected =>
+            (7..20, [3]) => fg: Color::DarkGray,
+        }
+
+        assert_eq!(buf, expected);
+    }
+}
@@ -95,7 +95,7 @@ fn build_list_item(
     selected: usize,
     item_type: ItemType,
     file_detail: &FileDetail,
-) -> ListItem {
+) -> ListItem<'_> {
     let (name, value) = item_type.name_and_value(file_detail);
     let item = ListItem::new(vec![
         Line::from(format!("{}:", name).add_modifier(Modifier::BOLD)),
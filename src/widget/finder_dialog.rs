@@ -0,0 +1,450 @@
+use crossterm::event::KeyEvent;
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        block::Title, Block, BorderType, List, ListItem, Padding, Paragraph, StatefulWidget,
+        Widget, WidgetRef,
+    },
+};
+use tui_input::{backend::crossterm::EventHandler, Input};
+
+use crate::{ui::common::calc_centered_dialog_rect, widget::Dialog};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
+const MATCHED_TEXT_COLOR: Color = Color::Red;
+const STATUS_TEXT_COLOR: Color = Color::DarkGray;
+
+#[derive(Debug, Default)]
+pub struct FinderDialogState {
+    input: Input,
+    keys: Vec<String>,
+    matches: Vec<FinderMatch>,
+    selected: usize,
+    offset: usize,
+    loading: bool,
+    cursor: (u16, u16),
+    mode: FinderMode,
+    // the query whose keys are loaded (or being loaded) in the prefix mode
+    searched_query: Option<String>,
+    // the loading was stopped at the limit, so some keys are missing
+    truncated: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum FinderMode {
+    // all keys are loaded first, and matched by the query
+    #[default]
+    Fuzzy,
+    // the keys starting with the query are loaded every time the query changes,
+    // unless all the keys for the shorter query have been loaded
+    Prefix,
+}
+
+#[derive(Debug)]
+struct FinderMatch {
+    index: usize,
+    score: i64,
+    positions: Vec<usize>, // char indices
+}
+
+impl FinderDialogState {
+    pub fn start_loading(&mut self) {
+        *self = FinderDialogState {
+            loading: true,
+            ..Default::default()
+        };
+    }
+
+    pub fn start_prefix_search(&mut self) {
+        *self = FinderDialogState {
+            mode: FinderMode::Prefix,
+            ..Default::default()
+        };
+    }
+
+    // keeps the input, and clears the keys loaded for the previous query
+    pub fn start_prefix_loading(&mut self, query: &str) {
+        self.keys.clear();
+        self.matches.clear();
+        self.selected = 0;
+        self.offset = 0;
+        self.loading = !query.is_empty();
+        self.truncated = false;
+        self.searched_query = (!query.is_empty()).then(|| query.to_string());
+    }
+
+    pub fn is_prefix_search(&self) -> bool {
+        self.mode == FinderMode::Prefix
+    }
+
+    // returns the query to list the keys with, if the loaded keys do not cover the current input
+    pub fn prefix_search_query(&self) -> Option<String> {
+        if self.mode != FinderMode::Prefix {
+            return None;
+        }
+        let query = self.input.value();
+        let covered = match &self.searched_query {
+            Some(searched) if searched == query => true,
+            // the keys for the shorter query can be narrowed only if all of them are loaded,
+            // otherwise the listing is restarted with the longer prefix (stopping the current one)
+            Some(searched) => !self.loading && !self.truncated && query.starts_with(searched),
+            None => query.is_empty(),
+        };
+        (!covered).then(|| query.to_string())
+    }
+
+    pub fn finish_loading(&mut self, truncated: bool) {
+        self.loading = false;
+        self.truncated = truncated;
+    }
+
+    pub fn add_keys(&mut self, keys: Vec<String>) {
+        let start = self.keys.len();
+        self.keys.extend(keys);
+        let new_matches = match_keys(&self.keys, start, self.input.value(), self.mode);
+        self.matches.extend(new_matches);
+        if self.mode == FinderMode::Fuzzy {
+            sort_matches(&mut self.matches, &self.keys, self.input.value());
+        }
+    }
+
+    pub fn cursor(&self) -> (u16, u16) {
+        self.cursor
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) {
+        let event = &crossterm::event::Event::Key(key);
+        if let Some(changed) = self.input.handle_event(event) {
+            if changed.value {
+                self.update_matches();
+            }
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.matches.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_key(&self) -> Option<&str> {
+        self.matches
+            .get(self.selected)
+            .map(|m| self.keys[m.index].as_str())
+    }
+
+    fn update_matches(&mut self) {
+        self.matches = match_keys(&self.keys, 0, self.input.value(), self.mode);
+        if self.mode == FinderMode::Fuzzy {
+            sort_matches(&mut self.matches, &self.keys, self.input.value());
+        }
+        self.selected = 0;
+        self.offset = 0;
+    }
+}
+
+fn match_keys(keys: &[String], start: usize, query: &str, mode: FinderMode) -> Vec<FinderMatch> {
+    if mode == FinderMode::Prefix {
+        // in listing order, as the keys are already sorted
+        let positions: Vec<usize> = (0..query.chars().count()).collect();
+        return keys
+            .iter()
+            .enumerate()
+            .skip(start)
+            .filter(|(_, key)| key.starts_with(query))
+            .map(|(index, _)| FinderMatch {
+                index,
+                score: 0,
+                positions: positions.clone(),
+            })
+            .collect();
+    }
+    if query.is_empty() {
+        return (start..keys.len())
+            .map(|index| FinderMatch {
+                index,
+                score: 0,
+                positions: Vec::new(),
+            })
+            .collect();
+    }
+    let matcher = SkimMatcherV2::default().smart_case();
+    keys.iter()
+        .enumerate()
+        .skip(start)
+        .filter_map(|(index, key)| {
+            matcher
+                .fuzzy_indices(key, query)
+                .map(|(score, positions)| FinderMatch {
+                    index,
+                    score,
+                    positions,
+                })
+        })
+        .collect()
+}
+
+fn sort_matches(matches: &mut [FinderMatch], keys: &[String], query: &str) {
+    if query.is_empty() {
+        // keep listing order
+        return;
+    }
+    // higher score first, then shorter key, then listing order
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| keys[a.index].len().cmp(&keys[b.index].len()))
+            .then_with(|| a.index.cmp(&b.index))
+    });
+}
+
+#[derive(Debug, Default)]
+pub struct FinderDialog {
+    title: &'static str,
+}
+
+impl FinderDialog {
+    pub fn title(mut self, title: &'static str) -> Self {
+        self.title = title;
+        self
+    }
+}
+
+impl StatefulWidget for FinderDialog {
+    type State = FinderDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = (area.width - 4).min(100);
+        let dialog_height = (area.height - 2).min(24);
+        let dialog_area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title(Title::from(self.title))
+            .padding(Padding::horizontal(1));
+        let inner = block.inner(dialog_area);
+        let dialog = Dialog::new(Box::new(block));
+        dialog.render_ref(dialog_area, buf);
+
+        if inner.height < 3 {
+            return;
+        }
+
+        // show the last `input_max_width` characters of the input
+        let input_max_width = (inner.width - 2) as usize;
+        let input_start_index = state.input.visual_cursor().saturating_sub(input_max_width);
+        let input_view: &str = &state.input.value()[input_start_index..];
+        let input_area = Rect::new(inner.x, inner.y, inner.width, 1);
+        Paragraph::new(Line::from(vec!["> ".bold(), input_view.into()])).render(input_area, buf);
+
+        let status = if state.loading {
+            format!("{}/{} (loading...)", state.matches.len(), state.keys.len())
+        } else if state.truncated {
+            format!(
+                "{}/{} (truncated, type more to narrow down)",
+                state.matches.len(),
+                state.keys.len()
+            )
+        } else {
+            format!("{}/{}", state.matches.len(), state.keys.len())
+        };
+        let status_area = Rect::new(inner.x, inner.y + 1, inner.width, 1);
+        Paragraph::new(status.fg(STATUS_TEXT_COLOR)).render(status_area, buf);
+
+        let list_area = Rect::new(inner.x, inner.y + 2, inner.width, inner.height - 2);
+        let list_height = list_area.height as usize;
+        if state.selected < state.offset {
+            state.offset = state.selected;
+        } else if state.selected >= state.offset + list_height {
+            state.offset = state.selected + 1 - list_height;
+        }
+
+        let list_items: Vec<ListItem> = state
+            .matches
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(list_height)
+            .map(|(i, m)| build_list_item(&state.keys[m.index], m, i == state.selected))
+            .collect();
+        Widget::render(List::new(list_items), list_area, buf);
+
+        // update cursor position
+        let cursor_x = inner.x + state.input.visual_cursor().min(input_max_width) as u16 + 2;
+        let cursor_y = inner.y;
+        state.cursor = (cursor_x, cursor_y);
+    }
+}
+
+fn build_list_item<'a>(key: &'a str, m: &FinderMatch, selected: bool) -> ListItem<'a> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut positions = m.positions.iter().peekable();
+    let mut start = 0;
+    let mut in_match = false;
+    for (ci, (bi, _)) in key.char_indices().enumerate() {
+        let matched = positions.next_if(|&&p| p == ci).is_some();
+        if matched != in_match {
+            if bi > start {
+                spans.push(build_span(&key[start..bi], in_match));
+            }
+            start = bi;
+            in_match = matched;
+        }
+    }
+    spans.push(build_span(&key[start..], in_match));
+
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(Line::from(spans)).style(style)
+}
+
+fn build_span(s: &str, matched: bool) -> Span<'_> {
+    if matched {
+        s.fg(MATCHED_TEXT_COLOR).bold()
+    } else {
+        s.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+    use ratatui::style::Modifier;
+
+    use crate::set_cells;
+
+    use super::*;
+
+    fn type_query(state: &mut FinderDialogState, query: &str) {
+        for c in query.chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_rank_keys() {
+        let mut state = FinderDialogState::default();
+        state.start_loading();
+        state.add_keys(vec![
+            "2024/01/02/report.csv".to_string(),
+            "2024/01/01/data.json".to_string(),
+        ]);
+        state.add_keys(vec!["reports/2024.csv".to_string()]);
+
+        assert_eq!(state.selected_key(), Some("2024/01/02/report.csv"));
+
+        type_query(&mut state, "rep");
+        assert_eq!(state.matches.len(), 2);
+        assert_eq!(state.selected_key(), Some("reports/2024.csv"));
+
+        state.select_next();
+        assert_eq!(state.selected_key(), Some("2024/01/02/report.csv"));
+        state.select_next();
+        assert_eq!(state.selected_key(), Some("2024/01/02/report.csv"));
+
+        type_query(&mut state, "xyz");
+        assert_eq!(state.selected_key(), None);
+    }
+
+    #[test]
+    fn test_prefix_search() {
+        let mut state = FinderDialogState::default();
+        state.start_prefix_search();
+        assert_eq!(state.prefix_search_query(), None);
+
+        type_query(&mut state, "20");
+        assert_eq!(state.prefix_search_query(), Some("20".to_string()));
+        state.start_prefix_loading("20");
+        state.add_keys(vec![
+            "2023/b.csv".to_string(),
+            "2024/a.csv".to_string(),
+            "2024/c.csv".to_string(),
+        ]);
+        assert_eq!(state.matches.len(), 3);
+        state.finish_loading(false);
+
+        // narrowed without loading again
+        type_query(&mut state, "24");
+        assert_eq!(state.prefix_search_query(), None);
+        assert_eq!(state.matches.len(), 2);
+        assert_eq!(state.selected_key(), Some("2024/a.csv"));
+
+        state.handle_key_event(KeyEvent::from(KeyCode::Backspace));
+        state.handle_key_event(KeyEvent::from(KeyCode::Backspace));
+        state.handle_key_event(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(state.prefix_search_query(), Some("2".to_string()));
+    }
+
+    #[test]
+    fn test_prefix_search_incomplete() {
+        let mut state = FinderDialogState::default();
+        state.start_prefix_search();
+
+        // loaded again while loading the keys for the shorter query
+        type_query(&mut state, "2");
+        state.start_prefix_loading("2");
+        state.add_keys(vec!["2023/b.csv".to_string()]);
+        type_query(&mut state, "02");
+        assert_eq!(state.prefix_search_query(), Some("202".to_string()));
+
+        // loaded again if the keys for the shorter query are truncated
+        state.start_prefix_loading("202");
+        state.add_keys(vec!["2023/b.csv".to_string()]);
+        state.finish_loading(true);
+        assert_eq!(state.prefix_search_query(), None);
+        type_query(&mut state, "4");
+        assert_eq!(state.prefix_search_query(), Some("2024".to_string()));
+    }
+
+    #[test]
+    fn test_render_finder_dialog() {
+        let mut state = FinderDialogState::default();
+        state.start_loading();
+        state.add_keys(vec!["a/file1.txt".to_string(), "b/data.csv".to_string()]);
+        type_query(&mut state, "af");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 8));
+        FinderDialog::default()
+            .title("Find")
+            .render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                              ",
+            "  ╭Find────────────────────╮  ",
+            "  │ > af                   │  ",
+            "  │ 1/2 (loading...)       │  ",
+            "  │ a/file1.txt            │  ",
+            "  │                        │  ",
+            "  ╰────────────────────────╯  ",
+            "                              ",
+        ]);
+        set_cells! { expected =>
+            // prompt
+            (4..6, [2]) => modifier: Modifier::BOLD,
+            // status
+            (4..20, [3]) => fg: Color::DarkGray,
+            // selected item
+            (4..26, [4]) => bg: Color::Cyan, fg: Color::Black,
+            // matched characters
+            ([4, 6], [4]) => fg: Color::Red, modifier: Modifier::BOLD,
+        }
+
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor(), (8, 2));
+    }
+}
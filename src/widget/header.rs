@@ -1,18 +1,42 @@
 use ratatui::{
     buffer::Buffer,
-    layout::{Margin, Rect},
-    widgets::{Block, Padding, Paragraph, Widget},
+    layout::{Alignment, Margin, Rect},
+    style::{Color, Stylize},
+    text::{Line, Span},
+    widgets::{
+        block::{Position, Title},
+        Block, Padding, Paragraph, Widget,
+    },
 };
 
-use crate::{constant::APP_NAME, util::prune_strings_to_fit_width};
+use crate::{constant::APP_NAME, ui::common::format_size_byte, util::prune_strings_to_fit_width};
+
+const SELECTED_TAB_COLOR: Color = Color::Cyan;
+const SELECTED_TAB_TEXT_COLOR: Color = Color::Black;
 
 pub struct Header {
     breadcrumb: Vec<String>,
+    marked: Option<(usize, usize)>,
+    tabs: Option<(Vec<String>, usize)>,
 }
 
 impl Header {
     pub fn new(breadcrumb: Vec<String>) -> Header {
-        Header { breadcrumb }
+        Header {
+            breadcrumb,
+            marked: None,
+            tabs: None,
+        }
+    }
+
+    pub fn tabs(mut self, labels: Vec<String>, current: usize) -> Header {
+        self.tabs = Some((labels, current));
+        self
+    }
+
+    pub fn marked(mut self, count: usize, size_byte: usize) -> Header {
+        self.marked = Some((count, size_byte));
+        self
     }
 }
 
@@ -31,10 +55,30 @@ impl Header {
         let pad = Padding::horizontal(1);
         let max_width = (inner_area.width - pad.left - pad.right) as usize;
 
+        let mut block = Block::bordered().title(APP_NAME).padding(pad);
+        if let Some((count, size_byte)) = self.marked {
+            let marked = format!(" {} marked ({}) ", count, format_size_byte(size_byte));
+            block = block.title(Title::from(marked).alignment(Alignment::Right));
+        }
+        if let Some((labels, current)) = &self.tabs {
+            let spans: Vec<Span> = labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| {
+                    let label = format!(" {}:{} ", i + 1, label);
+                    if i == *current {
+                        label.bg(SELECTED_TAB_COLOR).fg(SELECTED_TAB_TEXT_COLOR)
+                    } else {
+                        label.into()
+                    }
+                })
+                .collect();
+            block = block.title(Title::from(Line::from(spans)).position(Position::Bottom));
+        }
+
         let current_key_str = self.build_current_key_str(max_width);
 
-        let paragraph =
-            Paragraph::new(current_key_str).block(Block::bordered().title(APP_NAME).padding(pad));
+        let paragraph = Paragraph::new(current_key_str).block(block);
 
         paragraph.render(area, buf);
     }
@@ -69,6 +113,8 @@ impl Header {
 
 #[cfg(test)]
 mod tests {
+    use crate::set_cells;
+
     use super::*;
 
     #[test]
@@ -109,6 +155,49 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn test_render_header_with_marked() {
+        let breadcrumb = ["bucket", "key01"]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect();
+        let header = Header::new(breadcrumb).marked(3, 1024 + 10);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30 + 4, 3));
+        header.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌STU──────── 3 marked (1.01 KiB) ┐",
+            "│ bucket / key01                 │",
+            "└────────────────────────────────┘",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_render_header_with_tabs() {
+        let breadcrumb = ["bucket", "key01"]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect();
+        let labels = vec!["key01".to_string(), "raw".to_string()];
+        let header = Header::new(breadcrumb).tabs(labels, 0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30 + 4, 3));
+        header.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌STU─────────────────────────────┐",
+            "│ bucket / key01                 │",
+            "└ 1:key01  2:raw ────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // current tab
+            (1..10, [2]) => bg: Color::Cyan, fg: Color::Black,
+        }
+        assert_eq!(buf, expected);
+    }
+
     #[test]
     fn test_render_header_empty() {
         let header = Header::new(vec![]);
@@ -4,7 +4,7 @@ use ratatui::{
     layout::Rect,
     widgets::{block::Title, Block, BorderType, Padding, Paragraph, StatefulWidget, WidgetRef},
 };
-use tui_input::{backend::crossterm::EventHandler, Input};
+use tui_input::{backend::crossterm::EventHandler, Input, InputRequest};
 
 use crate::{ui::common::calc_centered_dialog_rect, widget::Dialog};
 
@@ -31,6 +31,12 @@ impl InputDialogState {
         let event = &crossterm::event::Event::Key(key);
         self.input.handle_event(event);
     }
+
+    pub fn insert_str(&mut self, s: &str) {
+        for c in s.chars().filter(|c| !c.is_control()) {
+            self.input.handle(InputRequest::InsertChar(c));
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -2,13 +2,17 @@ use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
     style::{Color, Style, Stylize},
-    text::Line,
+    text::{Line, Span},
     widgets::{block::BlockExt, Block, Borders, Padding, Paragraph, StatefulWidget, Widget, Wrap},
 };
+use regex::Regex;
 
 use crate::util::digits;
 
 const PREVIEW_LINE_NUMBER_COLOR: Color = Color::DarkGray;
+const PREVIEW_SEARCH_MATCH_FG_COLOR: Color = Color::Black;
+const PREVIEW_SEARCH_MATCH_BG_COLOR: Color = Color::Yellow;
+const PREVIEW_SEARCH_CURRENT_MATCH_BG_COLOR: Color = Color::LightRed;
 
 #[derive(Debug, Default)]
 enum ScrollEvent {
@@ -22,6 +26,7 @@ enum ScrollEvent {
     End,
     Right,
     Left,
+    Match,
 }
 
 #[derive(Debug, Clone)]
@@ -51,20 +56,45 @@ pub struct ScrollLinesState {
     h_offset: usize,
     options: ScrollLinesOptions,
     scroll_event: ScrollEvent,
+    search: Option<SearchState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SearchMatch {
+    line: usize,
+    // byte range in the line
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug)]
+struct SearchState {
+    re: Regex,
+    matches: Vec<SearchMatch>,
+    current: usize,
+}
+
+impl SearchState {
+    fn current_match(&self) -> Option<SearchMatch> {
+        self.matches.get(self.current).copied()
+    }
+
+    fn line_matches(&self, line: usize) -> &[SearchMatch] {
+        let start = self.matches.partition_point(|m| m.line < line);
+        let end = self.matches.partition_point(|m| m.line <= line);
+        &self.matches[start..end]
+    }
 }
 
 impl ScrollLinesState {
     pub fn new(lines: Vec<Line<'static>>, options: ScrollLinesOptions) -> Self {
-        let max_digits = digits(lines.len());
-        let max_line_width = lines.iter().map(Line::width).max().unwrap_or_default();
-
-        Self {
+        let mut state = Self {
             lines,
-            max_digits,
-            max_line_width,
             options,
             ..Default::default()
-        }
+        };
+        state.update_lines();
+        state
     }
 
     pub fn scroll_forward(&mut self) {
@@ -107,6 +137,88 @@ impl ScrollLinesState {
     pub fn toggle_number(&mut self) {
         self.options.number = !self.options.number;
     }
+
+    pub fn set_lines(&mut self, lines: Vec<Line<'static>>) {
+        self.lines = lines;
+        self.v_offset = self.v_offset.min(self.lines.len().saturating_sub(1));
+        self.update_lines();
+    }
+
+    pub fn append_lines(&mut self, lines: Vec<Line<'static>>, replace_last: bool) {
+        if replace_last {
+            self.lines.pop();
+        }
+        self.lines.extend(lines);
+        self.update_lines();
+    }
+
+    fn update_lines(&mut self) {
+        self.max_digits = digits(self.lines.len());
+        self.max_line_width = self.lines.iter().map(Line::width).max().unwrap_or_default();
+
+        if let Some(search) = &mut self.search {
+            search.matches = find_matches(&self.lines, &search.re);
+            search.current = search.current.min(search.matches.len().saturating_sub(1));
+        }
+    }
+
+    pub fn search(&mut self, re: &Regex) {
+        let matches = find_matches(&self.lines, re);
+        // start from the first match in or after the current top line
+        let current = matches
+            .iter()
+            .position(|m| m.line >= self.v_offset)
+            .unwrap_or_default();
+
+        self.search = Some(SearchState {
+            re: re.clone(),
+            matches,
+            current,
+        });
+        self.scroll_to_current_match();
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search = None;
+    }
+
+    pub fn select_next_match(&mut self) {
+        if let Some(search) = &mut self.search {
+            if !search.matches.is_empty() {
+                search.current = (search.current + 1) % search.matches.len();
+            }
+        }
+        self.scroll_to_current_match();
+    }
+
+    pub fn select_prev_match(&mut self) {
+        if let Some(search) = &mut self.search {
+            if !search.matches.is_empty() {
+                let len = search.matches.len();
+                search.current = (search.current + len - 1) % len;
+            }
+        }
+        self.scroll_to_current_match();
+    }
+
+    // (current match number, total matches)
+    pub fn search_status(&self) -> Option<(usize, usize)> {
+        self.search.as_ref().map(|search| {
+            if search.matches.is_empty() {
+                (0, 0)
+            } else {
+                (search.current + 1, search.matches.len())
+            }
+        })
+    }
+
+    fn scroll_to_current_match(&mut self) {
+        let current = self.search.as_ref().and_then(SearchState::current_match);
+        if let Some(m) = current {
+            self.v_offset = m.line;
+            self.scroll_event = ScrollEvent::Match;
+        }
+    }
 }
 
 // fixme: bad implementation for highlighting and displaying the number of lines :(
@@ -158,7 +270,7 @@ fn build_line_numbers_paragraph(
     state: &ScrollLinesState,
     text_area_width: usize,
     show_lines_count: usize,
-) -> Paragraph {
+) -> Paragraph<'_> {
     // may not be correct because the wrap of the text is calculated separately...
     let line_heights = wrapped_line_width_iter(
         &state.lines,
@@ -190,13 +302,17 @@ fn build_line_numbers_paragraph(
     )
 }
 
-fn build_lines_paragraph(state: &ScrollLinesState, show_lines_count: usize) -> Paragraph {
+fn build_lines_paragraph(state: &ScrollLinesState, show_lines_count: usize) -> Paragraph<'_> {
     let lines_content: Vec<Line> = state
         .lines
         .iter()
+        .enumerate()
         .skip(state.v_offset)
         .take(show_lines_count)
-        .cloned()
+        .map(|(i, line)| match &state.search {
+            Some(search) => highlight_matches(line, search.line_matches(i), search.current_match()),
+            None => line.clone(),
+        })
         .collect();
 
     let lines_paragraph = Paragraph::new(lines_content).block(
@@ -295,6 +411,20 @@ fn handle_scroll_events(state: &mut ScrollLinesState, width: usize, height: usiz
                 state.h_offset = state.h_offset.saturating_sub(1);
             }
         }
+        ScrollEvent::Match => {
+            // v_offset is already updated, make the match visible horizontally
+            let current = state.search.as_ref().and_then(SearchState::current_match);
+            if let (Some(m), false) = (current, state.options.wrap) {
+                let line_str = line_to_plain_string(&state.lines[m.line]);
+                let start_col = Span::raw(&line_str[..m.start]).width();
+                let end_col = Span::raw(&line_str[..m.end]).width();
+                if start_col < state.h_offset {
+                    state.h_offset = start_col;
+                } else if end_col > state.h_offset + width {
+                    state.h_offset = end_col.saturating_sub(width);
+                }
+            }
+        }
     }
     // reset the scroll event
     state.scroll_event = ScrollEvent::None;
@@ -341,6 +471,78 @@ fn wrapped_reversed_line_width_iter<'a>(
         })
 }
 
+fn find_matches(lines: &[Line], re: &Regex) -> Vec<SearchMatch> {
+    lines
+        .iter()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            let line_str = line_to_plain_string(line);
+            re.find_iter(&line_str)
+                .filter(|m| !m.is_empty())
+                .map(|m| SearchMatch {
+                    line: i,
+                    start: m.start(),
+                    end: m.end(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn highlight_matches(
+    line: &Line<'static>,
+    matches: &[SearchMatch],
+    current: Option<SearchMatch>,
+) -> Line<'static> {
+    if matches.is_empty() {
+        return line.clone();
+    }
+
+    let mut spans = Vec::new();
+    let mut span_start = 0;
+    for span in &line.spans {
+        let content = span.content.as_ref();
+        let span_end = span_start + content.len();
+        let mut pos = span_start;
+        for m in matches
+            .iter()
+            .filter(|m| m.start < span_end && m.end > span_start)
+        {
+            let start = m.start.max(span_start);
+            let end = m.end.min(span_end);
+            if pos < start {
+                let s = &content[(pos - span_start)..(start - span_start)];
+                spans.push(Span::styled(s.to_string(), span.style));
+            }
+            let bg = if current == Some(*m) {
+                PREVIEW_SEARCH_CURRENT_MATCH_BG_COLOR
+            } else {
+                PREVIEW_SEARCH_MATCH_BG_COLOR
+            };
+            let s = &content[(start - span_start)..(end - span_start)];
+            let style = span.style.fg(PREVIEW_SEARCH_MATCH_FG_COLOR).bg(bg);
+            spans.push(Span::styled(s.to_string(), style));
+            pos = end;
+        }
+        if pos < span_end {
+            let s = &content[(pos - span_start)..];
+            spans.push(Span::styled(s.to_string(), span.style));
+        }
+        span_start = span_end;
+    }
+
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+// unlike line_to_string, byte offsets in the returned string correspond to the spans
+fn line_to_plain_string(line: &Line) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
 fn line_to_string(line: &Line) -> String {
     line.styled_graphemes(Style::default())
         .map(|g| g.symbol)
@@ -678,6 +880,26 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn test_highlight_matches_across_spans() {
+        let line = Line::from(vec!["ab".red(), "cd".into()]);
+        let m = SearchMatch {
+            line: 0,
+            start: 1,
+            end: 3,
+        };
+
+        let actual = highlight_matches(&line, &[m], Some(m));
+
+        let expected = Line::from(vec![
+            "a".red(),
+            "b".black().on_light_red(),
+            "c".black().on_light_red(),
+            "d".into(),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
     fn state(number: bool, wrap: bool) -> ScrollLinesState {
         let lines: Vec<Line> = [
             "aaa bbb ccc ddd",
@@ -109,6 +109,28 @@ impl ScrollListState {
             self.offset = self.total - self.height;
         }
     }
+
+    pub fn select_index(&mut self, index: usize) {
+        if index >= self.total {
+            return;
+        }
+        self.selected = index;
+        self.fit_height(self.height);
+    }
+
+    // keep the selected item visible, the height may not be known until rendered
+    pub fn fit_height(&mut self, height: usize) {
+        self.height = height;
+        if height == 0 {
+            return;
+        }
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + height {
+            self.offset = self.selected + 1 - height;
+        }
+        self.offset = self.offset.min(self.total.saturating_sub(height));
+    }
 }
 
 #[derive(Debug)]
@@ -126,7 +148,7 @@ impl StatefulWidget for ScrollList<'_> {
     type State = ScrollListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        state.height = area.height as usize - 2 /* border */;
+        state.fit_height(area.height as usize - 2 /* border */);
 
         let title = format_list_count(state.total, state.selected);
         let list = List::new(self.items).block(
@@ -263,6 +285,27 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn test_select_index() {
+        let mut state = ScrollListState::new(20);
+
+        // the height is not known until rendered
+        state.select_index(15);
+        assert_eq!((state.selected, state.offset), (15, 0));
+
+        state.fit_height(10);
+        assert_eq!((state.selected, state.offset), (15, 6));
+
+        state.select_index(3);
+        assert_eq!((state.selected, state.offset), (3, 3));
+
+        state.select_index(19);
+        assert_eq!((state.selected, state.offset), (19, 10));
+
+        state.select_index(20);
+        assert_eq!((state.selected, state.offset), (19, 10));
+    }
+
     fn render_scroll_list(state: &mut ScrollListState) -> Buffer {
         let show_item_count = 10_u16;
         let items: Vec<ListItem> = (1..=20)
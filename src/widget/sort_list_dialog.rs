@@ -6,6 +6,7 @@ use ratatui::{
     text::Line,
     widgets::{block::Title, Block, BorderType, List, ListItem, Padding, Widget, WidgetRef},
 };
+use serde::{Deserialize, Serialize};
 
 use crate::{ui::common::calc_centered_dialog_rect, widget::Dialog};
 
@@ -18,6 +19,10 @@ pub enum BucketListSortType {
     Default,
     NameAsc,
     NameDesc,
+    CreationDateAsc,
+    CreationDateDesc,
+    RegionAsc,
+    RegionDesc,
 }
 
 impl BucketListSortType {
@@ -26,6 +31,10 @@ impl BucketListSortType {
             Self::Default => "Default",
             Self::NameAsc => "Name (Asc)",
             Self::NameDesc => "Name (Desc)",
+            Self::CreationDateAsc => "Creation Date (Asc)",
+            Self::CreationDateDesc => "Creation Date (Desc)",
+            Self::RegionAsc => "Region (Asc)",
+            Self::RegionDesc => "Region (Desc)",
         }
     }
 }
@@ -70,12 +79,12 @@ impl BucketListSortDialog {
 
 impl Widget for BucketListSortDialog {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let dialog = ListSortDialog::new(self.state.selected.val(), self.labels);
+        let dialog = ListSortDialog::new(self.state.selected.val(), self.labels, "Sort");
         dialog.render(area, buf);
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 #[zero_indexed_enum]
 pub enum ObjectListSortType {
     #[default]
@@ -86,6 +95,10 @@ pub enum ObjectListSortType {
     LastModifiedDesc,
     SizeAsc,
     SizeDesc,
+    ExtensionAsc,
+    ExtensionDesc,
+    NaturalAsc,
+    NaturalDesc,
 }
 
 impl ObjectListSortType {
@@ -98,6 +111,10 @@ impl ObjectListSortType {
             Self::LastModifiedDesc => "Last Modified (Desc)",
             Self::SizeAsc => "Size (Asc)",
             Self::SizeDesc => "Size (Desc)",
+            Self::ExtensionAsc => "Extension (Asc)",
+            Self::ExtensionDesc => "Extension (Desc)",
+            Self::NaturalAsc => "Natural (Asc)",
+            Self::NaturalDesc => "Natural (Desc)",
         }
     }
 }
@@ -105,9 +122,26 @@ impl ObjectListSortType {
 #[derive(Debug, Default, Clone, Copy)]
 pub struct ObjectListSortDialogState {
     selected: ObjectListSortType,
+    // directories are listed before files regardless of the sort type
+    dirs_first: bool,
 }
 
 impl ObjectListSortDialogState {
+    pub fn new(selected: ObjectListSortType, dirs_first: bool) -> Self {
+        Self {
+            selected,
+            dirs_first,
+        }
+    }
+
+    pub fn toggle_dirs_first(&mut self) {
+        self.dirs_first = !self.dirs_first;
+    }
+
+    pub fn dirs_first(&self) -> bool {
+        self.dirs_first
+    }
+
     pub fn select_next(&mut self) {
         self.selected = self.selected.next();
     }
@@ -118,6 +152,7 @@ impl ObjectListSortDialogState {
 
     pub fn reset(&mut self) {
         self.selected = ObjectListSortType::Default;
+        self.dirs_first = false;
     }
 
     pub fn selected(&self) -> ObjectListSortType {
@@ -142,7 +177,12 @@ impl ObjectListSortDialog {
 
 impl Widget for ObjectListSortDialog {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let dialog = ListSortDialog::new(self.state.selected.val(), self.labels);
+        let title = if self.state.dirs_first {
+            "Sort (dirs first)"
+        } else {
+            "Sort"
+        };
+        let dialog = ListSortDialog::new(self.state.selected.val(), self.labels, title);
         dialog.render(area, buf);
     }
 }
@@ -150,11 +190,16 @@ impl Widget for ObjectListSortDialog {
 struct ListSortDialog {
     selected: usize,
     labels: Vec<&'static str>,
+    title: &'static str,
 }
 
 impl ListSortDialog {
-    pub fn new(selected: usize, labels: Vec<&'static str>) -> Self {
-        Self { selected, labels }
+    pub fn new(selected: usize, labels: Vec<&'static str>, title: &'static str) -> Self {
+        Self {
+            selected,
+            labels,
+            title,
+        }
     }
 }
 
@@ -178,7 +223,7 @@ impl Widget for ListSortDialog {
         let dialog_height = self.labels.len() as u16 + 2 /* border */;
         let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
 
-        let title = Title::from("Sort");
+        let title = Title::from(self.title);
         let list = List::new(list_items).block(
             Block::bordered()
                 .border_type(BorderType::Rounded)
@@ -0,0 +1,103 @@
+use itsuki::zero_indexed_enum;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, BorderType, List, ListItem, Padding, Widget, WidgetRef},
+};
+
+use crate::{ui::common::calc_centered_dialog_rect, widget::Dialog};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+
+#[derive(Default)]
+#[zero_indexed_enum]
+pub enum StorageClassType {
+    #[default]
+    Standard,
+    IntelligentTiering,
+    StandardIa,
+    OnezoneIa,
+    GlacierIr,
+    Glacier,
+    DeepArchive,
+}
+
+impl StorageClassType {
+    pub fn str(&self) -> &'static str {
+        match self {
+            Self::Standard => "STANDARD",
+            Self::IntelligentTiering => "INTELLIGENT_TIERING",
+            Self::StandardIa => "STANDARD_IA",
+            Self::OnezoneIa => "ONEZONE_IA",
+            Self::GlacierIr => "GLACIER_IR",
+            Self::Glacier => "GLACIER",
+            Self::DeepArchive => "DEEP_ARCHIVE",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StorageClassDialogState {
+    selected: StorageClassType,
+}
+
+impl StorageClassDialogState {
+    pub fn select_next(&mut self) {
+        self.selected = self.selected.next();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.prev();
+    }
+
+    pub fn reset(&mut self) {
+        self.selected = StorageClassType::Standard;
+    }
+
+    pub fn selected(&self) -> StorageClassType {
+        self.selected
+    }
+}
+
+pub struct StorageClassDialog {
+    state: StorageClassDialogState,
+}
+
+impl StorageClassDialog {
+    pub fn new(state: StorageClassDialogState) -> Self {
+        Self { state }
+    }
+}
+
+impl Widget for StorageClassDialog {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let types = StorageClassType::vars_vec();
+        let list_items: Vec<ListItem> = types
+            .iter()
+            .map(|storage_class| {
+                let item = ListItem::new(Line::raw(storage_class.str()));
+                if *storage_class == self.state.selected {
+                    item.fg(SELECTED_COLOR)
+                } else {
+                    item
+                }
+            })
+            .collect();
+
+        let dialog_width = (area.width - 4).min(30);
+        let dialog_height = types.len() as u16 + 2 /* border */;
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let title = Title::from("Storage class");
+        let list = List::new(list_items).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(list));
+        dialog.render_ref(area, buf);
+    }
+}
@@ -3,6 +3,7 @@ use once_cell::sync::Lazy;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
+    style::{Modifier, Stylize},
     text::Line,
     widgets::{Block, StatefulWidget},
 };
@@ -14,6 +15,7 @@ use syntect::{
 };
 
 use crate::{
+    format::ContainerContent,
     object::{FileDetail, RawObject},
     util::{extension_from_file_name, to_preview_string},
     widget::{ScrollLines, ScrollLinesOptions, ScrollLinesState},
@@ -33,32 +35,98 @@ impl TextPreviewState {
         object: &RawObject,
         highlight: bool,
     ) -> (Self, Option<String>) {
-        let mut warn_msg = None;
-
-        let s = to_preview_string(&object.bytes);
-        let s = if s.ends_with('\n') {
-            s.trim_end()
-        } else {
-            s.as_str()
-        };
-
-        let lines: Vec<Line<'static>> =
-            match build_highlighted_lines(s, &file_detail.name, highlight) {
-                Ok(lines) => lines,
-                Err(msg) => {
-                    // If there is an error, display the original text
-                    if let Some(msg) = msg {
-                        warn_msg = Some(msg);
-                    }
-                    s.split('\n').map(|s| Line::raw(s.to_string())).collect()
-                }
-            };
+        let (lines, warn_msg) = build_text_lines(&object.bytes, &file_detail.name, highlight);
 
         let scroll_lines_state = ScrollLinesState::new(lines, ScrollLinesOptions::default());
 
         let state = Self { scroll_lines_state };
         (state, warn_msg)
     }
+
+    pub fn set_text(&mut self, file_detail: &FileDetail, bytes: &[u8], highlight: bool) {
+        let (lines, _) = build_text_lines(bytes, &file_detail.name, highlight);
+        self.scroll_lines_state.set_lines(lines);
+    }
+
+    // `bytes` must start at the beginning of a line.
+    // If `replace_last` is true, `bytes` continues the last line, so it is replaced.
+    pub fn append_text(
+        &mut self,
+        file_detail: &FileDetail,
+        bytes: &[u8],
+        highlight: bool,
+        replace_last: bool,
+    ) {
+        let (lines, _) = build_text_lines(bytes, &file_detail.name, highlight);
+        self.scroll_lines_state.append_lines(lines, replace_last);
+    }
+
+    pub fn from_container(content: &ContainerContent) -> Self {
+        let lines = build_container_lines(content);
+        let scroll_lines_state = ScrollLinesState::new(lines, ScrollLinesOptions::default());
+        Self { scroll_lines_state }
+    }
+}
+
+fn build_container_lines(content: &ContainerContent) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    let properties = [("Format".to_string(), content.format.str().to_string())]
+        .into_iter()
+        .chain(content.properties.iter().cloned())
+        .chain([("Records".to_string(), content.total_records.to_string())]);
+    for (name, value) in properties {
+        lines.push(Line::from(vec![
+            format!("{}: ", name).add_modifier(Modifier::BOLD),
+            value.into(),
+        ]));
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from("Schema:".add_modifier(Modifier::BOLD)));
+    for s in &content.schema {
+        lines.push(Line::raw(format!("  {}", s)));
+    }
+
+    lines.push(Line::raw(""));
+    let title = format!(
+        "Records (first {} of {}):",
+        content.records.len(),
+        content.total_records
+    );
+    lines.push(Line::from(title.add_modifier(Modifier::BOLD)));
+    for record in &content.records {
+        lines.push(Line::raw(format!("  {}", record)));
+    }
+
+    lines
+}
+
+fn build_text_lines(
+    bytes: &[u8],
+    file_name: &str,
+    highlight: bool,
+) -> (Vec<Line<'static>>, Option<String>) {
+    let mut warn_msg = None;
+
+    let s = to_preview_string(bytes);
+    let s = if s.ends_with('\n') {
+        s.trim_end()
+    } else {
+        s.as_str()
+    };
+
+    let lines = match build_highlighted_lines(s, file_name, highlight) {
+        Ok(lines) => lines,
+        Err(msg) => {
+            // If there is an error, display the original text
+            if let Some(msg) = msg {
+                warn_msg = Some(msg);
+            }
+            s.split('\n').map(|s| Line::raw(s.to_string())).collect()
+        }
+    };
+    (lines, warn_msg)
 }
 
 fn build_highlighted_lines(
@@ -92,6 +160,7 @@ fn build_highlighted_lines(
 pub struct TextPreview<'a> {
     file_name: &'a str,
     file_version_id: Option<&'a str>,
+    following: bool,
 }
 
 impl<'a> TextPreview<'a> {
@@ -99,19 +168,31 @@ impl<'a> TextPreview<'a> {
         Self {
             file_name,
             file_version_id,
+            following: false,
         }
     }
+
+    pub fn following(mut self, following: bool) -> Self {
+        self.following = following;
+        self
+    }
 }
 
 impl StatefulWidget for TextPreview<'_> {
     type State = TextPreviewState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let title = if let Some(version_id) = self.file_version_id {
+        let mut title = if let Some(version_id) = self.file_version_id {
             format!("Preview [{} (Version ID: {})]", self.file_name, version_id)
         } else {
             format!("Preview [{}]", self.file_name)
         };
+        if self.following {
+            title = format!("{} (following)", title);
+        }
+        if let Some((current, total)) = state.scroll_lines_state.search_status() {
+            title = format!("{} ({}/{})", title, current, total);
+        }
         ScrollLines::default()
             .block(Block::bordered().title(title))
             .render(area, buf, &mut state.scroll_lines_state);